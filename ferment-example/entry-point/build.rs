@@ -1,20 +1,15 @@
 extern crate cbindgen;
 extern crate ferment_sys;
 
-use std::process::Command;
-
 pub const SELF_NAME: &str = "example_entry_point";
 fn main() {
-   let c_header = format!("target/{}.h", SELF_NAME);
    match ferment_sys::Ferment::with_crate_name(SELF_NAME)
        .with_default_mod_name()
+       .with_header_path(format!("target/{SELF_NAME}.h"))
+       .with_async_mode(ferment_sys::AsyncMode::Callback)
+       .with_diagnostic_policy(ferment_sys::DiagnosticPolicy::CargoWarning)
        .generate() {
-      Ok(()) => match Command::new("cbindgen")
-          .args(["--config", "cbindgen.toml", "-o", c_header.as_str()])
-          .status() {
-         Ok(status) => println!("[cbindgen] [ok] generated into {c_header} with status: {status}"),
-         Err(err) => panic!("[cbindgen] [error] {}", err)
-      }
+      Ok(_) => println!("[ferment-sys] [ok] generated"),
       Err(err) => panic!("[ferment-sys] [error] {}", err)
    }
 }
\ No newline at end of file
@@ -11,6 +11,9 @@ pub struct DashSharedCore {
     pub cache: BTreeMap<String, String>,
     pub context: *const std::os::raw::c_void,
 }
+// The processor and the context are provided by the foreign side, which allows to use them from the runtime threads
+unsafe impl Send for DashSharedCore {}
+unsafe impl Sync for DashSharedCore {}
 
 #[ferment_macro::export]
 impl DashSharedCore {
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::os::raw::c_void;
 use std::sync::Arc;
 
@@ -6,6 +7,7 @@ pub mod processor;
 pub mod provider;
 pub mod rnt;
 pub mod coinjoin;
+pub mod retry;
 
 #[ferment_macro::export]
 #[derive(Clone, Debug)]
@@ -14,6 +16,32 @@ pub struct SomeModel {
     pub desc: String,
 }
 
+#[ferment_macro::export]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GlyphMetrics {
+    pub symbol: char,
+    pub weight: f32,
+    pub weights: Vec<f32>,
+    pub fallback: Option<char>,
+    pub widths: BTreeMap<char, f32>,
+}
+
+pub const GLYPH_CLASS_BASE: u8 = 16;
+
+#[repr(u8)]
+#[ferment_macro::export]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GlyphClass {
+    Base = GLYPH_CLASS_BASE,
+    Ligature = GLYPH_CLASS_BASE + 1,
+    Mark = crate::entry::GLYPH_CLASS_BASE * 2,
+}
+
+#[ferment_macro::export]
+pub fn to_uppercase_symbol(symbol: char) -> char {
+    symbol.to_uppercase().next().unwrap_or(symbol)
+}
+
 #[derive(Clone, Debug)]
 #[ferment_macro::opaque]
 pub struct FFIContext {
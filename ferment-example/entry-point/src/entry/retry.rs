@@ -0,0 +1,14 @@
+use std::sync::Arc;
+
+#[ferment_macro::export]
+pub trait RetryPolicy {
+    fn max_retries(&self) -> u32 { 3 }
+    fn delay_ms(&self, attempt: u32) -> u64;
+}
+
+#[ferment_macro::export]
+pub fn total_retry_delay_ms(policy: Arc<dyn RetryPolicy + Send + Sync>) -> u64 {
+    (0..policy.max_retries())
+        .map(|attempt| policy.delay_ms(attempt))
+        .sum()
+}
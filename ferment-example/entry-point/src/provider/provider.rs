@@ -14,6 +14,9 @@ pub struct CoinJoinProvider {
     pub session_lifecycle_listener2: Arc<dyn Fn(*const c_void, bool, i32, [u8; 32], u32, [u8; 32], [u8; 32], [u8; 32], SocketAddr, bool)>,
     pub mixing_lifecycle_listener: Arc<dyn Fn(*const c_void, bool, bool, Vec<[u8; 32]>)>,
 }
+// The context and the callbacks are provided by the foreign side, which allows to use them from the runtime threads
+unsafe impl Send for CoinJoinProvider {}
+unsafe impl Sync for CoinJoinProvider {}
 
 #[ferment_macro::export]
 impl CoinJoinProvider  {
@@ -0,0 +1,95 @@
+#![allow(clippy::not_unsafe_ptr_arg_deref)]
+
+use std::os::raw::{c_char, c_void};
+use std::sync::Arc;
+use std::sync::mpsc::{channel, Sender};
+use std::time::Duration;
+use ferment::{FFIConversionFrom, FFIConversionTo};
+use example_entry_point::entry::{coinjoin::CoinJoinProvider, FFIContext};
+use example_entry_point::fermented::generics::{
+    Fn_ARGS_example_entry_point_entry_FFIContext_usize_RTRN_bool, Fn_ARGS_example_entry_point_entry_FFIContext_usize_RTRN_bool_ctor,
+    Fn_ARGS_example_entry_point_entry_FFIContext_usize_RTRN_Vec_u8, Fn_ARGS_example_entry_point_entry_FFIContext_usize_RTRN_Vec_u8_ctor,
+    Fn_ARGS_usize_RTRN_bool, Fn_ARGS_usize_RTRN_bool_ctor,
+    Vec_u8, Vec_Vec_u8,
+};
+use example_entry_point::fermented::types::example_entry_point::entry::coinjoin::{
+    example_entry_point_entry_coinjoin_CoinJoinProvider_load_smth_opaque as load_smth_opaque,
+    example_entry_point_entry_coinjoin_CoinJoinProvider_load_smth_opaque_Completion as LoadCompletion,
+    example_entry_point_entry_coinjoin_CoinJoinProvider_load_smth_opaque_with_cancel as load_smth_opaque_with_cancel,
+    example_entry_point_entry_coinjoin_CoinJoinProvider_load_smth_opaque_with_cancel_Completion as LoadWithCancelCompletion,
+};
+
+type Outcome = Result<Vec<Vec<u8>>, String>;
+
+// Completion context is a boxed sender: it's consumed by whichever callback fires
+unsafe extern "C" fn on_result(context: *const c_void, result: *mut Vec_Vec_u8) {
+    let sender = Box::from_raw(context as *mut Sender<Outcome>);
+    let value = <Vec_Vec_u8 as FFIConversionFrom<Vec<Vec<u8>>>>::ffi_from(result);
+    sender.send(Ok(value)).unwrap();
+}
+unsafe extern "C" fn on_error(context: *const c_void, error: *mut c_char) {
+    let sender = Box::from_raw(context as *mut Sender<Outcome>);
+    let message = <c_char as FFIConversionFrom<String>>::ffi_from(error);
+    ferment::unbox_string(error);
+    sender.send(Err(message)).unwrap();
+}
+
+unsafe extern "C" fn should_continue(_context: *const c_void, _ffi_context: *mut FFIContext, i: usize) -> bool { i < 4 }
+unsafe extern "C" fn always_continue(_context: *const c_void, _ffi_context: *mut FFIContext, _i: usize) -> bool { true }
+unsafe extern "C" fn never_cancel(_context: *const c_void, _i: usize) -> bool { false }
+unsafe extern "C" fn fetch(_context: *const c_void, _ffi_context: *mut FFIContext, i: usize) -> *mut Vec_u8 {
+    <Vec_u8 as FFIConversionTo<Vec<u8>>>::ffi_to(vec![i as u8])
+}
+unsafe extern "C" fn drop_bool(_value: bool) {}
+unsafe extern "C" fn drop_vec(ptr: *mut Vec_u8) { if !ptr.is_null() { ferment::unbox_any(ptr) } }
+
+unsafe fn take<T>(ptr: *mut T) -> T {
+    *Box::from_raw(ptr)
+}
+
+// Async (AsyncMode::Callback): the binding returns a handle right away and delivers the result via completion
+#[test]
+fn async_completion_delivers_result() {
+    let rt = tokio::runtime::Builder::new_multi_thread().enable_all().build().unwrap();
+    let runtime = &rt as *const tokio::runtime::Runtime as *const c_void;
+    let mut provider = CoinJoinProvider::new(Arc::new(FFIContext {}));
+    let (sender, receiver) = channel::<Outcome>();
+    let completion = LoadCompletion { context: ferment::boxed(sender) as *const c_void, on_result, on_error };
+    let handle = unsafe {
+        let should: Fn_ARGS_example_entry_point_entry_FFIContext_usize_RTRN_bool = take(Fn_ARGS_example_entry_point_entry_FFIContext_usize_RTRN_bool_ctor(std::ptr::null(), should_continue, drop_bool, None));
+        let fetch: Fn_ARGS_example_entry_point_entry_FFIContext_usize_RTRN_Vec_u8 = take(Fn_ARGS_example_entry_point_entry_FFIContext_usize_RTRN_Vec_u8_ctor(std::ptr::null(), fetch, drop_vec, None));
+        load_smth_opaque(runtime, completion, &mut provider, should, fetch, 10)
+    };
+    assert!(!handle.is_null());
+
+    let outcome = receiver.recv_timeout(Duration::from_secs(5)).expect("completion");
+    assert_eq!(outcome, Ok(vec![vec![0], vec![1], vec![2], vec![3]]));
+    unsafe { ferment::ferment_cancellation_handle_destroy(handle) };
+}
+
+// Async (AsyncMode::Callback): cancelling the handle aborts the task and reports an error
+#[test]
+fn async_completion_cancelled() {
+    let rt = tokio::runtime::Builder::new_multi_thread().enable_all().build().unwrap();
+    let runtime = &rt as *const tokio::runtime::Runtime as *const c_void;
+    let mut provider = CoinJoinProvider::new(Arc::new(FFIContext {}));
+    let (sender, receiver) = channel::<Outcome>();
+    let completion = LoadWithCancelCompletion { context: ferment::boxed(sender) as *const c_void, on_result, on_error };
+    let handle = unsafe {
+        let should: Fn_ARGS_example_entry_point_entry_FFIContext_usize_RTRN_bool = take(Fn_ARGS_example_entry_point_entry_FFIContext_usize_RTRN_bool_ctor(std::ptr::null(), always_continue, drop_bool, None));
+        let fetch: Fn_ARGS_example_entry_point_entry_FFIContext_usize_RTRN_Vec_u8 = take(Fn_ARGS_example_entry_point_entry_FFIContext_usize_RTRN_Vec_u8_ctor(std::ptr::null(), fetch, drop_vec, None));
+        let cancel: Fn_ARGS_usize_RTRN_bool = take(Fn_ARGS_usize_RTRN_bool_ctor(std::ptr::null(), never_cancel, drop_bool, None));
+        // Would run for a minute with 50ms pauses between fetches
+        load_smth_opaque_with_cancel(runtime, completion, &mut provider, should, fetch, cancel, usize::MAX, 60_000, 50, 1, 1)
+    };
+    assert!(!handle.is_null());
+    // The calling thread isn't blocked: nothing is delivered yet
+    assert!(receiver.try_recv().is_err());
+
+    unsafe {
+        ferment::ferment_cancellation_handle_cancel(handle);
+        ferment::ferment_cancellation_handle_destroy(handle);
+    }
+    let outcome = receiver.recv_timeout(Duration::from_secs(5)).expect("completion");
+    assert!(matches!(outcome, Err(ref message) if message.contains("cancelled")), "{outcome:?}");
+}
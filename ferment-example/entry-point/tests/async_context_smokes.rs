@@ -1,14 +1,22 @@
 #![allow(clippy::not_unsafe_ptr_arg_deref)]
 
 use std::collections::HashMap;
-use std::sync::{Mutex, OnceLock};
+use std::os::raw::c_void;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use ferment::{FFIConversionFrom, FFIConversionTo};
 
-// Shared test context: maps produced hashes to the originating height
-static CONTEXT: OnceLock<Mutex<HashMap<[u8; 32], u32>>> = OnceLock::new();
+// Per-instance test context: maps produced hashes to the originating height
+#[derive(Default)]
+struct ChainContext {
+    heights: Mutex<HashMap<[u8; 32], u32>>,
+}
+
+static CONTEXTS_DROPPED: AtomicUsize = AtomicUsize::new(0);
 
-fn ctx() -> &'static Mutex<HashMap<[u8; 32], u32>> {
-    CONTEXT.get_or_init(|| Mutex::new(HashMap::new()))
+unsafe extern "C" fn drop_context(context: *const c_void) {
+    drop(Box::from_raw(context as *mut ChainContext));
+    CONTEXTS_DROPPED.fetch_add(1, Ordering::SeqCst);
 }
 
 // Advanced async chaining with context preservation between steps
@@ -21,17 +29,17 @@ async fn async_chain_with_context_preserved() {
     };
 
     // Step 1: height -> hash, store context (height) keyed by hash
-    unsafe extern "C" fn h2hash_store(h: u32) -> *mut Arr_u8_32 {
+    unsafe extern "C" fn h2hash_store(context: *const c_void, h: u32) -> *mut Arr_u8_32 {
+        let context = &*(context as *const ChainContext);
         let hash = [h as u8; 32];
-        let mut lock = ctx().lock().unwrap();
-        lock.insert(hash, h);
+        context.heights.lock().unwrap().insert(hash, h);
         <Arr_u8_32 as FFIConversionTo<[u8; 32]>>::ffi_to(hash)
     }
     // Step 2: hash -> root, use stored context to build a root deterministically
-    unsafe extern "C" fn hash2root_from_ctx(h_ptr: *mut Arr_u8_32) -> *mut Arr_u8_32 {
+    unsafe extern "C" fn hash2root_from_ctx(context: *const c_void, h_ptr: *mut Arr_u8_32) -> *mut Arr_u8_32 {
+        let context = &*(context as *const ChainContext);
         let hash = <Arr_u8_32 as FFIConversionFrom<[u8; 32]>>::ffi_from(h_ptr);
-        let lock = ctx().lock().unwrap();
-        if let Some(height) = lock.get(&hash).copied() {
+        if let Some(height) = context.heights.lock().unwrap().get(&hash).copied() {
             // Build a root that encodes the height in the first byte to verify context
             let mut root = [0u8; 32];
             root[0] = (height & 0xFF) as u8;
@@ -43,15 +51,17 @@ async fn async_chain_with_context_preserved() {
     }
     unsafe extern "C" fn drop_arr(ptr: *mut Arr_u8_32) { if !ptr.is_null() { ferment::unbox_any(ptr) } }
 
-    let cb_h2hash: *mut Fn_ARGS_u32_RTRN_Option_u8_32 = unsafe { Fn_ARGS_u32_RTRN_Option_u8_32_ctor(h2hash_store, drop_arr) };
-    let cb_hash2root: *mut Fn_ARGS_Arr_u8_32_RTRN_Option_u8_32 = unsafe { Fn_ARGS_Arr_u8_32_RTRN_Option_u8_32_ctor(hash2root_from_ctx, drop_arr) };
+    // Both wrappers share one context; only the second one owns (and releases) it
+    let context = ferment::boxed(ChainContext::default()) as *const c_void;
+    let cb_h2hash: *mut Fn_ARGS_u32_RTRN_Option_u8_32 = unsafe { Fn_ARGS_u32_RTRN_Option_u8_32_ctor(context, h2hash_store, drop_arr, None) };
+    let cb_hash2root: *mut Fn_ARGS_Arr_u8_32_RTRN_Option_u8_32 = unsafe { Fn_ARGS_Arr_u8_32_RTRN_Option_u8_32_ctor(context, hash2root_from_ctx, drop_arr, Some(drop_context)) };
     assert!(!cb_h2hash.is_null() && !cb_hash2root.is_null());
 
     // Run multiple chains concurrently and verify first byte of root matches original height (mod 256)
     let mut tasks = vec![];
     for h in 1u32..=8 {
-        let a = unsafe { (&*cb_h2hash).clone() };
-        let b = unsafe { (&*cb_hash2root).clone() };
+        let a = unsafe { &*cb_h2hash };
+        let b = unsafe { &*cb_hash2root };
         tasks.push(tokio::spawn(async move {
             let hash = unsafe { a.call(h) }.expect("hash");
             let root = unsafe { b.call(hash) }.expect("root");
@@ -63,11 +73,46 @@ async fn async_chain_with_context_preserved() {
         let (h, root) = t.await.unwrap();
         assert_eq!(root[0], (h & 0xFF) as u8);
     }
+    // The tasks only borrow the wrappers, so the context is released by its owner only
+    assert_eq!(CONTEXTS_DROPPED.load(Ordering::SeqCst), 0);
 
     // Clean up wrappers
     unsafe {
         Fn_ARGS_u32_RTRN_Option_u8_32_destroy(cb_h2hash);
         Fn_ARGS_Arr_u8_32_RTRN_Option_u8_32_destroy(cb_hash2root);
     }
+    assert_eq!(CONTEXTS_DROPPED.load(Ordering::SeqCst), 1);
 }
 
+// Separate wrapper instances keep separate state
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn async_per_instance_context() {
+    use example_entry_point::fermented::generics::{
+        Arr_u8_32, Fn_ARGS_u32_RTRN_Option_u8_32, Fn_ARGS_u32_RTRN_Option_u8_32_ctor,
+        Fn_ARGS_u32_RTRN_Option_u8_32_destroy,
+    };
+
+    unsafe extern "C" fn salted(context: *const c_void, h: u32) -> *mut Arr_u8_32 {
+        let salt = *(context as *const u8);
+        <Arr_u8_32 as FFIConversionTo<[u8; 32]>>::ffi_to([salt ^ h as u8; 32])
+    }
+    unsafe extern "C" fn drop_arr(ptr: *mut Arr_u8_32) { if !ptr.is_null() { ferment::unbox_any(ptr) } }
+    unsafe extern "C" fn drop_salt(context: *const c_void) { ferment::unbox_any(context as *mut u8) }
+
+    let cb_a = unsafe { Fn_ARGS_u32_RTRN_Option_u8_32_ctor(ferment::boxed(0x0Fu8) as *const c_void, salted, drop_arr, Some(drop_salt)) };
+    let cb_b = unsafe { Fn_ARGS_u32_RTRN_Option_u8_32_ctor(ferment::boxed(0xF0u8) as *const c_void, salted, drop_arr, Some(drop_salt)) };
+
+    let a: &Fn_ARGS_u32_RTRN_Option_u8_32 = unsafe { &*cb_a };
+    let b: &Fn_ARGS_u32_RTRN_Option_u8_32 = unsafe { &*cb_b };
+    let (ra, rb) = tokio::join!(
+        tokio::spawn(async move { unsafe { a.call(1) } }),
+        tokio::spawn(async move { unsafe { b.call(1) } })
+    );
+    assert_eq!(ra.unwrap(), Some([0x0E; 32]));
+    assert_eq!(rb.unwrap(), Some([0xF1; 32]));
+
+    unsafe {
+        Fn_ARGS_u32_RTRN_Option_u8_32_destroy(cb_a);
+        Fn_ARGS_u32_RTRN_Option_u8_32_destroy(cb_b);
+    }
+}
@@ -1,5 +1,6 @@
 #![allow(clippy::not_unsafe_ptr_arg_deref)]
 
+use std::os::raw::c_void;
 use ferment::FFIConversionTo;
 
 // Async: many concurrent calls to a simple u32 -> Option<[u8;32]> callback wrapper
@@ -10,17 +11,17 @@ async fn async_many_height_to_hash_calls() {
         Fn_ARGS_u32_RTRN_Option_u8_32_destroy,
     };
 
-    unsafe extern "C" fn caller(h: u32) -> *mut Arr_u8_32 {
+    unsafe extern "C" fn caller(_context: *const c_void, h: u32) -> *mut Arr_u8_32 {
         if h % 2 == 0 { <Arr_u8_32 as FFIConversionTo<[u8;32]>>::ffi_to([h as u8; 32]) } else { std::ptr::null_mut() }
     }
     unsafe extern "C" fn dtor(ptr: *mut Arr_u8_32) { if !ptr.is_null() { ferment::unbox_any(ptr) } }
 
-    let cb: *mut Fn_ARGS_u32_RTRN_Option_u8_32 = unsafe { Fn_ARGS_u32_RTRN_Option_u8_32_ctor(caller, dtor) };
+    let cb: *mut Fn_ARGS_u32_RTRN_Option_u8_32 = unsafe { Fn_ARGS_u32_RTRN_Option_u8_32_ctor(std::ptr::null(), caller, dtor, None) };
     assert!(!cb.is_null());
 
     let mut tasks = vec![];
     for h in 0u32..10 {
-        let local = unsafe { (&*cb).clone() };
+        let local = unsafe { &*cb };
         tasks.push(tokio::spawn(async move {
             let r = unsafe { local.call(h) };
             (h, r)
@@ -44,19 +45,19 @@ async fn async_chain_height_to_root() {
         Fn_ARGS_Arr_u8_32_RTRN_Option_u8_32, Fn_ARGS_Arr_u8_32_RTRN_Option_u8_32_ctor, Fn_ARGS_Arr_u8_32_RTRN_Option_u8_32_destroy,
     };
 
-    unsafe extern "C" fn h2hash(h: u32) -> *mut Arr_u8_32 {
+    unsafe extern "C" fn h2hash(_context: *const c_void, h: u32) -> *mut Arr_u8_32 {
         if h > 0 { <Arr_u8_32 as FFIConversionTo<[u8;32]>>::ffi_to([h as u8; 32]) } else { std::ptr::null_mut() }
     }
-    unsafe extern "C" fn hash2root(_h: *mut Arr_u8_32) -> *mut Arr_u8_32 { <Arr_u8_32 as FFIConversionTo<[u8;32]>>::ffi_to([0xAA; 32]) }
+    unsafe extern "C" fn hash2root(_context: *const c_void, _h: *mut Arr_u8_32) -> *mut Arr_u8_32 { <Arr_u8_32 as FFIConversionTo<[u8;32]>>::ffi_to([0xAA; 32]) }
     unsafe extern "C" fn dtor(ptr: *mut Arr_u8_32) { if !ptr.is_null() { ferment::unbox_any(ptr) } }
 
-    let cb1: *mut Fn_ARGS_u32_RTRN_Option_u8_32 = unsafe { Fn_ARGS_u32_RTRN_Option_u8_32_ctor(h2hash, dtor) };
-    let cb2: *mut Fn_ARGS_Arr_u8_32_RTRN_Option_u8_32 = unsafe { Fn_ARGS_Arr_u8_32_RTRN_Option_u8_32_ctor(hash2root, dtor) };
+    let cb1: *mut Fn_ARGS_u32_RTRN_Option_u8_32 = unsafe { Fn_ARGS_u32_RTRN_Option_u8_32_ctor(std::ptr::null(), h2hash, dtor, None) };
+    let cb2: *mut Fn_ARGS_Arr_u8_32_RTRN_Option_u8_32 = unsafe { Fn_ARGS_Arr_u8_32_RTRN_Option_u8_32_ctor(std::ptr::null(), hash2root, dtor, None) };
 
     let mut tasks = vec![];
     for h in 1u32..6 {
-        let a = unsafe { (&*cb1).clone() };
-        let b = unsafe { (&*cb2).clone() };
+        let a = unsafe { &*cb1 };
+        let b = unsafe { &*cb2 };
         tasks.push(tokio::spawn(async move {
             let hash = unsafe { a.call(h) }.unwrap();
             let root = unsafe { b.call(hash) }.unwrap();
@@ -86,16 +87,16 @@ async fn async_result_callback() {
         Fn_ARGS_Arr_u8_32_Arr_u8_32_RTRN_Result_ok_u32_err_String_destroy as FnResDestroy,
     };
 
-    unsafe extern "C" fn ok_call(_a: *mut Arr_u8_32, _b: *mut Arr_u8_32) -> *mut FFIRes {
+    unsafe extern "C" fn ok_call(_context: *const c_void, _a: *mut Arr_u8_32, _b: *mut Arr_u8_32) -> *mut FFIRes {
         <FFIRes as FFIConversionTo<Result<u32, String>>>::ffi_to(Ok(5u32))
     }
     unsafe extern "C" fn drop_res(ptr: *mut FFIRes) { if !ptr.is_null() { ferment::unbox_any(ptr) } }
 
-    let cb: *mut FnRes = unsafe { FnResCtor(ok_call, drop_res) };
+    let cb: *mut FnRes = unsafe { FnResCtor(std::ptr::null(), ok_call, drop_res, None) };
 
     let mut tasks = vec![];
     for _ in 0..8 {
-        let c = unsafe { (&*cb).clone() };
+        let c = unsafe { &*cb };
         tasks.push(tokio::spawn(async move { unsafe { c.call([1u8; 32], [2u8; 32]) } }));
     }
 
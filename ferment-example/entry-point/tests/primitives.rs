@@ -0,0 +1,120 @@
+#![allow(clippy::not_unsafe_ptr_arg_deref)]
+
+use std::collections::BTreeMap;
+use ferment::{FFIConversionFrom, FFIConversionTo};
+use example_entry_point::entry::{GlyphClass, GlyphMetrics};
+use example_entry_point::fermented::generics::{std_collections_Map_keys_char_values_f32, Vec_f32};
+use example_entry_point::fermented::types::example_entry_point::entry::{
+    example_entry_point_entry_GlyphClass as FFIGlyphClass,
+    example_entry_point_entry_GlyphMetrics as FFIGlyphMetrics,
+    example_entry_point_entry_GlyphMetrics_ctor,
+    example_entry_point_entry_GlyphMetrics_destroy,
+    example_entry_point_entry_GlyphMetrics_get_fallback,
+    example_entry_point_entry_GlyphMetrics_get_symbol,
+    example_entry_point_entry_GlyphMetrics_get_weight,
+    example_entry_point_entry_to_uppercase_symbol,
+};
+
+fn glyph_metrics() -> GlyphMetrics {
+    GlyphMetrics {
+        symbol: 'ж',
+        weight: 0.75,
+        weights: vec![0.0, -1.5, f32::MAX, f32::MIN_POSITIVE],
+        fallback: Some('🚀'),
+        widths: BTreeMap::from_iter([('a', 0.5), ('Ω', 1.25), ('😀', 2.0)]),
+    }
+}
+
+// Vec<f32> is passed as a plain array of floats
+#[test]
+fn vec_f32_roundtrip_and_free() {
+    let original = vec![0.0f32, 1.5, -2.25, f32::INFINITY];
+    let ffi_ptr = unsafe { <Vec_f32 as FFIConversionTo<Vec<f32>>>::ffi_to_const(original.clone()) };
+    let decoded = unsafe { <Vec_f32 as FFIConversionFrom<Vec<f32>>>::ffi_from_const(ffi_ptr) };
+    assert_eq!(decoded, original);
+    unsafe { ferment::unbox_any(ffi_ptr.cast_mut()) };
+}
+
+// BTreeMap<char, f32>: keys are passed as an array of Unicode scalar values
+#[test]
+fn btreemap_char_f32_roundtrip_and_free() {
+    let original = BTreeMap::from_iter([('a', 0.5f32), ('Ω', 1.25), ('😀', 2.0)]);
+    let ffi_ptr = unsafe { <std_collections_Map_keys_char_values_f32 as FFIConversionTo<BTreeMap<char, f32>>>::ffi_to_const(original.clone()) };
+    let decoded = unsafe { <std_collections_Map_keys_char_values_f32 as FFIConversionFrom<BTreeMap<char, f32>>>::ffi_from_const(ffi_ptr) };
+    assert_eq!(decoded, original);
+    unsafe { ferment::unbox_any(ffi_ptr.cast_mut()) };
+}
+
+// Option<char> maps to a nullable pointer to u32
+#[test]
+fn option_char_roundtrip() {
+    unsafe {
+        let some = ferment::to_opt_scalar(Some('€'));
+        assert_eq!(*some, '€' as u32);
+        assert_eq!(ferment::from_opt_scalar(some), Some('€'));
+        ferment::destroy_opt_primitive(some);
+        let none = ferment::to_opt_scalar(None);
+        assert!(none.is_null());
+        assert_eq!(ferment::from_opt_scalar(none), None);
+    }
+}
+
+// Surrogates and values above U+10FFFF aren't Unicode scalar values: the error is reported, no char is made up
+#[test]
+fn char_from_invalid_scalar_is_reported() {
+    for scalar in [0xD800u32, 0x110000] {
+        ferment::take_last_error();
+        assert!(std::panic::catch_unwind(|| ferment::from_scalar(scalar)).is_err());
+        assert_eq!(ferment::take_last_error(), Some(format!("Invalid Unicode scalar value: {scalar:#x}")));
+    }
+    let scalar = ferment::boxed(0xDFFFu32);
+    assert!(std::panic::catch_unwind(|| unsafe { ferment::from_opt_scalar(scalar) }).is_err());
+    assert_eq!(ferment::take_last_error(), Some(String::from("Invalid Unicode scalar value: 0xdfff")));
+    unsafe { ferment::destroy_opt_primitive(scalar) };
+}
+
+// char arguments and results are passed by value
+#[test]
+fn char_by_value() {
+    unsafe {
+        assert_eq!(example_entry_point_entry_to_uppercase_symbol('ж' as u32), 'Ж' as u32);
+    }
+}
+
+#[test]
+fn struct_with_f32_and_char_fields_roundtrip_and_free() {
+    let original = glyph_metrics();
+    let ffi_ptr = unsafe { <FFIGlyphMetrics as FFIConversionTo<GlyphMetrics>>::ffi_to_const(original.clone()) };
+    unsafe {
+        assert_eq!(example_entry_point_entry_GlyphMetrics_get_symbol(ffi_ptr), 'ж' as u32);
+        assert_eq!(example_entry_point_entry_GlyphMetrics_get_weight(ffi_ptr), 0.75);
+        assert_eq!(*example_entry_point_entry_GlyphMetrics_get_fallback(ffi_ptr), '🚀' as u32);
+    }
+    let decoded = unsafe { <FFIGlyphMetrics as FFIConversionFrom<GlyphMetrics>>::ffi_from_const(ffi_ptr) };
+    assert_eq!(decoded, original);
+    unsafe { ferment::unbox_any(ffi_ptr.cast_mut()) };
+}
+
+#[test]
+fn struct_with_f32_and_char_fields_ctor() {
+    let original = glyph_metrics();
+    unsafe {
+        let ffi_ptr = example_entry_point_entry_GlyphMetrics_ctor(
+            u32::from(original.symbol),
+            original.weight,
+            <Vec_f32 as FFIConversionTo<Vec<f32>>>::ffi_to(original.weights.clone()),
+            ferment::to_opt_scalar(original.fallback),
+            <std_collections_Map_keys_char_values_f32 as FFIConversionTo<BTreeMap<char, f32>>>::ffi_to(original.widths.clone()));
+        assert_eq!(<FFIGlyphMetrics as FFIConversionFrom<GlyphMetrics>>::ffi_from_const(ffi_ptr), original);
+        example_entry_point_entry_GlyphMetrics_destroy(ffi_ptr);
+    }
+}
+
+// Discriminants referring to the consts keep the values
+#[test]
+fn enum_with_const_discriminants() {
+    assert_eq!(FFIGlyphClass::Base as u8, 16);
+    assert_eq!(FFIGlyphClass::Ligature as u8, 17);
+    assert_eq!(FFIGlyphClass::Mark as u8, 32);
+    assert_eq!(GlyphClass::from(FFIGlyphClass::from(GlyphClass::Mark)), GlyphClass::Mark);
+}
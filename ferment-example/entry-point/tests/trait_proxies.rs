@@ -0,0 +1,62 @@
+#![allow(clippy::not_unsafe_ptr_arg_deref)]
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use example_entry_point::fermented::types::example_entry_point::entry::retry::{
+    example_entry_point_entry_retry_RetryPolicy as FFIRetryPolicy,
+    example_entry_point_entry_retry_RetryPolicy_VTable as FFIRetryPolicyVTable,
+    example_entry_point_entry_retry_RetryPolicy_Proxy_ctor,
+    example_entry_point_entry_retry_total_retry_delay_ms,
+};
+
+// Foreign context of the implementation: the proxy passes it back to the vtable entries
+struct Context {
+    base_delay_ms: u64,
+    released: Arc<AtomicBool>,
+}
+
+unsafe fn context<'a>(self_: *mut FFIRetryPolicy) -> &'a Context {
+    // the trait object is #[repr(C)] and starts with the context pointer
+    &*(*(self_ as *const *const ()) as *const Context)
+}
+
+unsafe extern "C" fn delay_ms(self_: *mut FFIRetryPolicy, attempt: u32) -> u64 {
+    context(self_).base_delay_ms * (attempt as u64 + 1)
+}
+
+unsafe extern "C" fn max_retries(_self: *mut FFIRetryPolicy) -> u32 {
+    1
+}
+
+unsafe extern "C" fn release_context(object: *const ()) {
+    let context = Box::from_raw(object as *mut Context);
+    context.released.store(true, Ordering::SeqCst);
+}
+
+fn total_delay_ms(vtable: &FFIRetryPolicyVTable) -> (u64, bool) {
+    let released = Arc::new(AtomicBool::new(false));
+    let context = ferment::boxed(Context { base_delay_ms: 10, released: Arc::clone(&released) });
+    let total = unsafe {
+        let proxy = example_entry_point_entry_retry_RetryPolicy_Proxy_ctor(context as *const (), vtable, Some(release_context));
+        example_entry_point_entry_retry_total_retry_delay_ms(proxy)
+    };
+    (total, released.load(Ordering::SeqCst))
+}
+
+// Null default entry falls back to the Rust implementation, the context is released with the proxy
+#[test]
+fn proxy_falls_back_to_default_method() {
+    let vtable = FFIRetryPolicyVTable { max_retries: None, delay_ms };
+    let (total, released) = total_delay_ms(&vtable);
+    assert_eq!(total, 10 + 20 + 30);
+    assert!(released);
+}
+
+// Non-null default entry overrides the Rust implementation
+#[test]
+fn proxy_overrides_default_method() {
+    let vtable = FFIRetryPolicyVTable { max_retries: Some(max_retries), delay_ms };
+    let (total, released) = total_delay_ms(&vtable);
+    assert_eq!(total, 10);
+    assert!(released);
+}
@@ -6,6 +6,7 @@ fn main() {
     match ferment_sys::Ferment::with_crate_name(SELF_NAME)
         .with_default_mod_name()
         .with_cbindgen_config_from_file("cbindgen.toml")
+        .with_panic_policy(ferment_sys::PanicPolicy::CatchUnwind)
         .with_external_crates(vec![
             "versioned-feature-core",
             "example-simple",
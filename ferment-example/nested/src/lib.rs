@@ -5,6 +5,7 @@ mod gen;
 mod entry;
 mod state_transition;
 pub mod custom;
+pub mod reprs;
 // mod sync_state;
 
 extern crate ferment_macro;
@@ -220,4 +221,13 @@ impl Manager {
 #[ferment_macro::export]
 pub fn identity_public_key_test(identity: Identity) -> IdentityPublicKey {
     identity.public_keys().first_key_value().expect("").1.clone()
-}
\ No newline at end of file
+}
+#[ferment_macro::export]
+pub fn checked_divide(dividend: u32, divisor: u32) -> u32 {
+    dividend / divisor
+}
+
+#[ferment_macro::export]
+pub fn greeting_at(index: u32) -> String {
+    ["hello", "hola", "bonjour"][index as usize].to_string()
+}
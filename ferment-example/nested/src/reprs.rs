@@ -0,0 +1,52 @@
+#[derive(Clone, Debug, PartialEq)]
+#[ferment_macro::export]
+pub struct BoundId(pub u32);
+
+#[derive(Clone, Debug, PartialEq)]
+#[repr(u8)]
+#[ferment_macro::export]
+pub enum ContractBounds {
+    SingleContract { id: BoundId } = 0,
+    SingleContractDocumentType { id: BoundId, document_type_name: String } = 1,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[repr(i32)]
+#[ferment_macro::export]
+pub enum MixedVariants {
+    Empty = -1,
+    Pair(u32, String) = 10,
+    Named { flag: bool, name: Option<String> } = 20,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[repr(u64)]
+#[ferment_macro::export]
+pub enum WideTag {
+    Small = 1,
+    Large(u64) = 1 << 40,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[ferment_macro::export]
+pub enum SparseKeyType {
+    EcdsaSecp256k1 = 0,
+    Bls12_381 = 4,
+    EddsaHash160 = 7,
+}
+
+#[ferment_macro::export]
+pub fn next_key_type(key_type: SparseKeyType) -> SparseKeyType {
+    match key_type {
+        SparseKeyType::EcdsaSecp256k1 => SparseKeyType::Bls12_381,
+        SparseKeyType::Bls12_381 => SparseKeyType::EddsaHash160,
+        SparseKeyType::EddsaHash160 => SparseKeyType::EcdsaSecp256k1,
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[ferment_macro::export]
+pub struct KeySlot {
+    pub key_type: SparseKeyType,
+    pub fallback: Option<SparseKeyType>,
+}
@@ -1,3 +1,4 @@
+use std::os::raw::c_void;
 use ferment::FFIConversionTo;
 
 // Callback: u32 -> Option<[u8; 32]>
@@ -9,7 +10,7 @@ fn callback_u32_to_opt_hash_smoke() {
     };
 
     // Caller returns None for odd numbers, Some([x;32]) for even numbers
-    unsafe extern "C" fn caller(x: u32) -> *mut Arr_u8_32 {
+    unsafe extern "C" fn caller(_context: *const c_void, x: u32) -> *mut Arr_u8_32 {
         if x % 2 == 0 {
             let arr = [x as u8; 32];
             <Arr_u8_32 as FFIConversionTo<[u8; 32]>>::ffi_to(arr)
@@ -26,7 +27,7 @@ fn callback_u32_to_opt_hash_smoke() {
     }
 
     // SAFETY: Construct the FFI callback wrapper
-    let cb: *mut Fn_ARGS_u32_RTRN_Option_u8_32 = unsafe { Fn_ARGS_u32_RTRN_Option_u8_32_ctor(caller, destructor) };
+    let cb: *mut Fn_ARGS_u32_RTRN_Option_u8_32 = unsafe { Fn_ARGS_u32_RTRN_Option_u8_32_ctor(std::ptr::null(), caller, destructor, None) };
     assert!(!cb.is_null());
 
     // SAFETY: Exercise the call path directly
@@ -57,7 +58,7 @@ fn callback_two_args_to_opt_string_smoke() {
     static ARG_FREES: AtomicUsize = AtomicUsize::new(0);
 
     // Caller frees arg Arr_u8_32 and returns a C string for even x, otherwise null
-    unsafe extern "C" fn caller(x: u32, arr: *mut Arr_u8_32) -> *mut c_char {
+    unsafe extern "C" fn caller(_context: *const c_void, x: u32, arr: *mut Arr_u8_32) -> *mut c_char {
         if !arr.is_null() {
             // free the passed-in array to avoid leaks
             ferment::unbox_any(arr);
@@ -77,7 +78,7 @@ fn callback_two_args_to_opt_string_smoke() {
     }
 
     let cb: *mut Fn_ARGS_u32_Arr_u8_32_RTRN_Option_String = unsafe {
-        Fn_ARGS_u32_Arr_u8_32_RTRN_Option_String_ctor(caller, destructor)
+        Fn_ARGS_u32_Arr_u8_32_RTRN_Option_String_ctor(std::ptr::null(), caller, destructor, None)
     };
     assert!(!cb.is_null());
 
@@ -111,7 +112,7 @@ fn callback_fnmut_two_args_to_opt_string_smoke() {
     static DESTRUCTOR_CALLS: AtomicUsize = AtomicUsize::new(0);
     static ARG_FREES: AtomicUsize = AtomicUsize::new(0);
 
-    unsafe extern "C" fn caller(x: u32, arr: *mut Arr_u8_32) -> *mut c_char {
+    unsafe extern "C" fn caller(_context: *const c_void, x: u32, arr: *mut Arr_u8_32) -> *mut c_char {
         if !arr.is_null() {
             ferment::unbox_any(arr);
             ARG_FREES.fetch_add(1, Ordering::Relaxed);
@@ -130,7 +131,7 @@ fn callback_fnmut_two_args_to_opt_string_smoke() {
     }
 
     let cb: *mut FnMut_ARGS_u32_Arr_u8_32_RTRN_Option_String = unsafe {
-        FnMut_ARGS_u32_Arr_u8_32_RTRN_Option_String_ctor(caller, destructor)
+        FnMut_ARGS_u32_Arr_u8_32_RTRN_Option_String_ctor(std::ptr::null(), caller, destructor, None)
     };
     assert!(!cb.is_null());
 
@@ -165,7 +166,7 @@ fn callback_args_to_result_u32_protocol_error_smoke() {
     use example_simple::errors::protocol_error::ProtocolError;
     use example_simple::state_transition::errors::invalid_identity_public_key_type_error::InvalidIdentityPublicKeyTypeError;
 
-    unsafe extern "C" fn caller(_a: *mut Arr_u8_32, _b: *mut Arr_u8_32) -> *mut FFIResWrapper {
+    unsafe extern "C" fn caller(_context: *const c_void, _a: *mut Arr_u8_32, _b: *mut Arr_u8_32) -> *mut FFIResWrapper {
         // produce Ok(7)
         <FFIResWrapper as FFIConversionTo<Result<u32, ProtocolError>>>::ffi_to(Ok(7u32))
     }
@@ -175,7 +176,7 @@ fn callback_args_to_result_u32_protocol_error_smoke() {
         }
     }
 
-    let cb: *mut FnRes = unsafe { FnResCtor(caller, destructor) };
+    let cb: *mut FnRes = unsafe { FnResCtor(std::ptr::null(), caller, destructor, None) };
     assert!(!cb.is_null());
 
     // SAFETY: Exercise call; wrapper will convert to Result and call destructor
@@ -183,12 +184,12 @@ fn callback_args_to_result_u32_protocol_error_smoke() {
     assert_eq!(res, Ok(7u32));
 
     // Now a variant that returns Err(…)
-    unsafe extern "C" fn caller_err(_a: *mut Arr_u8_32, _b: *mut Arr_u8_32) -> *mut FFIResWrapper {
+    unsafe extern "C" fn caller_err(_context: *const c_void, _a: *mut Arr_u8_32, _b: *mut Arr_u8_32) -> *mut FFIResWrapper {
         // Build a valid ProtocolError variant
         let err = ProtocolError::InvalidPKT(InvalidIdentityPublicKeyTypeError { public_key_type: "boom".to_string() });
         <FFIResWrapper as FFIConversionTo<Result<u32, ProtocolError>>>::ffi_to(Err(err))
     }
-    let cb_err: *mut FnRes = unsafe { FnResCtor(caller_err, destructor) };
+    let cb_err: *mut FnRes = unsafe { FnResCtor(std::ptr::null(), caller_err, destructor, None) };
     let res_err = unsafe { (&*cb_err).call([0u8; 32], [1u8; 32]) };
     assert!(res_err.is_err());
 
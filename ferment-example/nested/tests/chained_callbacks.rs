@@ -1,5 +1,6 @@
 #![allow(clippy::not_unsafe_ptr_arg_deref)]
 
+use std::os::raw::c_void;
 use ferment::FFIConversionTo;
 
 // Chain two exported callback-based calls sequentially
@@ -14,23 +15,23 @@ fn chain_lookup_then_merkle() {
     };
 
     // First callback: height -> Option<[u8;32]>
-    unsafe extern "C" fn height_to_hash(h: u32) -> *mut Arr_u8_32 {
+    unsafe extern "C" fn height_to_hash(_context: *const c_void, h: u32) -> *mut Arr_u8_32 {
         if h == 0 { std::ptr::null_mut() } else { <Arr_u8_32 as FFIConversionTo<[u8;32]>>::ffi_to([h as u8; 32]) }
     }
     unsafe extern "C" fn drop_arr(ptr: *mut Arr_u8_32) { if !ptr.is_null() { ferment::unbox_any(ptr) } }
 
-    let cb1 = unsafe { Fn_ARGS_u32_RTRN_Option_u8_32_ctor(height_to_hash, drop_arr) };
+    let cb1 = unsafe { Fn_ARGS_u32_RTRN_Option_u8_32_ctor(std::ptr::null(), height_to_hash, drop_arr, None) };
     assert!(!cb1.is_null());
     // Directly call the wrapper and chain to the next callback
     let first: Option<[u8; 32]> = unsafe { (&*cb1).call(10) };
     assert!(first.is_some());
 
     // Second callback: hash -> Option<[u8;32]>
-    unsafe extern "C" fn hash_to_root(_h: *mut Arr_u8_32) -> *mut Arr_u8_32 {
+    unsafe extern "C" fn hash_to_root(_context: *const c_void, _h: *mut Arr_u8_32) -> *mut Arr_u8_32 {
         // Always return a constant root
         <Arr_u8_32 as FFIConversionTo<[u8;32]>>::ffi_to([0xAB; 32])
     }
-    let cb2 = unsafe { Fn_ARGS_Arr_u8_32_RTRN_Option_u8_32_ctor(hash_to_root, drop_arr) };
+    let cb2 = unsafe { Fn_ARGS_Arr_u8_32_RTRN_Option_u8_32_ctor(std::ptr::null(), hash_to_root, drop_arr, None) };
     assert!(!cb2.is_null());
     if let Some(h) = first {
         let second = unsafe { (&*cb2).call(h) };
@@ -60,18 +61,18 @@ fn chain_find_desc_then_desc_mut() {
     unsafe extern "C" fn cstr_drop(ptr: *mut c_char) { if !ptr.is_null() { ferment::fermented::types::str_destroy(ptr) } }
 
     // Fn variant
-    unsafe extern "C" fn desc(_h: u32, _hash: *mut Arr_u8_32) -> *mut c_char {
+    unsafe extern "C" fn desc(_context: *const c_void, _h: u32, _hash: *mut Arr_u8_32) -> *mut c_char {
         <c_char as FFIConversionTo<&str>>::ffi_to("desc")
     }
-    let cb1: *mut Fn_ARGS_u32_Arr_u8_32_RTRN_Option_String = unsafe { Fn_ARGS_u32_Arr_u8_32_RTRN_Option_String_ctor(desc, cstr_drop) };
+    let cb1: *mut Fn_ARGS_u32_Arr_u8_32_RTRN_Option_String = unsafe { Fn_ARGS_u32_Arr_u8_32_RTRN_Option_String_ctor(std::ptr::null(), desc, cstr_drop, None) };
     let r1 = unsafe { (&*cb1).call(1, [0u8; 32]) };
     assert_eq!(r1.as_deref(), Some("desc"));
 
     // FnMut variant
-    unsafe extern "C" fn desc_mut(_h: u32, _hash: *mut Arr_u8_32) -> *mut c_char {
+    unsafe extern "C" fn desc_mut(_context: *const c_void, _h: u32, _hash: *mut Arr_u8_32) -> *mut c_char {
         <c_char as FFIConversionTo<&str>>::ffi_to("desc_mut")
     }
-    let cb2: *mut FnMut_ARGS_u32_Arr_u8_32_RTRN_Option_String = unsafe { FnMut_ARGS_u32_Arr_u8_32_RTRN_Option_String_ctor(desc_mut, cstr_drop) };
+    let cb2: *mut FnMut_ARGS_u32_Arr_u8_32_RTRN_Option_String = unsafe { FnMut_ARGS_u32_Arr_u8_32_RTRN_Option_String_ctor(std::ptr::null(), desc_mut, cstr_drop, None) };
     let r2 = unsafe { (&*cb2).call(2, [1u8; 32]) };
     assert_eq!(r2.as_deref(), Some("desc_mut"));
 
@@ -93,12 +94,12 @@ fn chain_should_process_diff_twice() {
     };
     use example_simple::errors::protocol_error::ProtocolError;
 
-    unsafe extern "C" fn ok_caller(_a: *mut Arr_u8_32, _b: *mut Arr_u8_32) -> *mut FFIRes {
+    unsafe extern "C" fn ok_caller(_context: *const c_void, _a: *mut Arr_u8_32, _b: *mut Arr_u8_32) -> *mut FFIRes {
         <FFIRes as FFIConversionTo<Result<u32, ProtocolError>>>::ffi_to(Ok(9u32))
     }
     unsafe extern "C" fn res_drop(ptr: *mut FFIRes) { if !ptr.is_null() { ferment::unbox_any(ptr) } }
 
-    let cb: *mut FnRes = unsafe { FnResCtor(ok_caller, res_drop) };
+    let cb: *mut FnRes = unsafe { FnResCtor(std::ptr::null(), ok_caller, res_drop, None) };
     // Invoke twice via direct wrapper method
     let a = [1u8; 32];
     let b = [2u8; 32];
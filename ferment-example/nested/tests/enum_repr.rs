@@ -0,0 +1,68 @@
+use ferment::{FFIConversionFrom, FFIConversionTo};
+use example_nested::reprs::{BoundId, ContractBounds, MixedVariants, SparseKeyType, WideTag};
+use example_nested::fermented::types::example_nested::reprs::{example_nested_reprs_ContractBounds, example_nested_reprs_MixedVariants, example_nested_reprs_SparseKeyType, example_nested_reprs_WideTag};
+
+/// Round-trips the value and returns the tag of the FFI enum
+unsafe fn roundtrip_tag<T, FFI, Tag>(original: T) -> Tag
+    where T: Clone + PartialEq + std::fmt::Debug,
+          FFI: FFIConversionFrom<T> + FFIConversionTo<T>,
+          Tag: Copy {
+    let ffi_ptr = <FFI as FFIConversionTo<T>>::ffi_to_const(original.clone());
+    assert!(!ffi_ptr.is_null());
+    let decoded = <FFI as FFIConversionFrom<T>>::ffi_from_const(ffi_ptr);
+    assert_eq!(decoded, original);
+    let tag = *(ffi_ptr as *const Tag);
+    ferment::unbox_any(ffi_ptr.cast_mut());
+    tag
+}
+
+// #[repr(u8)] with struct-like variants
+#[test]
+fn repr_u8_struct_variants_roundtrip_and_free() {
+    let cases = vec![
+        (ContractBounds::SingleContract { id: BoundId(1) }, 0u8),
+        (ContractBounds::SingleContractDocumentType { id: BoundId(2), document_type_name: "note".into() }, 1u8),
+    ];
+    for (case, tag) in cases {
+        assert_eq!(unsafe { roundtrip_tag::<_, example_nested_reprs_ContractBounds, u8>(case) }, tag);
+    }
+}
+
+// #[repr(i32)] with unit, tuple and struct variants
+#[test]
+fn repr_i32_mixed_variants_roundtrip_and_free() {
+    let cases = vec![
+        (MixedVariants::Empty, -1i32),
+        (MixedVariants::Pair(7, "seven".into()), 10),
+        (MixedVariants::Named { flag: true, name: None }, 20),
+        (MixedVariants::Named { flag: false, name: Some("named".into()) }, 20),
+    ];
+    for (case, tag) in cases {
+        assert_eq!(unsafe { roundtrip_tag::<_, example_nested_reprs_MixedVariants, i32>(case) }, tag);
+    }
+}
+
+// #[repr(u64)] with discriminants which don't fit into C int
+#[test]
+fn repr_u64_wide_discriminants_roundtrip_and_free() {
+    let cases = vec![
+        (WideTag::Small, 1u64),
+        (WideTag::Large(u64::MAX), 1 << 40),
+    ];
+    for (case, tag) in cases {
+        assert_eq!(unsafe { roundtrip_tag::<_, example_nested_reprs_WideTag, u64>(case) }, tag);
+    }
+}
+
+// Fieldless enum with sparse discriminants
+#[test]
+fn fieldless_discriminants_roundtrip_and_free() {
+    let cases = vec![
+        (SparseKeyType::EcdsaSecp256k1, 0i32),
+        (SparseKeyType::Bls12_381, 4),
+        (SparseKeyType::EddsaHash160, 7),
+    ];
+    for (case, tag) in cases {
+        assert_eq!(unsafe { roundtrip_tag::<_, example_nested_reprs_SparseKeyType, i32>(case) }, tag);
+    }
+}
@@ -0,0 +1,23 @@
+use ferment::{FFIConversionFrom, FFIConversionTo};
+use example_nested::reprs::{KeySlot, SparseKeyType};
+use example_nested::fermented::types::example_nested::reprs::{example_nested_reprs_KeySlot, example_nested_reprs_KeySlot_get_key_type, example_nested_reprs_SparseKeyType, example_nested_reprs_next_key_type};
+
+// Fieldless enums are passed by value
+#[test]
+fn fieldless_enum_by_value() {
+    let next = unsafe { example_nested_reprs_next_key_type(example_nested_reprs_SparseKeyType::Bls12_381) };
+    assert!(next == example_nested_reprs_SparseKeyType::EddsaHash160);
+    assert_eq!(next as i32, 7);
+}
+
+#[test]
+fn fieldless_enum_fields_roundtrip_and_free() {
+    let slot = KeySlot { key_type: SparseKeyType::Bls12_381, fallback: Some(SparseKeyType::EcdsaSecp256k1) };
+    unsafe {
+        let ffi_ptr = <example_nested_reprs_KeySlot as FFIConversionTo<KeySlot>>::ffi_to(slot.clone());
+        assert!(example_nested_reprs_KeySlot_get_key_type(ffi_ptr) == example_nested_reprs_SparseKeyType::Bls12_381);
+        assert!(*(*ffi_ptr).fallback == example_nested_reprs_SparseKeyType::EcdsaSecp256k1);
+        assert_eq!(<example_nested_reprs_KeySlot as FFIConversionFrom<KeySlot>>::ffi_from_const(ffi_ptr), slot);
+        ferment::unbox_any(ffi_ptr);
+    }
+}
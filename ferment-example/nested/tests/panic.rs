@@ -0,0 +1,51 @@
+use std::ffi::CStr;
+use example_nested::fermented::types::example_nested::{example_nested_checked_divide, example_nested_greeting_at};
+
+unsafe fn take_last_error() -> Option<String> {
+    let ptr = ferment::ferment_last_error();
+    (!ptr.is_null()).then(|| {
+        let message = CStr::from_ptr(ptr).to_string_lossy().into_owned();
+        ferment::fermented::types::str_destroy(ptr);
+        message
+    })
+}
+
+#[test]
+fn panicking_fn_returns_zeroed_value_and_sets_last_error() {
+    unsafe {
+        assert_eq!(example_nested_checked_divide(10, 2), 5);
+        assert!(!ferment::ferment_has_last_error());
+
+        assert_eq!(example_nested_checked_divide(10, 0), 0);
+        assert!(ferment::ferment_has_last_error());
+        let message = take_last_error().expect("last error");
+        assert!(message.contains("divide by zero"), "unexpected message: {message}");
+        assert!(take_last_error().is_none());
+    }
+}
+
+#[test]
+fn panicking_fn_returns_null_pointer_and_sets_last_error() {
+    unsafe {
+        let ok = example_nested_greeting_at(1);
+        assert!(!ok.is_null());
+        assert_eq!(CStr::from_ptr(ok).to_str().unwrap(), "hola");
+        ferment::fermented::types::str_destroy(ok);
+
+        let failed = example_nested_greeting_at(42);
+        assert!(failed.is_null());
+        let message = take_last_error().expect("last error");
+        assert!(message.contains("index out of bounds"), "unexpected message: {message}");
+    }
+}
+
+#[test]
+fn last_error_can_be_cleared() {
+    unsafe {
+        example_nested_checked_divide(1, 0);
+        assert!(ferment::ferment_has_last_error());
+        ferment::ferment_clear_last_error();
+        assert!(!ferment::ferment_has_last_error());
+        assert!(take_last_error().is_none());
+    }
+}
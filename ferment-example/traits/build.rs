@@ -0,0 +1,13 @@
+extern crate cbindgen;
+extern crate ferment_sys;
+
+pub const SELF_NAME: &str = "example_traits";
+fn main() {
+   match ferment_sys::Ferment::with_crate_name(SELF_NAME)
+       .with_default_mod_name()
+       .with_header_path(format!("target/{SELF_NAME}.h"))
+       .generate() {
+      Ok(_) => println!("[ferment-sys] [ok] generated"),
+      Err(err) => panic!("[ferment-sys] [error] {}", err)
+   }
+}
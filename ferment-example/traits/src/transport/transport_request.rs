@@ -35,6 +35,49 @@ impl CanRetry for CoreGrpcClient {
     }
 }
 
+#[ferment_macro::export]
+pub trait Backoff {
+    fn base_delay_ms(&self) -> u64;
+    fn max_retries(&self) -> u32 {
+        3
+    }
+    fn delay_ms(&self, attempt: u32) -> u64 {
+        self.base_delay_ms() << attempt.min(self.max_retries())
+    }
+}
+
+#[ferment_macro::export]
+impl Backoff for CoreGrpcClient {
+    fn base_delay_ms(&self) -> u64 {
+        100
+    }
+}
+
+#[ferment_macro::export]
+impl Backoff for Identifier {
+    fn base_delay_ms(&self) -> u64 {
+        self.0 as u64
+    }
+    fn max_retries(&self) -> u32 {
+        5
+    }
+}
+
+#[ferment_macro::export]
+pub fn total_delay_ms(backoff: impl Backoff) -> u64 {
+    (0..backoff.max_retries()).map(|attempt| backoff.delay_ms(attempt)).sum()
+}
+
+#[ferment_macro::export]
+pub fn should_retry(policy: &dyn CanRetry) -> bool {
+    policy.can_retry()
+}
+
+#[ferment_macro::export]
+pub fn shared_backoff_delay_ms(backoff: std::sync::Arc<dyn Backoff + Send + Sync>) -> u64 {
+    backoff.delay_ms(0)
+}
+
 // #[ferment_macro::export]
 pub trait SomeOtherTrait {
     fn some_other_method(&self);
@@ -0,0 +1,74 @@
+#![allow(clippy::not_unsafe_ptr_arg_deref)]
+
+use example_traits::fermented::types::example_traits::transport::transport_request::{
+    example_traits_transport_transport_request_Backoff as FFIBackoff,
+    example_traits_transport_transport_request_Backoff_VTable as FFIBackoffVTable,
+    example_traits_transport_transport_request_Backoff_Proxy_ctor,
+    example_traits_transport_transport_request_Backoff_Proxy_destroy,
+    example_traits_transport_transport_request_total_delay_ms,
+};
+use example_traits::transport::transport_request::Backoff;
+
+// Foreign context of the implementation: the proxy passes it back to the vtable entries
+struct Context {
+    base_delay_ms: u64,
+}
+
+unsafe fn context<'a>(self_: *mut FFIBackoff) -> &'a Context {
+    // the trait object is #[repr(C)] and starts with the context pointer
+    &*(*(self_ as *const *const ()) as *const Context)
+}
+
+unsafe extern "C" fn base_delay_ms(self_: *mut FFIBackoff) -> u64 {
+    context(self_).base_delay_ms
+}
+
+unsafe extern "C" fn max_retries(_self: *mut FFIBackoff) -> u32 {
+    1
+}
+
+unsafe extern "C" fn delay_ms(self_: *mut FFIBackoff, attempt: u32) -> u64 {
+    context(self_).base_delay_ms + attempt as u64
+}
+
+unsafe extern "C" fn release_context(object: *const ()) {
+    drop(Box::from_raw(object as *mut Context));
+}
+
+fn new_context() -> *const () {
+    ferment::boxed(Context { base_delay_ms: 10 }) as *const ()
+}
+
+// Null entries of the default methods fall back to the default bodies of the trait
+#[test]
+fn null_entries_fall_back_to_default_methods() {
+    let vtable = FFIBackoffVTable { base_delay_ms, max_retries: None, delay_ms: None };
+    let total = unsafe {
+        let proxy = example_traits_transport_transport_request_Backoff_Proxy_ctor(new_context(), &vtable, Some(release_context));
+        example_traits_transport_transport_request_total_delay_ms(proxy)
+    };
+    assert_eq!(total, 10 + 20 + 40);
+}
+
+// Default body calls the foreign entries of the other methods
+#[test]
+fn default_method_calls_foreign_overrides() {
+    let vtable = FFIBackoffVTable { base_delay_ms, max_retries: Some(max_retries), delay_ms: None };
+    unsafe {
+        let proxy = example_traits_transport_transport_request_Backoff_Proxy_ctor(new_context(), &vtable, Some(release_context));
+        assert_eq!((*proxy).max_retries(), 1);
+        assert_eq!((*proxy).delay_ms(5), 10 << 1);
+        example_traits_transport_transport_request_Backoff_Proxy_destroy(proxy);
+    }
+}
+
+// Non-null entries of the default methods override the default bodies
+#[test]
+fn non_null_entries_override_default_methods() {
+    let vtable = FFIBackoffVTable { base_delay_ms, max_retries: Some(max_retries), delay_ms: Some(delay_ms) };
+    let total = unsafe {
+        let proxy = example_traits_transport_transport_request_Backoff_Proxy_ctor(new_context(), &vtable, Some(release_context));
+        example_traits_transport_transport_request_total_delay_ms(proxy)
+    };
+    assert_eq!(total, 10);
+}
@@ -23,6 +23,30 @@ use syn::token::Comma;
 /// }
 /// ```
 ///
+/// Structs and enums can list the formatting traits they implement (`Display`, `Debug`) if the implementations
+/// can't be found in the crate, so they get the `_to_string` and `_debug_string` bindings:
+///
+/// ```ignore
+/// #[ferment_macro::export(Display, Debug)]
+/// pub struct Version { pub major: u32 }
+/// ```
+///
+/// Structs labeled with `handle` are passed as the boxed Rust values, so the methods taking `&self` and `&mut self`
+/// borrow them without converting the FFI struct. The FFI struct is still available with `<Type>_to_ffi`:
+///
+/// ```ignore
+/// #[ferment_macro::export(handle)]
+/// pub struct State { pub count: u32 }
+/// ```
+///
+/// Generic structs and enums are fermented per instantiation. The ones which aren't used in the other
+/// exported signatures can be listed:
+///
+/// ```ignore
+/// #[ferment_macro::export(instantiate(Page<Identity>, Page<Document>))]
+/// pub struct Page<T> { pub items: Vec<T> }
+/// ```
+///
 /// # Output
 ///
 /// The macro will automatically generate additional FFI-compatible code around the annotated function.
@@ -100,6 +124,26 @@ pub fn opaque(_attr: TokenStream, input: TokenStream) -> TokenStream {
     TokenStream::from(expanded)
 }
 
+/// Opts the item (or the module) out of the module-level export:
+/// items of the module labeled with `#[ferment_macro::export]` are exported unless they're labeled with `skip`.
+///
+/// Inline modules can be labeled directly, file modules need the inner attribute at the top of the file
+/// (`#![ferment_macro::export]` requires `custom_inner_attributes`, use `#![cfg_attr(ferment, ferment_macro::export)]` on stable:
+/// the `ferment` cfg option is only set while the sources are fermented)
+///
+/// ```ignore
+/// #[ferment_macro::export]
+/// pub mod model {
+///     pub struct Exported { pub id: u32 }
+///     #[ferment_macro::skip]
+///     pub struct Internal { pub id: u32 }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn skip(_attr: TokenStream, input: TokenStream) -> TokenStream {
+    input
+}
+
 
 #[proc_macro_derive(CompositionContext)]
 pub fn composition_context_derive(input: TokenStream) -> TokenStream {
@@ -1,5 +1,7 @@
-use crate::{Config, Crate, error, Lang};
-use crate::lang::rust::find_crates_paths;
+use std::path::PathBuf;
+use crate::{AsyncMode, CfgMode, Config, Crate, Diagnostic, DiagnosticPolicy, error, FermentateLayout, Lang, PanicPolicy};
+use crate::config::DEFAULT_CBINDGEN_CONFIG_FILE;
+use crate::lang::rust::{build_script_cfg, build_script_features, find_crates_paths};
 use crate::writer::Writer;
 
 extern crate env_logger;
@@ -12,9 +14,15 @@ pub struct Builder {
 }
 
 impl Builder {
-    pub fn new(current_crate: Crate) -> Builder {
+    pub fn new(mut current_crate: Crate) -> Builder {
         env_logger::init();
-        Builder { config: Config::new(DEFAULT_FERMENTATE_MOD, current_crate, Default::default()) }
+        if current_crate.features.is_none() {
+            current_crate.features = build_script_features();
+        }
+        let mut config = Config::new(DEFAULT_FERMENTATE_MOD, current_crate, Default::default());
+        config.cbindgen_config_from_file = Some(String::from(DEFAULT_CBINDGEN_CONFIG_FILE));
+        config.target_cfg = build_script_cfg();
+        Builder { config }
     }
     #[allow(unused)]
     pub fn with_crate_name(crate_name: &str) -> Builder {
@@ -26,16 +34,25 @@ impl Builder {
         self.config.mod_name = String::from(DEFAULT_FERMENTATE_MOD);
         self
     }
+    /// Generates the header with this config instead of a config file
     #[allow(unused)]
     pub fn with_cbindgen_config(mut self, config: cbindgen::Config) -> Builder {
         self.config.cbindgen_config = config;
+        self.config.cbindgen_config_from_file = None;
         self
     }
+    /// Generates the header with the config file (`cbindgen.toml` by default)
     #[allow(unused)]
     pub fn with_cbindgen_config_from_file(mut self, config: &'static str) -> Builder {
         self.config.cbindgen_config_from_file = Some(config.to_string());
         self
     }
+    /// Writes the C header into this file instead of `target/include/{crate_name}.h`
+    #[allow(unused)]
+    pub fn with_header_path<P: Into<PathBuf>>(mut self, path: P) -> Builder {
+        self.config.header_path = Some(path.into());
+        self
+    }
     #[allow(unused)]
     pub fn with_mod_name<S: AsRef<str>>(mut self, mod_name: S) -> Builder {
         self.config.mod_name = String::from(mod_name.as_ref());
@@ -43,13 +60,19 @@ impl Builder {
     }
 
     #[allow(unused)]
-    pub fn with_crates(mut self, crates: Vec<&str>) -> Builder {
-        self.config.external_crates = find_crates_paths(crates);
-        self
+    pub fn with_crates(self, crates: Vec<&str>) -> Builder {
+        self.with_external_crates(crates)
     }
+    /// Ferments these dependencies of the current crate along with it.
+    /// Crates are named as they're used in the code (the renamed dependencies are named by their new names)
+    /// or by their package names, and resolved with the dependency graph of the current package.
+    /// Crates which can't be resolved (unknown, ambiguous versions, no library target)
+    /// fail `generate` and `check` with `error::Error::Configuration` before anything is written
     #[allow(unused)]
     pub fn with_external_crates(mut self, crates: Vec<&str>) -> Builder {
-        self.config.external_crates = find_crates_paths(crates);
+        let (external_crates, unresolved_crates) = find_crates_paths(&self.config.current_crate.name, crates);
+        self.config.external_crates = external_crates;
+        self.config.unresolved_crates = unresolved_crates;
         self
     }
 
@@ -59,6 +82,50 @@ impl Builder {
         self
     }
 
+    pub fn with_panic_policy(mut self, policy: PanicPolicy) -> Builder {
+        self.config.panic_policy = policy;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_async_mode(mut self, mode: AsyncMode) -> Builder {
+        self.config.async_mode = mode;
+        self
+    }
+
+    /// Sets the `ferment::Executor` implementation (e.g. `"my_crate::LocalExecutor"`)
+    /// the async bindings cast their `runtime` pointer to
+    #[allow(unused)]
+    pub fn with_executor<P: AsRef<str>>(mut self, path: P) -> Builder {
+        self.config.executor = String::from(path.as_ref());
+        self
+    }
+
+    /// Sets how the Rust fermentate is written: a single line (default), a single formatted file
+    /// or a formatted directory of module files
+    #[allow(unused)]
+    pub fn with_layout(mut self, layout: FermentateLayout) -> Builder {
+        self.config.layout = layout;
+        self
+    }
+
+    /// Sets what happens with the exported items which can't be fermented:
+    /// they're skipped and reported (default), also reported as `cargo:warning=` lines, or fail the generation
+    #[allow(unused)]
+    pub fn with_diagnostic_policy(mut self, policy: DiagnosticPolicy) -> Builder {
+        self.config.diagnostic_policy = policy;
+        self
+    }
+
+    /// Sets whether the `#[cfg(...)]` predicates are evaluated against the build configuration
+    /// (enabled features and target cfg options of the build script) to skip the disabled items,
+    /// or all the branches are kept (default)
+    #[allow(unused)]
+    pub fn with_cfg_mode(mut self, mode: CfgMode) -> Builder {
+        self.config.cfg_mode = mode;
+        self
+    }
+
     /// Reads rust file and its nested dependencies
     /// Creates syntax tree which we'll use later
     /// to handle imports for FFI converted types
@@ -81,6 +148,8 @@ impl Builder {
     ///
     /// If the function encounters any errors while reading the file, processing the syntax,
     /// or writing to the output file, it will return an `error::Error`.
+    /// Exported items with unsupported constructs are skipped: their diagnostics are returned
+    /// on success, or as `error::Error::Diagnostics` with `DiagnosticPolicy::Deny`.
     ///
     /// # Example
     ///
@@ -106,13 +175,26 @@ impl Builder {
     ///
     /// # Remarks
     ///
-    /// This function expects the primary Rust file to be named `lib.rs` and located inside
-    /// the `src/` directory. Any deviation from this naming and structure might lead to errors.
+    /// The primary Rust file is the library target of the crate (`src/lib.rs` by default),
+    /// the module files are resolved the same way rustc does.
     ///
     /// The resulting module will only contain the necessary imports and types suitable for FFI kind.
     ///
-    pub fn generate(self) -> Result<(), error::Error> {
+    pub fn generate(self) -> Result<Vec<Diagnostic>, error::Error> {
         Writer::from(self.config)
             .write_all()
     }
+
+    /// Runs the same pipeline as [`Builder::generate`] but doesn't write anything:
+    /// compares the Rust fermentate and the C header with the ones on disk.
+    ///
+    /// # Errors
+    ///
+    /// Returns `error::Error::Stale` listing the added, removed and changed items of every stale file.
+    /// The header is generated from the sources on disk, so it's compared against the current fermentate
+    #[allow(unused)]
+    pub fn check(self) -> Result<(), error::Error> {
+        Writer::from(self.config)
+            .check_all()
+    }
 }
@@ -1,8 +1,39 @@
 #[cfg(feature = "cbindgen")]
 impl crate::writer::Writer {
     pub(crate) fn write_headers(&self) -> Result<(), crate::Error> {
-        use std::process::Command;
-        let crate::Config { current_crate: crate::Crate { name: framework, .. }, cbindgen_config_from_file, .. } = &self.config;
+        let header = self.generate_header()?;
+        let path = self.header_path();
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, header)
+            .map_err(crate::Error::from)
+    }
+
+    pub(crate) fn check_headers(&self) -> Result<Vec<crate::StaleFile>, crate::Error> {
+        let header = self.generate_header()?;
+        let path = self.header_path();
+        let actual = crate::writer::read_existing(&path)?;
+        Ok(crate::StaleFile::header(path, &String::from_utf8_lossy(&header), actual.as_deref()).into_iter().collect())
+    }
+
+    /// Runs cbindgen over the crate in the current directory
+    fn generate_header(&self) -> Result<Vec<u8>, crate::Error> {
+        let bindings = cbindgen::Builder::new()
+            .with_crate(std::env::current_dir()?)
+            .with_config(self.config.resolve_cbindgen_config()?)
+            .generate()
+            .map_err(crate::Error::Cbindgen)?;
+        let mut header = vec![];
+        bindings.write(&mut header);
+        Ok(header)
+    }
+
+    fn header_path(&self) -> std::path::PathBuf {
+        if let Some(path) = &self.config.header_path {
+            return path.clone();
+        }
+        let crate::Config { current_crate: crate::Crate { name: framework, .. }, .. } = &self.config;
         let framework = {
             #[cfg(feature = "objc")]
             {
@@ -13,17 +44,6 @@ impl crate::writer::Writer {
                 framework.clone()
             }
         };
-        Command::new("mkdir")
-            .args(["-p", "target/include"])
-            .status()?;
-        Command::new("cbindgen")
-            .args([
-                "--config", cbindgen_config_from_file.as_ref().map(String::as_str).unwrap_or("cbindgen.toml"),
-                "-o", format!("target/include/{framework}.h").as_str()
-            ])
-            .status()
-            .map_err(crate::Error::from)
-            .map(|_| ())
+        std::path::PathBuf::from(format!("target/include/{framework}.h"))
     }
-
-}
\ No newline at end of file
+}
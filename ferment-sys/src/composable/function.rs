@@ -10,7 +10,8 @@ pub enum FnSignatureContext {
     TraitImpl(Signature, Type, Type),
     TraitAsType(Signature, Type, Type),
     Bare(Ident, TypeBareFn),
-    TraitInner(Signature, Type, Type)
+    /// The flag marks trait methods with default implementations
+    TraitInner(Signature, Type, Type, bool)
 }
 
 impl Debug for FnSignatureContext {
@@ -26,8 +27,8 @@ impl Debug for FnSignatureContext {
                 f.write_fmt(format_args!("TraitImpl(self: {}, trait: {}, sig: {})", self_ty.to_token_stream(), trait_ty.to_token_stream(), sig.to_token_stream())),
             FnSignatureContext::TraitAsType(sig, self_ty, trait_ty) =>
                 f.write_fmt(format_args!("TraitAsType(self: {}, trait: {}, sig: {})", self_ty.to_token_stream(), trait_ty.to_token_stream(), sig.to_token_stream())),
-            FnSignatureContext::TraitInner(sig, self_ty, trait_ty) =>
-                f.write_fmt(format_args!("TraitInner(self: {}, trait: {}, sig: {})", self_ty.to_token_stream(), trait_ty.to_token_stream(), sig.to_token_stream())),
+            FnSignatureContext::TraitInner(sig, self_ty, trait_ty, has_default) =>
+                f.write_fmt(format_args!("TraitInner(self: {}, trait: {}, sig: {}, default: {})", self_ty.to_token_stream(), trait_ty.to_token_stream(), sig.to_token_stream(), has_default)),
         }
     }
 }
@@ -53,7 +54,7 @@ impl FnSignatureContext {
         match self {
             FnSignatureContext::TraitAsType(.., trait_ty) |
             FnSignatureContext::TraitImpl(.., trait_ty) |
-            FnSignatureContext::TraitInner(.., trait_ty) => trait_ty,
+            FnSignatureContext::TraitInner(_, _, trait_ty, _) => trait_ty,
             FnSignatureContext::Impl(.., self_ty) => self_ty,
             _ => panic!("Receiver in mod fn")
         }
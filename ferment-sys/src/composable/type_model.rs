@@ -1,11 +1,11 @@
 use std::fmt::{Debug, Display, Formatter};
 use proc_macro2::Ident;
 use quote::ToTokens;
-use syn::{Generics, Lifetime, Path, TraitBound, Type, TypePtr, TypeReference, TypeTraitObject};
+use syn::{Generics, Lifetime, Path, TraitBound, Type, TypePath, TypePtr, TypeReference, TypeTraitObject};
 use crate::composable::{NestedArgument, TypeModeled};
 use crate::composer::CommaPunctuatedNestedArguments;
 use crate::context::ScopeChain;
-use crate::ext::{AsType, refine_ty_with_import_path, RefineWithNestedArgs, ToPath, ToType, LifetimeProcessor, ArgsTransform, MaybeTraitBound};
+use crate::ext::{AsType, refine_ty_with_import_path, RefineWithNestedArgs, ToType, LifetimeProcessor, ArgsTransform, MaybeTraitBound};
 
 #[derive(Clone)]
 pub struct TypeModel {
@@ -85,18 +85,25 @@ impl TypeModel {
         &self.nested_arguments[index]
     }
 
-    pub fn pointer_less(&self) -> Path {
-        match &self.ty {
-            Type::Reference(TypeReference { elem, .. }) |
-            Type::Ptr(TypePtr { elem, .. }) => elem.to_path(),
-            Type::TraitObject(TypeTraitObject { bounds, .. }) =>
-                bounds.iter()
-                    .find_map(MaybeTraitBound::maybe_trait_bound)
-                    .map(|TraitBound { path, .. }| path.arg_less())
-                    .unwrap_or_else(|| bounds.to_path()),
-            other =>
-                other.to_path()
-        }
+    /// Types which aren't named by a path (`impl Trait`, `!`, tuples, ..) can't be searched in the scope
+    pub fn pointer_less(&self) -> Option<Path> {
+        pointer_less_path(&self.ty)
+    }
+}
+
+fn pointer_less_path(ty: &Type) -> Option<Path> {
+    match ty {
+        Type::Reference(TypeReference { elem, .. }) |
+        Type::Ptr(TypePtr { elem, .. }) =>
+            pointer_less_path(elem),
+        Type::TraitObject(TypeTraitObject { bounds, .. }) =>
+            bounds.iter()
+                .find_map(MaybeTraitBound::maybe_trait_bound)
+                .map(|TraitBound { path, .. }| path.arg_less()),
+        Type::Path(TypePath { qself: None, path }) =>
+            Some(path.clone()),
+        _ =>
+            None
     }
 }
 
@@ -1,5 +1,6 @@
 use std::cell::Ref;
 use std::fmt::Debug;
+use proc_macro2::Ident;
 use quote::ToTokens;
 use syn::__private::TokenStream2;
 use crate::ast::{CommaPunctuated, Depunctuated};
@@ -114,6 +115,8 @@ pub trait SignatureAspectComposable<Attr, Lt, Gen>: AttrComposable<Attr> + Lifet
 pub trait VariantComposable<SPEC>
     where SPEC: Specification {
     fn compose_variants(&self) -> CommaPunctuated<SeqKind<SPEC>>;
+    fn compose_repr(&self) -> CommaPunctuated<Ident>;
+    fn compose_derives(&self) -> CommaPunctuated<Ident>;
 }
 pub trait InterfaceComposable<T> where T: ToTokens {
     fn compose_interfaces(&self) -> Depunctuated<T>;
@@ -2,6 +2,7 @@ use std::cell::RefCell;
 use std::rc::Rc;
 use quote::{quote, ToTokens};
 use syn::{BareFnArg, Generics, Lifetime, Path, ReturnType, Type, TypeBareFn};
+use syn::spanned::Spanned;
 use ferment_macro::ComposerBase;
 use crate::ast::CommaPunctuated;
 use crate::composable::{AttrsModel, GenModel, LifetimesModel};
@@ -53,7 +54,7 @@ pub fn compose_bare_fn<SPEC>(
     aspect: Aspect<SPEC::TYC>,
     type_bare_fn: &TypeBareFn,
     source: &ScopeContext
-) -> BindingPresentableContext<SPEC>
+) -> Option<BindingPresentableContext<SPEC>>
 where SPEC: Specification<Expr=Expression<SPEC>, Name=Name<SPEC>>,
       SPEC::Expr: ScopeContextPresentable,
       SPEC::Lt: IntoIterator + Extend<<SPEC::Lt as IntoIterator>::Item>,
@@ -78,8 +79,10 @@ where SPEC: Specification<Expr=Expression<SPEC>, Name=Name<SPEC>>,
                     DictionaryExpr::IfThen(quote!((!#ffi_result.is_null())), DictionaryExpr::deref(&ffi_result).to_token_stream()),
                 TypeKind::Generic(GenericTypeKind::Optional(_)) =>
                     DictionaryExpr::IfThen(quote!((!#ffi_result.is_null())), DictionaryExpr::callback_dtor(&ffi_result_conversion, &ffi_result).wrap()),
-                TypeKind::Generic(GenericTypeKind::TraitBounds(_)) =>
-                    unimplemented!("TODO: mixins+traits+generics"),
+                TypeKind::Generic(GenericTypeKind::TraitBounds(_)) => {
+                    source.add_diagnostic(field_type.span(), format!("`{}` isn't supported as a result of the fn pointer", field_type.to_token_stream()));
+                    return None;
+                },
                 TypeKind::Complex(_) |
                 TypeKind::Generic(_) =>
                     DictionaryExpr::callback_dtor(&ffi_result_conversion, &ffi_result)
@@ -94,11 +97,15 @@ where SPEC: Specification<Expr=Expression<SPEC>, Name=Name<SPEC>>,
     let mut arg_to_conversions = CommaPunctuated::new();
     inputs
         .iter()
-        .for_each(|bare_fn_arg| {
+        .enumerate()
+        .for_each(|(index, bare_fn_arg)| {
             let BareFnArg { ty, name, .. } = bare_fn_arg;
             let var_composer = VarComposer::<SPEC>::key_ref_in_composer_scope(ty);
             let var_ty = var_composer.compose(source);
-            let ident_name = Name::<SPEC>::Optional(name.as_ref().map(|(ident, ..)| ident.clone()));
+            let ident_name = match name {
+                Some((ident, ..)) => Name::<SPEC>::Optional(Some(ident.clone())),
+                None => Name::<SPEC>::UnnamedArg(index)
+            };
             arg_names.push(ident_name.to_token_stream());
             arg_target_types.push(ArgPresentation::no_attr_tokens(ty));
             let mut bare_fn_arg_replacement = bare_fn_arg.clone();
@@ -116,7 +123,7 @@ where SPEC: Specification<Expr=Expression<SPEC>, Name=Name<SPEC>>,
             });
             arg_target_fields.push(ArgPresentation::Field(field::<SPEC>(ident_name, ty, source)));
         });
-    BindingPresentableContext::Callback(
+    Some(BindingPresentableContext::Callback(
         aspect,
         signature_aspect,
         full_fn_path.mangle_ident_default(),
@@ -126,5 +133,5 @@ where SPEC: Specification<Expr=Expression<SPEC>, Name=Name<SPEC>>,
         post_processing,
         ffi_return_type,
         ffi_args
-    )
+    ))
 }
\ No newline at end of file
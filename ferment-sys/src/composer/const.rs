@@ -0,0 +1,42 @@
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::rc::Rc;
+use syn::{Attribute, ImplItemConst, ItemConst, ItemStatic, Path, Type};
+use crate::composable::CfgAttributes;
+use crate::composer::ComposerLink;
+use crate::context::ScopeContextLink;
+use crate::lang::Specification;
+
+/// Composes the bindings of the exported `const` and `static` items and the associated consts of the inherent impls:
+/// primitives are exported as `#[no_mangle]` statics, the other types as getters returning the FFI values
+pub struct ConstComposer<SPEC>
+    where SPEC: Specification + 'static {
+    /// Full path of the item (`crate_name::module::NAME` or `crate_name::module::Type::NAME`)
+    pub path: Path,
+    pub ty: Type,
+    pub attrs: Vec<Attribute>,
+    /// Statics are read by reference, so they don't have to be `Copy`
+    pub is_static: bool,
+    pub context: ScopeContextLink,
+    _marker: PhantomData<SPEC>,
+}
+
+impl<SPEC> ConstComposer<SPEC>
+    where SPEC: Specification {
+    fn new(path: Path, ty: &Type, attrs: &[Attribute], is_static: bool, context: &ScopeContextLink) -> ComposerLink<Self> {
+        Rc::new(RefCell::new(Self { path, ty: ty.clone(), attrs: attrs.to_vec().cfg_attributes(), is_static, context: Rc::clone(context), _marker: PhantomData }))
+    }
+    pub fn from_item_const(item_const: &ItemConst, path: Path, context: &ScopeContextLink) -> ComposerLink<Self> {
+        let ItemConst { attrs, ty, .. } = item_const;
+        Self::new(path, ty, attrs, false, context)
+    }
+    pub fn from_item_static(item_static: &ItemStatic, path: Path, context: &ScopeContextLink) -> ComposerLink<Self> {
+        // mutable statics never get here: `Reachability` drops them with a diagnostic
+        let ItemStatic { attrs, ty, .. } = item_static;
+        Self::new(path, ty, attrs, true, context)
+    }
+    pub fn from_impl_item_const(impl_item_const: &ImplItemConst, path: Path, context: &ScopeContextLink) -> ComposerLink<Self> {
+        let ImplItemConst { attrs, ty, .. } = impl_item_const;
+        Self::new(path, ty, attrs, false, context)
+    }
+}
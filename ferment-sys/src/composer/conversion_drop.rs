@@ -1,11 +1,11 @@
 use std::fmt::Debug;
 use quote::quote;
-use syn::{parse_quote, Type, TypeReference};
+use syn::{parse_quote, Type, TypePath, TypeReference};
 use crate::composable::TypeModel;
 use crate::composer::SourceComposable;
 use crate::context::{ScopeContext, ScopeSearch, ScopeSearchKey};
 use crate::kind::{DictFermentableModelKind, DictTypeModelKind, GenericTypeKind, ObjectKind, SmartPointerModelKind, TypeKind, TypeModelKind};
-use crate::ext::{ExpressionComposable, FFISpecialTypeResolve, GenericNestedArg, Primitive, Resolve, ToType};
+use crate::ext::{DictionaryType, ExpressionComposable, FFISpecialTypeResolve, GenericNestedArg, Primitive, Resolve, ToType};
 use crate::lang::Specification;
 use crate::presentable::{ConversionExpressionKind, Expression, ScopeContextPresentable};
 use crate::presentation::{FFIFullDictionaryPath, FFIFullPath};
@@ -82,14 +82,23 @@ where SPEC: Specification<Expr=Expression<SPEC>>,
             _ => match maybe_object
                 .and_then(|kind| kind.maybe_trait_or_same_kind(source))
                 .unwrap_or_else(|| TypeModelKind::unknown_type(search_key.to_type())) {
-                TypeModelKind::Dictionary(DictTypeModelKind::LambdaFn(..)) =>
+                TypeModelKind::Dictionary(DictTypeModelKind::LambdaFn(..)) |
+                TypeModelKind::Dictionary(DictTypeModelKind::NonPrimitiveFermentable(DictFermentableModelKind::Char(..))) |
+                TypeModelKind::FieldlessEnum(..) =>
                     None,
                 TypeModelKind::FnPointer(..) =>
                     source.maybe_lambda_args::<SPEC>(&full_type)
                         .map(|_| SPEC::Expr::cast_destroy(field_path, ConversionExpressionKind::Complex, ffi_type, full_type)),
                 TypeModelKind::Optional(..) =>
                     full_type.maybe_first_nested_type()
-                        .map(|target_ty| SPEC::Expr::cast_destroy(field_path, if full_type.is_primitive() { ConversionExpressionKind::PrimitiveOpt } else { ConversionExpressionKind::ComplexOpt }, ffi_type, target_ty)),
+                        .map(|target_ty| {
+                            let kind = match &target_ty {
+                                Type::Path(TypePath { path, .. }) if path.is_char() => ConversionExpressionKind::ScalarOpt,
+                                _ if full_type.is_primitive() => ConversionExpressionKind::PrimitiveOpt,
+                                _ => ConversionExpressionKind::ComplexOpt
+                            };
+                            SPEC::Expr::cast_destroy(field_path, kind, ffi_type, target_ty)
+                        }),
                 TypeModelKind::Dictionary(DictTypeModelKind::NonPrimitiveFermentable(DictFermentableModelKind::Str(TypeModel { ty: ref full_ty, .. }))) =>
                     Some(SPEC::Expr::destroy_string(field_path, quote!(&#full_ty))),
                 TypeModelKind::Dictionary(DictTypeModelKind::NonPrimitiveFermentable(DictFermentableModelKind::String(TypeModel { ty: ref full_ty, .. }))) =>
@@ -1,10 +1,10 @@
 use quote::ToTokens;
-use syn::{parse_quote, Type, TypeReference};
+use syn::{parse_quote, Type, TypePath, TypeReference};
 use crate::composable::TypeModel;
 use crate::composer::SourceComposable;
 use crate::context::{ScopeContext, ScopeSearch};
 use crate::kind::{DictFermentableModelKind, DictTypeModelKind, ObjectKind, SmartPointerModelKind, SpecialType, TypeModelKind};
-use crate::ext::{Accessory, ExpressionComposable, FFISpecialTypeResolve, GenericNestedArg, MaybeLambdaArgs, Primitive, Resolve, ToType};
+use crate::ext::{Accessory, DictionaryType, ExpressionComposable, FFISpecialTypeResolve, GenericNestedArg, MaybeLambdaArgs, Primitive, Resolve, ToType};
 use crate::lang::Specification;
 use crate::presentable::{ConversionExpressionKind, Expression, ScopeContextPresentable};
 use crate::presentation::{FFIFullDictionaryPath, FFIFullPath, Name};
@@ -108,6 +108,8 @@ impl<SPEC> SourceComposable for ConversionFromComposer<SPEC>
             _ => match type_model_kind {
                 TypeModelKind::Dictionary(DictTypeModelKind::LambdaFn(..)) =>
                     Expression::cast_from(field_path, ConversionExpressionKind::Primitive, ffi_type, full_type),
+                TypeModelKind::FieldlessEnum(..) =>
+                    Expression::cast_from(field_path, ConversionExpressionKind::Value, ffi_type, full_type),
                 TypeModelKind::FnPointer(..) => if let Some(lambda_args) = source.maybe_lambda_args::<SPEC>(&full_type) {
                     Expression::from_lambda(field_path, lambda_args)
                 } else {
@@ -120,6 +122,7 @@ impl<SPEC> SourceComposable for ConversionFromComposer<SPEC>
                             Some(SpecialType::Custom(custom_ffi_type)) => (ConversionExpressionKind::ComplexOpt, custom_ffi_type),
                             Some(SpecialType::Opaque(..)) => (ConversionExpressionKind::OpaqueOpt, ffi_type),
                             _ if full_nested_ty_kind.is_primitive() => (ConversionExpressionKind::PrimitiveOpt, ffi_type),
+                            _ if matches!(full_nested_ty_kind.to_type(), Type::Path(TypePath { path, .. }) if path.is_char()) => (ConversionExpressionKind::ScalarOpt, parse_quote!(u32)),
                             _ => (ConversionExpressionKind::ComplexOpt, ffi_type)
                         };
                         Expression::cast_from(field_path, expr_kind, ffi_type, full_nested_ty_kind.to_type())
@@ -131,6 +134,8 @@ impl<SPEC> SourceComposable for ConversionFromComposer<SPEC>
                     Expression::cast_from::<Type, Type>(field_path, ConversionExpressionKind::Complex, parse_quote!([u8; 16]), parse_quote!(i128)),
                 TypeModelKind::Dictionary(DictTypeModelKind::NonPrimitiveFermentable(DictFermentableModelKind::U128(..))) =>
                     Expression::cast_from::<Type, Type>(field_path, ConversionExpressionKind::Complex, parse_quote!([u8; 16]), parse_quote!(u128)),
+                TypeModelKind::Dictionary(DictTypeModelKind::NonPrimitiveFermentable(DictFermentableModelKind::Char(..))) =>
+                    Expression::cast_from::<Type, Type>(field_path, ConversionExpressionKind::Scalar, parse_quote!(u32), parse_quote!(char)),
                 TypeModelKind::Dictionary(DictTypeModelKind::NonPrimitiveFermentable(DictFermentableModelKind::SmartPointer(SmartPointerModelKind::Box(TypeModel { ty: ref full_ty, .. })))) => {
                     if let Some(full_nested_ty) = full_ty.maybe_first_nested_type_ref() {
                         match (Resolve::<SpecialType<SPEC>>::maybe_resolve(full_nested_ty, source), source.maybe_object_by_value(full_nested_ty)) {
@@ -1,11 +1,11 @@
 use std::fmt::Debug;
 use quote::ToTokens;
-use syn::{parse_quote, Type, TypeReference};
+use syn::{parse_quote, Type, TypePath, TypeReference};
 use crate::composable::TypeModel;
 use crate::composer::SourceComposable;
 use crate::context::{ScopeContext, ScopeSearch};
 use crate::kind::{DictFermentableModelKind, DictTypeModelKind, ObjectKind, ScopeItemKind, SmartPointerModelKind, SpecialType, TypeModelKind};
-use crate::ext::{Accessory, AsType, ExpressionComposable, FFIObjectResolve, FFISpecialTypeResolve, GenericNestedArg, MaybeLambdaArgs, Primitive, Resolve, ToType};
+use crate::ext::{Accessory, AsType, DictionaryType, ExpressionComposable, FFIObjectResolve, FFISpecialTypeResolve, GenericNestedArg, MaybeLambdaArgs, Primitive, Resolve, ToType};
 use crate::lang::Specification;
 use crate::presentable::{ConversionExpressionKind, Expression, ScopeContextPresentable};
 use crate::presentation::{DictionaryExpr, FFIFullDictionaryPath, FFIFullPath};
@@ -106,6 +106,8 @@ where SPEC: Specification<Expr=Expression<SPEC>>,
                     TypeModelKind::FnPointer(..) |
                     TypeModelKind::Dictionary(DictTypeModelKind::LambdaFn(..)) =>
                         Expression::cast_to(field_path, ConversionExpressionKind::Complex, ffi_type, full_type),
+                    TypeModelKind::FieldlessEnum(..) =>
+                        Expression::cast_to(if is_ref { field_path.cloned() } else { field_path }, ConversionExpressionKind::Value, ffi_type, full_type),
                     TypeModelKind::Optional(TypeModel { ty, .. }) => match ty.maybe_first_nested_type_kind() {
                         None =>
                             Expression::cast_to(if is_ref { field_path.cloned() } else { field_path }, ConversionExpressionKind::from(&full_type), ffi_type, full_type),
@@ -114,6 +116,7 @@ where SPEC: Specification<Expr=Expression<SPEC>>,
                             let (expr_kind, ffi_type) = match FFISpecialTypeResolve::<SPEC>::maybe_special_type(&nested_ty, source) {
                                 Some(SpecialType::Custom(custom_ty)) => (ConversionExpressionKind::ComplexOpt, custom_ty),
                                 Some(SpecialType::Opaque(opaque_ty)) => (ConversionExpressionKind::PrimitiveOpt, opaque_ty),
+                                _ if matches!(&nested_ty, Type::Path(TypePath { path, .. }) if path.is_char()) => (ConversionExpressionKind::ScalarOpt, parse_quote!(u32)),
                                 _ => (if nested_ty_kind.is_primitive() { ConversionExpressionKind::PrimitiveOpt } else { ConversionExpressionKind::ComplexOpt }, ffi_type)
                             };
                             Expression::cast_to(field_path, expr_kind, ffi_type, nested_ty)
@@ -123,6 +126,8 @@ where SPEC: Specification<Expr=Expression<SPEC>>,
                         Expression::cast_to::<Type, Type>(if is_ref { field_path.cloned() } else { field_path }, ConversionExpressionKind::Complex, parse_quote!([u8; 16]), parse_quote!(i128)),
                     TypeModelKind::Dictionary(DictTypeModelKind::NonPrimitiveFermentable(DictFermentableModelKind::U128(..))) =>
                         Expression::cast_to::<Type, Type>(if is_ref { field_path.cloned() } else { field_path }, ConversionExpressionKind::Complex, parse_quote!([u8; 16]), parse_quote!(u128)),
+                    TypeModelKind::Dictionary(DictTypeModelKind::NonPrimitiveFermentable(DictFermentableModelKind::Char(..))) =>
+                        Expression::cast_to::<Type, Type>(if is_ref { field_path.cloned() } else { field_path }, ConversionExpressionKind::Scalar, parse_quote!(u32), parse_quote!(char)),
                     TypeModelKind::Dictionary(DictTypeModelKind::NonPrimitiveFermentable(DictFermentableModelKind::Str(TypeModel { ty, .. }))) =>
                         Expression::cast_to::<Type, Type>(field_path, ConversionExpressionKind::Complex, ffi_type, ty.joined_ref()),
                     TypeModelKind::Dictionary(DictTypeModelKind::NonPrimitiveFermentable(DictFermentableModelKind::SmartPointer(SmartPointerModelKind::Box(TypeModel { ref ty, .. })))) => if let Some(nested_ty) = ty.maybe_first_nested_type_ref() {
@@ -1,13 +1,16 @@
-use syn::{Field, Fields, FieldsNamed, FieldsUnnamed, ItemEnum, Variant};
+use syn::{Attribute, Expr, ExprPath, Field, Fields, FieldsNamed, FieldsUnnamed, ItemEnum, Meta, Variant};
 use std::rc::Rc;
 use std::cell::RefCell;
-use quote::ToTokens;
+use proc_macro2::Ident;
+use quote::{format_ident, ToTokens};
+use syn::parse::Parser;
+use syn::visit_mut::VisitMut;
 use ferment_macro::ComposerBase;
 use crate::ast::{CommaPunctuated, Depunctuated};
 use crate::composable::{AttrsModel, CfgAttributes, FieldComposer, GenModel, LifetimesModel};
 use crate::composer::{r#abstract::LinkedContextComposer, AttrComposable, BasicComposer, BasicComposerOwner, BindingComposable, CommaPunctuatedArgKinds, DocComposer, SourceComposable, ComposerLink, DocsComposable, FFIObjectComposable, GenericsComposable, ItemComposerWrapper, Linkable, AspectCommaPunctuatedArgKinds, SourceAccessible, TypeAspect, VariantComposable, VariantComposerRef, SeqKindComposerLink, BasicComposerLink, NameKindComposable, NameKind, LifetimesComposable};
-use crate::context::ScopeContextLink;
-use crate::ext::PunctuateOne;
+use crate::context::{ScopeContext, ScopeContextLink};
+use crate::kind::is_fieldless_enum;
 use crate::lang::{LangAttrSpecification, Specification};
 use crate::presentable::{Aspect, BindingPresentableContext, NameTreeContext, ArgKind, ScopeContextPresentable, SeqKind, Expression};
 use crate::presentation::{DocPresentation, FFIObjectPresentation, Name};
@@ -18,7 +21,10 @@ pub struct EnumComposer<SPEC>
     pub base: BasicComposerLink<SPEC, Self>,
     pub ffi_object_composer: SeqKindComposerLink<SPEC, Self>,
     pub variant_composers: Vec<ItemComposerWrapper<SPEC>>,
-    pub variant_presenters: Vec<(VariantComposerRef<SPEC>, AspectCommaPunctuatedArgKinds<SPEC>)>,
+    pub variant_presenters: Vec<(VariantComposerRef<SPEC>, AspectCommaPunctuatedArgKinds<SPEC>, Option<Expr>)>,
+    pub repr: CommaPunctuated<Ident>,
+    /// Enums with unit variants only are passed by value
+    pub fieldless: bool,
 }
 
 impl<SPEC> NameKindComposable for EnumComposer<SPEC>
@@ -37,37 +43,33 @@ impl<SPEC> EnumComposer<SPEC>
           Self: AttrComposable<SPEC::Attr> + GenericsComposable<SPEC::Gen> + LifetimesComposable<SPEC::Lt> + TypeAspect<SPEC::TYC> + NameKindComposable {
     pub fn new(item_enum: &ItemEnum, ty_context: SPEC::TYC, context: &ScopeContextLink) -> ComposerLink<Self> {
         let ItemEnum { attrs, ident: target_name, variants, generics, .. } = item_enum;
+        let repr = ffi_repr(attrs);
+        let fieldless = is_fieldless_enum(item_enum);
         let variant_composers = variants
             .iter()
             .map(|Variant { attrs, ident: variant_name, fields, discriminant, .. }| {
                 let ty_context = ty_context.join_variant(target_name.clone(), variant_name.clone(), attrs.cfg_attributes());
                 let ffi_aspect = Aspect::Ffi(ty_context.clone());
-                let (variant_composer, fields_context): (VariantComposerRef<SPEC>, CommaPunctuatedArgKinds<SPEC>) = match discriminant {
-                    Some((_, expr)) => (
-                        SeqKind::unit_fields,
-                        ArgKind::AttrName(expr.to_token_stream(), SPEC::Attr::from_cfg_attrs(attrs)).punctuate_one()
+                let (variant_composer, fields_context): (VariantComposerRef<SPEC>, CommaPunctuatedArgKinds<SPEC>) = match fields {
+                    Fields::Unit => (SeqKind::unit, CommaPunctuated::new()),
+                    Fields::Unnamed(FieldsUnnamed { unnamed, .. }) => (
+                        SeqKind::paren_variants,
+                        CommaPunctuated::from_iter(unnamed
+                            .iter()
+                            .map(|Field { attrs, ty, .. }|
+                                ArgKind::Unnamed(FieldComposer::unnamed_typed(Name::default(), ty, attrs)))),
+                    ),
+                    Fields::Named(FieldsNamed { named, .. }) => (
+                        SeqKind::brace_variants,
+                        CommaPunctuated::from_iter(named
+                            .iter()
+                            .map(|Field { ident, attrs, ty, .. }|
+                                ArgKind::inherited_named_type(Name::Optional(ident.clone()), ty, SPEC::Attr::from_cfg_attrs(attrs)))),
                     ),
-                    None => match fields {
-                        Fields::Unit => (SeqKind::unit, CommaPunctuated::new()),
-                        Fields::Unnamed(FieldsUnnamed { unnamed, .. }) => (
-                            SeqKind::paren_variants,
-                            CommaPunctuated::from_iter(unnamed
-                                .iter()
-                                .map(|Field { attrs, ty, .. }|
-                                    ArgKind::Unnamed(FieldComposer::unnamed_typed(Name::default(), ty, attrs)))),
-                        ),
-                        Fields::Named(FieldsNamed { named, .. }) => (
-                            SeqKind::brace_variants,
-                            CommaPunctuated::from_iter(named
-                                .iter()
-                                .map(|Field { ident, attrs, ty, .. }|
-                                    ArgKind::inherited_named_type(Name::Optional(ident.clone()), ty, SPEC::Attr::from_cfg_attrs(attrs)))),
-                        ),
-                    },
                 };
                 let aspect_presentable_args = ((ffi_aspect, (SPEC::Attr::from_cfg_attrs(attrs), SPEC::Lt::default(), SPEC::Gen::default()), NameKind::Named), fields_context);
                 let variant_composer_wrapper = ItemComposerWrapper::variant(fields, ty_context, attrs, context);
-                (variant_composer_wrapper, (variant_composer, aspect_presentable_args))
+                (variant_composer_wrapper, (variant_composer, aspect_presentable_args, discriminant.as_ref().map(|(_, expr)| full_discriminant(expr, &context.borrow()))))
             }).unzip();
         let root = Rc::new(RefCell::new(Self {
             base: BasicComposer::from(
@@ -80,7 +82,9 @@ impl<SPEC> EnumComposer<SPEC>
             ),
             variant_composers: variant_composers.0,
             variant_presenters: variant_composers.1,
-            ffi_object_composer: LinkedContextComposer::new(SeqKind::r#enum, SeqKind::variants),
+            repr,
+            fieldless,
+            ffi_object_composer: LinkedContextComposer::new(SeqKind::bypass, SeqKind::r#enum),
         }));
         {
             let mut root_borrowed = root.borrow_mut();
@@ -118,7 +122,9 @@ impl<SPEC> BindingComposable<SPEC> for EnumComposer<SPEC>
           Name<SPEC>: ToTokens {
     fn compose_bindings(&self) -> Depunctuated<BindingPresentableContext<SPEC>> {
         let mut bindings = Depunctuated::new();
-        bindings.extend(self.variant_composers.iter().filter_map(ItemComposerWrapper::compose_ctor));
+        if !self.fieldless {
+            bindings.extend(self.variant_composers.iter().filter_map(ItemComposerWrapper::compose_ctor));
+        }
         bindings.push(BindingPresentableContext::<SPEC>::dtor(((self.ffi_type_aspect(), (self.compose_attributes(), self.compose_lifetimes(), self.compose_generics()), NameKind::Named), Default::default())));
         bindings
     }
@@ -129,6 +135,53 @@ impl<SPEC> VariantComposable<SPEC> for EnumComposer<SPEC>
         CommaPunctuated::from_iter(
             self.variant_presenters
                 .iter()
-                .map(|(composer, context)| composer(context)))
+                .map(|(composer, context, discriminant)| match discriminant {
+                    Some(expr) => SeqKind::discriminant(composer(context), expr),
+                    None => composer(context)
+                }))
+    }
+    fn compose_repr(&self) -> CommaPunctuated<Ident> {
+        self.repr.clone()
+    }
+    fn compose_derives(&self) -> CommaPunctuated<Ident> {
+        if self.fieldless {
+            CommaPunctuated::from_iter([format_ident!("Clone"), format_ident!("Copy"), format_ident!("PartialEq"), format_ident!("Eq")])
+        } else {
+            CommaPunctuated::from_iter([format_ident!("Clone")])
+        }
+    }
+}
+
+/// Discriminants are copied into the fermented module, so the consts they refer to are named by the full paths
+/// (the other non-literal expressions are rejected before, see `MaybeUnsupported`)
+fn full_discriminant(expr: &Expr, source: &ScopeContext) -> Expr {
+    struct FullPaths<'a>(&'a ScopeContext);
+    impl VisitMut for FullPaths<'_> {
+        fn visit_expr_path_mut(&mut self, node: &mut ExprPath) {
+            if node.qself.is_none() {
+                node.path = self.0.full_value_path(&node.path);
+            }
+        }
+    }
+    let mut expr = expr.clone();
+    FullPaths(source).visit_expr_mut(&mut expr);
+    expr
+}
+
+/// The declared `#[repr(u8)]` / `#[repr(C, u8)]` is kept to match the explicit discriminants,
+/// so enums with data have the tag + per-variant struct (or tag + union for `C`) layout. Other enums are `#[repr(C)]`
+fn ffi_repr(attrs: &[Attribute]) -> CommaPunctuated<Ident> {
+    let repr = CommaPunctuated::from_iter(attrs.iter()
+        .filter_map(|attr| match &attr.meta {
+            Meta::List(meta_list) if meta_list.path.is_ident("repr") =>
+                CommaPunctuated::<Ident>::parse_terminated.parse2(meta_list.tokens.clone()).ok(),
+            _ => None
+        })
+        .flatten()
+        .filter(|ident| matches!(ident.to_string().as_str(), "C" | "u8" | "u16" | "u32" | "u64" | "u128" | "usize" | "i8" | "i16" | "i32" | "i64" | "i128" | "isize")));
+    if repr.is_empty() {
+        CommaPunctuated::from_iter([format_ident!("C")])
+    } else {
+        repr
     }
 }
@@ -16,7 +16,9 @@ pub struct FFIBindingsComposer<SPEC, Link, Iter>
     pub dtor: DtorMethodComposer<SPEC, Link>,
     pub getter: AccessorMethodComposer<SPEC, Link>,
     pub setter: AccessorMethodComposer<SPEC, Link>,
-    pub get_set: bool
+    pub get_set: bool,
+    /// Indexes of the fields which aren't `pub`: they get no accessors and the ctor is omitted
+    pub private_fields: Vec<usize>,
 }
 impl<SPEC, Link, Iter> FFIBindingsComposer<SPEC, Link, Iter>
     where Link: SharedAccess,
@@ -29,11 +31,17 @@ impl<SPEC, Link, Iter> FFIBindingsComposer<SPEC, Link, Iter>
         setter: AccessorMethodComposer<SPEC, Link>,
         get_set: bool,
     ) -> Self {
-        Self { parent: None, ctor, dtor, getter, setter, get_set }
+        Self { parent: None, ctor, dtor, getter, setter, get_set, private_fields: vec![] }
     }
 
-    pub fn compose_ctor(&self) -> BindingPresentableContext<SPEC> {
-        self.ctor.compose(&())
+    pub fn compose_ctor(&self) -> Option<BindingPresentableContext<SPEC>> {
+        self.private_fields.is_empty()
+            .then(|| self.ctor.compose(&()))
+    }
+    fn public_accessors(&self, accessors: Vec<BindingPresentableContext<SPEC>>) -> impl Iterator<Item = BindingPresentableContext<SPEC>> + '_ {
+        accessors.into_iter()
+            .enumerate()
+            .filter_map(|(index, accessor)| (!self.private_fields.contains(&index)).then_some(accessor))
     }
 }
 
@@ -59,11 +67,11 @@ impl<SPEC, Link, Iter> SourceComposable for FFIBindingsComposer<SPEC, Link, Iter
 
     fn compose(&self, source: &Self::Source) -> Self::Output {
         let mut bindings = Depunctuated::new();
-        bindings.push(self.ctor.compose(&()));
+        bindings.extend(self.compose_ctor());
         bindings.push(self.dtor.compose(source));
         if self.get_set {
-            bindings.extend(self.getter.compose(source));
-            bindings.extend(self.setter.compose(source));
+            bindings.extend(self.public_accessors(self.getter.compose(source)));
+            bindings.extend(self.public_accessors(self.setter.compose(source)));
         }
         bindings
     }
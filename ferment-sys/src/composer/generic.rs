@@ -18,6 +18,8 @@ pub struct GenericComposerInfo<SPEC>
     pub field_composers: Depunctuated<FieldComposer<SPEC>>,
     pub interfaces: Depunctuated<SPEC::Interface>,
     pub bindings: Depunctuated<BindingPresentableContext<SPEC>>,
+    /// Callbacks own the foreign context, so they can't be cloned
+    pub cloneable: bool,
 }
 
 impl<SPEC> GenericComposerInfo<SPEC>
@@ -38,6 +40,7 @@ impl<SPEC> GenericComposerInfo<SPEC>
             interfaces,
             bindings,
             field_composer,
+            cloneable: true,
         }
     }
     pub fn callback(
@@ -48,7 +51,7 @@ impl<SPEC> GenericComposerInfo<SPEC>
     ) -> Self {
         let dtor_context = (ffi_name.clone(), (attrs.clone(), SPEC::Lt::default(), SPEC::Gen::default()), NameKind::Named);
         let ctor_context = (dtor_context.clone(), Vec::from_iter(field_composers.iter().map(ArgKind::callback_ctor_pair)));
-        Self::new(
+        let info = Self::new(
             ffi_name,
             attrs.clone(),
             field_composers,
@@ -58,7 +61,8 @@ impl<SPEC> GenericComposerInfo<SPEC>
                 BindingPresentableContext::<SPEC>::dtor((dtor_context, Default::default()))
             ]),
             ArgKind::callback_arg
-        )
+        );
+        Self { cloneable: false, ..info }
     }
     pub fn default(
         ffi_name: Aspect<SPEC::TYC>,
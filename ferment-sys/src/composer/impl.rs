@@ -1,12 +1,12 @@
 use std::cell::RefCell;
 use std::rc::Rc;
-use syn::{ImplItem, ItemImpl};
+use syn::{ImplItem, ItemImpl, TraitItem, TraitItemFn};
 use ferment_macro::ComposerBase;
 use crate::composable::{AttrsModel, CfgAttributes, FnSignatureContext, GenModel, LifetimesModel};
-use crate::composer::{BasicComposer, BasicComposerLink, BasicComposerOwner, ComposerLink, DocComposer, DocsComposable, Linkable, SigComposer, SigComposerLink, SourceAccessible, SourceComposable, VTableComposerLink};
+use crate::composer::{BasicComposer, BasicComposerLink, BasicComposerOwner, ComposerLink, ConstComposer, ConstComposerLink, DocComposer, DocsComposable, Linkable, SigComposer, SigComposerLink, SourceAccessible, SourceComposable, VTableComposerLink};
 use crate::composer::vtable::VTableComposer;
-use crate::context::{ScopeChain, ScopeContextLink};
-use crate::ext::{Join, ToType};
+use crate::context::{ScopeChain, ScopeContext, ScopeContextLink};
+use crate::ext::{Join, ToPath, ToType};
 use crate::lang::Specification;
 use crate::presentable::NameTreeContext;
 use crate::presentation::DocPresentation;
@@ -16,6 +16,8 @@ pub struct ImplComposer<SPEC>
     where SPEC: Specification + 'static {
     pub base: BasicComposerLink<SPEC, Self>,
     pub methods: Vec<SigComposerLink<SPEC>>,
+    /// Associated consts of the inherent impl
+    pub consts: Vec<ConstComposerLink<SPEC>>,
     pub vtable: Option<VTableComposerLink<SPEC>>,
 }
 impl<SPEC> ImplComposer<SPEC>
@@ -24,40 +26,67 @@ impl<SPEC> ImplComposer<SPEC>
         let ItemImpl { attrs, generics, trait_, self_ty, items, ..  } = item_impl;
         let source = scope_context.borrow();
         let mut methods = Vec::new();
+        let mut consts = Vec::new();
         let mut vtable_method_composers = Vec::new();
         let attrs_model = AttrsModel::from(attrs);
-        items.iter().for_each(|impl_item| if let ImplItem::Fn(item) = impl_item {
-            let method_scope_context = Rc::new(RefCell::new(source.joined(item)));
-            // TMP strategy to provide both trait vtable based and implementor based bindings
-            match trait_.as_ref() {
-                Some((_, path, _)) => {
-                    let trait_ty_context = ty_context.join_fn(
-                        scope.joined_path(&item.sig.ident),
-                        FnSignatureContext::TraitImpl(item.sig.clone(), *self_ty.clone(), path.to_type()),
-                        item.attrs.cfg_attributes()
-                    );
-                    let composer = SigComposer::from_impl_item_method(item, trait_ty_context, &method_scope_context);
-                    methods.push(composer.clone());
-                    vtable_method_composers.push(composer);
+        items.iter().for_each(|impl_item| match impl_item {
+            ImplItem::Const(item) if trait_.is_none() =>
+                consts.push(ConstComposer::from_impl_item_const(item, scope.joined_path(&item.ident), scope_context)),
+            ImplItem::Fn(item) => {
+                let method_scope_context = Rc::new(RefCell::new(source.joined(item)));
+                // TMP strategy to provide both trait vtable based and implementor based bindings
+                match trait_.as_ref() {
+                    Some((_, path, _)) => {
+                        let trait_ty_context = ty_context.join_fn(
+                            scope.joined_path(&item.sig.ident),
+                            FnSignatureContext::TraitImpl(item.sig.clone(), *self_ty.clone(), path.to_type()),
+                            item.attrs.cfg_attributes()
+                        );
+                        let composer = SigComposer::from_impl_item_method(item, trait_ty_context, &method_scope_context);
+                        methods.push(composer.clone());
+                        vtable_method_composers.push(composer);
 
-                    let impl_ty_context = ty_context.join_fn(
-                        scope.joined_path(&item.sig.ident),
-                        FnSignatureContext::TraitAsType(item.sig.clone(), *self_ty.clone(), path.to_type()),
-                        item.attrs.cfg_attributes()
-                    );
-                    methods.push(SigComposer::from_impl_item_method(item, impl_ty_context, &method_scope_context));
+                        let impl_ty_context = ty_context.join_fn(
+                            scope.joined_path(&item.sig.ident),
+                            FnSignatureContext::TraitAsType(item.sig.clone(), *self_ty.clone(), path.to_type()),
+                            item.attrs.cfg_attributes()
+                        );
+                        methods.push(SigComposer::from_impl_item_method(item, impl_ty_context, &method_scope_context));
+                    }
+                    None => {
+                        let sig_context = FnSignatureContext::Impl(item.sig.clone(), *self_ty.clone());
+                        let ty_context = ty_context.join_fn(
+                            scope.joined_path(&item.sig.ident),
+                            sig_context,
+                            item.attrs.cfg_attributes()
+                        );
+                        methods.push(SigComposer::from_impl_item_method(item, ty_context, &method_scope_context));
+                    }
                 }
-                None => {
-                    let sig_context = FnSignatureContext::Impl(item.sig.clone(), *self_ty.clone());
-                    let ty_context = ty_context.join_fn(
-                        scope.joined_path(&item.sig.ident),
-                        sig_context,
-                        item.attrs.cfg_attributes()
-                    );
-                    methods.push(SigComposer::from_impl_item_method(item, ty_context, &method_scope_context));
-                }
-            }
+            },
+            _ => {}
         });
+        if let Some((_, path, _)) = trait_ {
+            // Default methods which aren't overridden are composed from the trait declarations (in the trait scope)
+            let full_trait_ty = source.full_type_for(&path.to_type());
+            let full_trait_path = full_trait_ty.to_path();
+            let maybe_trait_scope = source.context.borrow().maybe_scope_ref(&full_trait_path).cloned();
+            if let (Some(item_trait), Some(trait_scope)) = (source.maybe_item_trait(&full_trait_path), maybe_trait_scope) {
+                let trait_scope_context = ScopeContext::with(trait_scope, Rc::clone(&source.context));
+                item_trait.items.iter().for_each(|trait_item| match trait_item {
+                    TraitItem::Fn(trait_item_method @ TraitItemFn { sig, attrs, default: Some(..), .. }) if !items.iter().any(|impl_item| matches!(impl_item, ImplItem::Fn(item) if item.sig.ident == sig.ident)) => {
+                        let method_scope_context = Rc::new(RefCell::new(trait_scope_context.joined(trait_item_method)));
+                        let trait_ty_context = ty_context.join_fn(
+                            scope.joined_path(&sig.ident),
+                            FnSignatureContext::TraitImpl(sig.clone(), *self_ty.clone(), full_trait_ty.clone()),
+                            attrs.cfg_attributes()
+                        );
+                        vtable_method_composers.push(SigComposer::from_trait_item_method(trait_item_method, trait_ty_context, &method_scope_context));
+                    },
+                    _ => {}
+                });
+            }
+        }
         let root = Rc::new(RefCell::new(Self {
             base: BasicComposer::from(
                 DocComposer::from(&ty_context),
@@ -67,6 +96,7 @@ impl<SPEC> ImplComposer<SPEC>
                 LifetimesModel::default(),
                 Rc::clone(scope_context)),
             methods: methods.clone(),
+            consts,
             vtable: trait_.as_ref().map(|(..)| VTableComposer::from_trait_path(ty_context, attrs, vtable_method_composers, Rc::clone(scope_context)))
         }));
         {
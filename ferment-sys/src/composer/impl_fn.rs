@@ -59,7 +59,12 @@ where SPEC: Specification<Name=Name<SPEC>, Expr=Expression<SPEC>>,
                 let name = Name::self_();
                 let tokenized_name = name.to_token_stream();
                 argument_names.push(tokenized_name);
-                let arg_kind = ArgKind::inherited_named_var(name.clone(), VarComposer::<SPEC>::key_ref_in_composer_scope(self_ty).compose(source), attrs);
+                // Handles are borrowed in place, so `&self` only needs the const pointer
+                let var_ty = match (mutability, reference) {
+                    (None, Some(..)) if source.is_handle(self_ty) => self_ty.joined_const(),
+                    _ => self_ty.clone()
+                };
+                let arg_kind = ArgKind::inherited_named_var(name.clone(), VarComposer::<SPEC>::key_ref_in_composer_scope(&var_ty).compose(source), attrs);
                 arguments.push(arg_kind);
                 let arg_conversion = ConversionFromComposer::<SPEC>::key_in_composer_scope(name, &qualified_ty).compose(source);
                 argument_conversions.push(ArgKind::expr(arg_conversion));
@@ -1,12 +1,12 @@
 use quote::ToTokens;
-use syn::{Attribute, Fields, FieldsNamed, FieldsUnnamed, ItemEnum, ItemFn, ItemImpl, ItemStruct, ItemTrait, ItemType};
+use syn::{Attribute, Fields, FieldsNamed, FieldsUnnamed, ItemConst, ItemEnum, ItemFn, ItemImpl, ItemStatic, ItemStruct, ItemTrait, ItemType, Path};
 use syn::punctuated::Punctuated;
 use syn::token::{Brace, Paren};
 use crate::ast::Void;
-use crate::composer::{AttrComposable, EnumComposer, EnumComposerLink, EnumVariantComposer, EnumVariantComposerLink, FFIAspect, FFIBindingsComposer, ImplComposer, ImplComposerLink, OpaqueStructComposer, OpaqueStructComposerLink, SigComposer, SigComposerLink, StructComposer, StructComposerLink, TraitComposer, TraitComposerLink, TypeAliasComposer, TypeAliasComposerLink};
+use crate::composer::{AttrComposable, ConstComposer, ConstComposerLink, EnumComposer, EnumComposerLink, EnumVariantComposer, EnumVariantComposerLink, FFIAspect, FFIBindingsComposer, ImplComposer, ImplComposerLink, OpaqueStructComposer, OpaqueStructComposerLink, SigComposer, SigComposerLink, StructComposer, StructComposerLink, TraitComposer, TraitComposerLink, TypeAliasComposer, TypeAliasComposerLink};
 use crate::context::{ScopeChain, ScopeContextLink};
 use crate::ext::PunctuateOne;
-use crate::lang::Specification;
+use crate::lang::{LangAttrSpecification, Specification};
 use crate::presentable::{BindingPresentableContext, ScopeContextPresentable, SeqKind, Expression};
 use crate::presentation::Name;
 
@@ -22,6 +22,7 @@ pub enum ItemComposerWrapper<SPEC>
     OpaqueStructNamed(OpaqueStructComposerLink<SPEC, Brace>),
     OpaqueStructUnnamed(OpaqueStructComposerLink<SPEC, Paren>),
     Sig(SigComposerLink<SPEC>),
+    Const(ConstComposerLink<SPEC>),
     TypeAlias(TypeAliasComposerLink<SPEC, Paren>),
     Trait(TraitComposerLink<SPEC>),
     Impl(ImplComposerLink<SPEC>),
@@ -43,6 +44,12 @@ impl<SPEC> ItemComposerWrapper<SPEC>
     pub fn r#fn(item_fn: &ItemFn, ty_context: SPEC::TYC, context: &ScopeContextLink) -> Self {
         ItemComposerWrapper::Sig(SigComposer::from_item_fn(item_fn, ty_context, context))
     }
+    pub fn r#const(item_const: &ItemConst, path: Path, context: &ScopeContextLink) -> Self {
+        ItemComposerWrapper::Const(ConstComposer::from_item_const(item_const, path, context))
+    }
+    pub fn r#static(item_static: &ItemStatic, path: Path, context: &ScopeContextLink) -> Self {
+        ItemComposerWrapper::Const(ConstComposer::from_item_static(item_static, path, context))
+    }
     pub fn r#enum(item_enum: &ItemEnum, ty_context: SPEC::TYC, context: &ScopeContextLink) -> Self {
         ItemComposerWrapper::<SPEC>::Enum(EnumComposer::<SPEC>::new(item_enum, ty_context, context))
     }
@@ -110,19 +117,19 @@ impl<SPEC> ItemComposerWrapper<SPEC>
     pub fn compose_ctor(&self) -> Option<BindingPresentableContext<SPEC>> {
         match self {
             ItemComposerWrapper::EnumVariantNamed(composer) =>
-                composer.borrow().composer.borrow().bindings_composer.as_ref().map(FFIBindingsComposer::compose_ctor),
+                composer.borrow().composer.borrow().bindings_composer.as_ref().and_then(FFIBindingsComposer::compose_ctor),
             ItemComposerWrapper::EnumVariantUnnamed(composer) =>
-                composer.borrow().composer.borrow().bindings_composer.as_ref().map(FFIBindingsComposer::compose_ctor),
+                composer.borrow().composer.borrow().bindings_composer.as_ref().and_then(FFIBindingsComposer::compose_ctor),
             ItemComposerWrapper::EnumVariantUnit(composer) =>
-                composer.borrow().composer.borrow().bindings_composer.as_ref().map(FFIBindingsComposer::compose_ctor),
+                composer.borrow().composer.borrow().bindings_composer.as_ref().and_then(FFIBindingsComposer::compose_ctor),
             ItemComposerWrapper::StructNamed(composer) =>
-                composer.borrow().composer.borrow().bindings_composer.as_ref().map(FFIBindingsComposer::compose_ctor),
+                composer.borrow().composer.borrow().bindings_composer.as_ref().and_then(FFIBindingsComposer::compose_ctor),
             ItemComposerWrapper::StructUnnamed(composer) =>
-                composer.borrow().composer.borrow().bindings_composer.as_ref().map(FFIBindingsComposer::compose_ctor),
+                composer.borrow().composer.borrow().bindings_composer.as_ref().and_then(FFIBindingsComposer::compose_ctor),
             ItemComposerWrapper::OpaqueStructUnnamed(composer) =>
-                composer.borrow().composer.borrow().bindings_composer.as_ref().map(FFIBindingsComposer::compose_ctor),
+                composer.borrow().composer.borrow().bindings_composer.as_ref().and_then(FFIBindingsComposer::compose_ctor),
             ItemComposerWrapper::OpaqueStructNamed(composer) =>
-                composer.borrow().composer.borrow().bindings_composer.as_ref().map(FFIBindingsComposer::compose_ctor),
+                composer.borrow().composer.borrow().bindings_composer.as_ref().and_then(FFIBindingsComposer::compose_ctor),
             _ => None,
         }
     }
@@ -151,6 +158,8 @@ impl<SPEC> AttrComposable<SPEC::Attr> for ItemComposerWrapper<SPEC>
                 composer.borrow().composer.borrow().compose_attributes(),
             ItemComposerWrapper::Sig(composer) =>
                 composer.borrow().compose_attributes(),
+            ItemComposerWrapper::Const(composer) =>
+                SPEC::Attr::from_attrs(composer.borrow().attrs.clone()),
             ItemComposerWrapper::TypeAlias(composer) =>
                 composer.borrow().composer.borrow().compose_attributes(),
             ItemComposerWrapper::Trait(composer) =>
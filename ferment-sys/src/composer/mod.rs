@@ -6,6 +6,7 @@ mod bare_fn;
 mod basic;
 mod bounds;
 mod callback;
+mod r#const;
 mod constants;
 mod conversion_drop;
 mod conversion_from;
@@ -68,6 +69,7 @@ pub use self::basic::*;
 pub use self::bare_fn::*;
 pub use self::bounds::*;
 pub use self::callback::*;
+pub use self::r#const::*;
 pub use self::constants::*;
 pub use self::conversion_drop::*;
 pub use self::doc::*;
@@ -125,6 +127,7 @@ pub type OpaqueStructComposerLink<SPEC, I> = ComposerLink<OpaqueStructComposer<S
 pub type TypeAliasComposerLink<SPEC, I> = ComposerLink<TypeAliasComposer<SPEC, I>>;
 pub type EnumComposerLink<SPEC> = ComposerLink<EnumComposer<SPEC>>;
 pub type SigComposerLink<SPEC> = ComposerLink<SigComposer<SPEC>>;
+pub type ConstComposerLink<SPEC> = ComposerLink<ConstComposer<SPEC>>;
 pub type VTableComposerLink<SPEC> = ComposerLink<VTableComposer<SPEC>>;
 pub type ImplComposerLink<SPEC> = ComposerLink<ImplComposer<SPEC>>;
 pub type TraitComposerLink<SPEC> = ComposerLink<TraitComposer<SPEC>>;
@@ -1,7 +1,8 @@
 use std::marker::PhantomData;
 use quote::ToTokens;
 use syn::__private::TokenStream2;
-use syn::PatType;
+use syn::{parse_quote, PatType};
+use crate::composable::FieldComposer;
 use crate::composer::{ConversionFromComposer, SourceComposable};
 use crate::context::ScopeContext;
 use crate::ext::{LifetimeProcessor, ToType};
@@ -35,6 +36,16 @@ where SPEC: Specification<Name=Name<SPEC>, Expr=Expression<SPEC>>,
     fn compose(&self, source: &Self::Source) -> Self::Output {
         let PatType { ty, attrs, pat, .. } = self.pat_type;
         let name = Name::pat(pat);
+        if let Some(proxy_kind) = source.maybe_trait_proxy_kind(ty) {
+            let proxy_ty = proxy_kind.proxy_type::<SPEC>(source);
+            let var_composer = FieldComposer::named_type(name.clone(), &parse_quote!(*mut #proxy_ty), SPEC::Attr::from_cfg_attrs(attrs));
+            return (
+                SPEC::Lt::from_lifetimes(ty.unique_lifetimes()),
+                name.to_token_stream(),
+                ArgKind::inherited_named_ready(&var_composer),
+                ArgKind::expr(proxy_kind.expr_from::<SPEC>(Expression::name(&name)))
+            )
+        }
         (
             SPEC::Lt::from_lifetimes(ty.unique_lifetimes()),
             name.to_token_stream(),
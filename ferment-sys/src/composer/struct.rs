@@ -2,7 +2,7 @@ use std::cell::RefCell;
 use std::marker::PhantomData;
 use std::rc::Rc;
 use quote::ToTokens;
-use syn::{Attribute, Generics, Lifetime};
+use syn::{Attribute, Field, Generics, Lifetime};
 use syn::token::{Brace, Comma, Paren, Semi};
 use crate::ast::{DelimiterTrait, Void};
 use crate::composable::{AttrsModel, FieldComposer};
@@ -35,15 +35,21 @@ impl<SPEC, I> StructComposer<SPEC, I>
         fields: &CommaPunctuatedFields,
         context: &ScopeContextLink,
     ) -> ComposerLink<Self> {
-        Rc::new(RefCell::new(Self {
-            composer: ItemComposer::new::<Self>(
-                ty_context,
-                AttrsModel::from(attrs),
-                lifetimes.to_owned(),
-                Some(generics.clone()),
-                fields,
-                context)
-        }))
+        let composer = ItemComposer::new::<Self>(
+            ty_context,
+            AttrsModel::from(attrs),
+            lifetimes.to_owned(),
+            Some(generics.clone()),
+            fields,
+            context);
+        if let Some(bindings_composer) = composer.borrow_mut().bindings_composer.as_mut() {
+            let source = context.borrow();
+            bindings_composer.private_fields = fields.iter()
+                .enumerate()
+                .filter_map(|(index, Field { vis, .. })| (!source.is_visible_from_fermented_mod(vis)).then_some(index))
+                .collect();
+        }
+        Rc::new(RefCell::new(Self { composer }))
     }
 }
 impl<SPEC, T, I> FFIObjectSpec<SPEC, ComposerLink<T>> for StructComposer<SPEC, I>
@@ -1,14 +1,15 @@
 use std::cell::RefCell;
-use std::collections::HashMap;
 use std::rc::Rc;
 use std::vec;
+use indexmap::IndexMap;
 use proc_macro2::Ident;
-use syn::{Generics, ItemTrait, TraitItem, TraitItemFn, Lifetime};
+use syn::{FnArg, Generics, ItemTrait, Receiver, Signature, TraitItem, TraitItemFn, Lifetime};
+use syn::spanned::Spanned;
 use ferment_macro::ComposerBase;
 use crate::composable::{AttrsModel, FnSignatureContext, GenModel, LifetimesModel, TraitTypeModel};
 use crate::composer::{BasicComposer, BasicComposerLink, BasicComposerOwner, ComposerLink, DocComposer, DocsComposable, Linkable, SigComposer, SigComposerLink, SourceAccessible, SourceComposable};
 use crate::context::{ScopeChain, ScopeContextLink};
-use crate::ext::{Join, ToType};
+use crate::ext::{Join, Proxyable, ToType};
 use crate::lang::Specification;
 use crate::presentable::NameTreeContext;
 use crate::presentation::DocPresentation;
@@ -19,7 +20,9 @@ pub struct TraitComposer<SPEC>
     pub base: BasicComposerLink<SPEC, Self>,
     pub methods: Vec<SigComposerLink<SPEC>>,
     #[allow(unused)]
-    pub types: HashMap<Ident, TraitTypeModel>,
+    pub types: IndexMap<Ident, TraitTypeModel>,
+    /// Foreign implementations are accepted through the `*_Proxy`
+    pub proxyable: bool,
 }
 
 impl<SPEC> TraitComposer<SPEC>
@@ -33,13 +36,13 @@ impl<SPEC> TraitComposer<SPEC>
         let self_ty = ident.to_type();
         let source = scope_context.borrow();
         let mut methods = vec![];
-        let mut types = HashMap::new();
+        let mut types = IndexMap::new();
         items
             .iter()
             .for_each(|trait_item| match trait_item {
                 TraitItem::Fn(trait_item_method) => {
-                    let TraitItemFn { sig, attrs, .. } = trait_item_method;
-                    let sig_context = FnSignatureContext::TraitInner(sig.clone(), self_ty.clone(), self_ty.clone());
+                    let TraitItemFn { sig, attrs, default, .. } = trait_item_method;
+                    let sig_context = FnSignatureContext::TraitInner(sig.clone(), self_ty.clone(), self_ty.clone(), default.is_some());
                     let method_scope_context = Rc::new(RefCell::new(source.joined(trait_item_method)));
                     let ty_context = ty_context.join_fn(
                         scope.joined_path(&sig.ident),
@@ -52,19 +55,35 @@ impl<SPEC> TraitComposer<SPEC>
                 },
                 _ => {}
             });
-        Self::new(
+        let root = Self::new(
             methods,
             types,
             ty_context,
             Some(generics.clone()),
             vec![],
             AttrsModel::from(attrs),
-            scope_context)
+            scope_context);
+        root.borrow_mut().proxyable = item_trait.is_proxyable();
+        // A proxy can only borrow the foreign implementation, so it can't be moved into a method
+        items.iter()
+            .filter_map(|trait_item| match trait_item {
+                TraitItem::Fn(TraitItemFn { sig: Signature { ident: method, inputs, .. }, .. }) => match inputs.first() {
+                    Some(FnArg::Receiver(receiver @ Receiver { reference: None, .. })) => Some((method, receiver)),
+                    _ => None
+                },
+                _ => None
+            })
+            .for_each(|(method, receiver)| {
+                let mut context = source.context.borrow_mut();
+                let diagnostic = context.span_diagnostic(scope.self_path_ref(), receiver.span(), format!("`{ident}::{method}` takes `self` by value, so the foreign implementations of `{ident}` aren't accepted"));
+                context.add_diagnostic(diagnostic);
+            });
+        root
     }
 
     fn new(
         methods: Vec<SigComposerLink<SPEC>>,
-        types: HashMap<Ident, TraitTypeModel>,
+        types: IndexMap<Ident, TraitTypeModel>,
         ty_context: SPEC::TYC,
         generics: Option<Generics>,
         lifetimes: Vec<Lifetime>,
@@ -75,6 +94,7 @@ impl<SPEC> TraitComposer<SPEC>
             base: BasicComposer::from(DocComposer::from(&ty_context), attrs, ty_context, GenModel::new(generics), LifetimesModel::new(lifetimes), Rc::clone(context)),
             methods,
             types,
+            proxyable: false,
         }));
         {
             let mut composer = root.borrow_mut();
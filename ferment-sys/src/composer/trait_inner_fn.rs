@@ -12,6 +12,7 @@ pub fn compose_trait_inner_fn<SPEC>(
     trait_ty: &Type,
     aspect: SignatureAspect<SPEC>,
     sig: &Signature,
+    has_default: bool,
     source: &ScopeContext
 ) -> BindingPresentableContext<SPEC>
 where SPEC: Specification<Expr=Expression<SPEC>, Name=Name<SPEC>>,
@@ -37,5 +38,5 @@ where SPEC: Specification<Expr=Expression<SPEC>, Name=Name<SPEC>>,
                     FieldComposer::named_typed(Name::pat(pat), ty, attrs)
             })
         }));
-    BindingPresentableContext::TraitVTableInnerFn(aspect, sig.ident.clone(), arguments, return_type)
+    BindingPresentableContext::TraitVTableInnerFn(aspect, sig.ident.clone(), arguments, return_type, has_default)
 }
\ No newline at end of file
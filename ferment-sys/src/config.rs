@@ -1,7 +1,77 @@
 use std::fmt::{Display, Formatter};
 use std::path::PathBuf;
+use indexmap::{IndexMap, IndexSet};
 use proc_macro2::Ident;
-use crate::{Crate, Lang};
+use syn::Path;
+use crate::{Crate, Error, Lang};
+
+/// cbindgen config file used by `Builder` unless another one or a programmatic config is set
+pub const DEFAULT_CBINDGEN_CONFIG_FILE: &str = "cbindgen.toml";
+
+/// Default executor of the async bindings, requires `ferment` with the `tokio` feature
+pub const DEFAULT_EXECUTOR: &str = "tokio::runtime::Runtime";
+
+/// Defines what happens when a Rust panic reaches a generated `extern "C"` function
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PanicPolicy {
+    /// Generated bodies are emitted as is, so a panic unwinds into the caller
+    #[default]
+    Propagate,
+    /// Generated bodies are wrapped into `ferment::catch_panic`: a panic is stored
+    /// as a thread-local error (see `ferment_last_error`) and a null/zeroed value is returned
+    CatchUnwind,
+}
+
+/// Defines how exported `async fn` are presented in the fermented bindings
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AsyncMode {
+    /// The binding takes a `runtime: *const c_void` (`tokio::runtime::Runtime`)
+    /// and blocks the calling thread until the future is resolved
+    #[default]
+    Blocking,
+    /// The binding spawns the future onto the runtime and returns a `ferment::CancellationHandle` immediately,
+    /// the converted result (or an error) is delivered later through the generated `<binding>_Completion` struct
+    Callback,
+}
+
+/// Defines how the Rust fermentate is laid out on disk
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FermentateLayout {
+    /// Single `src/{mod_name}.rs` with the whole fermentate on one line
+    #[default]
+    Compact,
+    /// Single `src/{mod_name}.rs` formatted with `prettyplease`
+    Pretty,
+    /// Formatted `src/{mod_name}/mod.rs` with every inline module moved into its own file,
+    /// so `types::<crate>::<mod>` and `generics` mirror the source tree
+    Modules,
+}
+
+/// Defines what happens with the exported items which can't be fermented (see `Diagnostic`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DiagnosticPolicy {
+    /// The items are skipped and their diagnostics are returned by `Builder::generate`
+    #[default]
+    Warn,
+    /// Same as `Warn` but the diagnostics are also printed as `cargo:warning=` lines of the build script
+    CargoWarning,
+    /// Nothing is written and `Builder::generate` fails with `Error::Diagnostics`
+    Deny,
+}
+
+/// Defines which items under `#[cfg(...)]` are fermented, items under `#[cfg(test)]` are always skipped
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CfgMode {
+    /// Items of all the branches are fermented and their cfg attributes are copied onto the generated code,
+    /// so the fermentate fits any build configuration.
+    /// Items defined under the same name in several branches are collapsed into the last one
+    #[default]
+    Keep,
+    /// Predicates are evaluated against the enabled features of the crate and the cfg options of the target:
+    /// items of the disabled branches (and `cfg_attr` of the disabled predicates) are skipped,
+    /// predicates which can't be evaluated (e.g. target options outside the build script) are kept
+    Evaluate,
+}
 
 #[derive(Debug, Clone)]
 pub struct Config {
@@ -10,7 +80,21 @@ pub struct Config {
     pub cbindgen_config_from_file: Option<String>,
     pub current_crate: Crate,
     pub external_crates: Vec<Crate>,
+    /// Errors of the external crates which can't be resolved (see `Builder::with_external_crates`),
+    /// reported as `Error::Configuration` by `generate`/`check` before anything is written
+    pub unresolved_crates: Vec<String>,
     pub languages: Vec<Lang>,
+    pub panic_policy: PanicPolicy,
+    pub async_mode: AsyncMode,
+    /// Path of the `ferment::Executor` implementation the async bindings expect as `runtime`
+    pub executor: String,
+    pub layout: FermentateLayout,
+    /// Path of the C header (`target/include/{crate_name}.h` by default)
+    pub header_path: Option<PathBuf>,
+    pub diagnostic_policy: DiagnosticPolicy,
+    pub cfg_mode: CfgMode,
+    /// Cfg options of the target (`CARGO_CFG_*` of the build script), `None` if they're unknown
+    pub target_cfg: Option<IndexMap<String, IndexSet<String>>>,
 }
 
 impl Display for Config {
@@ -22,11 +106,39 @@ impl Display for Config {
 
 impl Config {
     pub fn new(mod_name: &'static str, current_crate: Crate, cbindgen_config: cbindgen::Config) -> Self {
-        Self { mod_name: String::from(mod_name), cbindgen_config, current_crate, cbindgen_config_from_file: None, external_crates: vec![], languages: vec![] }
+        Self { mod_name: String::from(mod_name), cbindgen_config, current_crate, cbindgen_config_from_file: None, external_crates: vec![], unresolved_crates: vec![], languages: vec![], panic_policy: PanicPolicy::default(), async_mode: AsyncMode::default(), executor: String::from(DEFAULT_EXECUTOR), layout: FermentateLayout::default(), header_path: None, diagnostic_policy: DiagnosticPolicy::default(), cfg_mode: CfgMode::default(), target_cfg: None }
     }
     pub fn expansion_path(&self) -> PathBuf {
         self.current_crate.root_path.join(format!("{}.rs", self.mod_name))
     }
+    pub fn expansion_dir(&self) -> PathBuf {
+        self.current_crate.root_path.join(&self.mod_name)
+    }
+    /// Config file takes precedence over the programmatic config.
+    /// The default file falls back to `new_cbindgen_config` if it doesn't exist
+    pub(crate) fn resolve_cbindgen_config(&self) -> Result<cbindgen::Config, Error> {
+        match self.cbindgen_config_from_file.as_deref() {
+            Some(DEFAULT_CBINDGEN_CONFIG_FILE) if !std::path::Path::new(DEFAULT_CBINDGEN_CONFIG_FILE).exists() =>
+                Ok(self.new_cbindgen_config()),
+            Some(file) =>
+                cbindgen::Config::from_file(file)
+                    .map_err(Error::Configuration),
+            None =>
+                Ok(self.cbindgen_config.clone()),
+        }
+    }
+    pub(crate) fn executor_path(&self) -> Result<Path, Error> {
+        syn::parse_str(&self.executor)
+            .map_err(|err| Error::Configuration(format!("Invalid executor path `{}`: {err}", self.executor)))
+    }
+    #[allow(unused)]
+    pub(crate) fn check_external_crates(&self) -> Result<(), Error> {
+        if self.unresolved_crates.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Configuration(format!("Can't resolve the external crates of `{}`:\n\t{}", self.current_crate.name, self.unresolved_crates.join("\n\t"))))
+        }
+    }
     pub(crate) fn contains_fermented_crate(&self, ident: &Ident) -> bool {
         self.external_crates.iter()
             .any(|c| c.ident().eq(ident))
@@ -1,4 +1,3 @@
-use std::collections::HashMap;
 use indexmap::IndexMap;
 use syn::{Path, TypePath};
 use crate::context::ScopeChain;
@@ -6,6 +5,6 @@ use crate::context::ScopeChain;
 #[allow(unused)]
 #[derive(Clone, Default)]
 pub struct AttrsResolver {
-    pub inner: HashMap<ScopeChain, IndexMap<TypePath, Vec<Path>>>,
+    pub inner: IndexMap<ScopeChain, IndexMap<TypePath, Vec<Path>>>,
 }
 
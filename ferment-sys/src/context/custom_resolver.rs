@@ -1,5 +1,5 @@
-use std::collections::HashMap;
 use std::fmt::{Debug, Display, Formatter};
+use indexmap::IndexMap;
 use syn::{GenericArgument, Path, PathArguments, TraitBound, Type, TypePath, TypeTraitObject};
 use crate::context::{ScopeChain, TypeChain};
 use crate::ext::MaybeTraitBound;
@@ -8,7 +8,7 @@ use crate::formatter::types_dict;
 
 #[derive(Clone, Default)]
 pub struct CustomResolver {
-    pub inner: HashMap<ScopeChain, TypeChain>,
+    pub inner: IndexMap<ScopeChain, TypeChain>,
 }
 impl Debug for CustomResolver {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
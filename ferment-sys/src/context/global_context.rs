@@ -1,15 +1,17 @@
-use std::collections::HashSet;
 use std::fmt::Formatter;
+use std::path::PathBuf;
+use indexmap::IndexSet;
 use indexmap::IndexMap;
-use proc_macro2::Ident;
-use syn::{parse_quote, Attribute, Item, ItemTrait, Path, PathSegment, Type};
-use crate::Config;
-use crate::composable::{TraitModelPart1, TypeModel, TypeModeled};
-use crate::composer::CommaPunctuatedNestedArguments;
-use crate::context::{CustomResolver, GenericResolver, ImportResolver, ScopeChain, ScopeResolver, ScopeSearchKey, TraitsResolver, TypeChain};
+use proc_macro2::{Ident, Span};
+use syn::{parse_quote, Attribute, Item, ItemTrait, Path, PathSegment, Type, TypePath};
+use syn::spanned::Spanned;
+use crate::{Config, Diagnostic, Error};
+use crate::composable::{NestedArgument, TraitModelPart1, TypeModel, TypeModeled};
+use crate::composer::{CommaPunctuatedNestedArguments, MaybeMacroLabeled};
+use crate::context::{normalize_import_path, CustomResolver, GenericResolver, ImportResolver, Scope, ScopeChain, ScopeInfo, ScopeResolver, ScopeSearchKey, TraitsResolver, TypeChain};
 use crate::kind::{DictFermentableModelKind, DictTypeModelKind, GroupModelKind, MixinKind, ObjectKind, ScopeItemKind, SmartPointerModelKind, TypeModelKind};
-use crate::ext::{AsType, GenericBoundKey, RefineInScope, Split, ToPath, ToType};
-use crate::formatter::format_global_context;
+use crate::ext::{ArgsTransform, AsType, CrateBased, GenericBoundKey, Join, MaybeGenerics, MaybeIdent, Monomorphize, Pop, RefineInScope, Split, ToPath, ToType};
+use crate::formatter::{format_global_context, format_token_stream};
 
 #[derive(Clone)]
 pub struct GlobalContext {
@@ -19,7 +21,19 @@ pub struct GlobalContext {
     pub traits: TraitsResolver,
     pub custom: CustomResolver,
     pub imports: ImportResolver,
-    pub refined_mixins: IndexMap<MixinKind, HashSet<Option<Attribute>>>
+    pub refined_mixins: IndexMap<MixinKind, IndexSet<Option<Attribute>>>,
+    /// Concrete instantiations of the exported generic structs and enums with the monomorphized items
+    /// and the scopes they're fermented in
+    pub refined_instances: IndexMap<Type, (Item, ScopeChain)>,
+    /// Source files keyed by the path of the module they contain
+    pub module_files: IndexMap<Path, PathBuf>,
+    pub diagnostics: Vec<Diagnostic>,
+    /// Items defined in the private modules are named by the path they're re-exported with
+    pub public_paths: IndexMap<Path, Path>,
+    /// Names defined in each module of the crates, known once all the crates are visited
+    pub modules: IndexMap<Path, IndexSet<Ident>>,
+    /// Validated path of the `ferment::Executor` implementation the async bindings expect
+    pub executor: Path,
 }
 
 impl std::fmt::Debug for GlobalContext {
@@ -34,14 +48,16 @@ impl std::fmt::Display for GlobalContext {
     }
 }
 
-impl From<&Config> for GlobalContext {
-    fn from(config: &Config) -> Self {
-        GlobalContext::with_config(config.clone())
+impl TryFrom<&Config> for GlobalContext {
+    type Error = Error;
+    fn try_from(config: &Config) -> Result<Self, Self::Error> {
+        config.executor_path()
+            .map(|executor| GlobalContext::with_config(config.clone(), executor))
     }
 }
 impl GlobalContext {
-    pub fn with_config(config: Config) -> Self {
-        Self { config, scope_register: ScopeResolver::default(), generics: Default::default(), traits: Default::default(), custom: Default::default(), imports: Default::default(), refined_mixins: IndexMap::default(), }
+    pub fn with_config(config: Config, executor: Path) -> Self {
+        Self { config, executor, scope_register: ScopeResolver::default(), generics: Default::default(), traits: Default::default(), custom: Default::default(), imports: Default::default(), refined_mixins: IndexMap::default(), refined_instances: IndexMap::default(), module_files: IndexMap::default(), diagnostics: Vec::new(), public_paths: IndexMap::default(), modules: IndexMap::default() }
     }
     pub fn fermented_mod_name(&self) -> &str {
         &self.config.mod_name
@@ -113,6 +129,30 @@ impl GlobalContext {
         maybe_trait
     }
 
+    /// Self types of the formatting trait impls are resolved with the imports of the modules they're written in,
+    /// so it can only be done once all the crates are visited
+    pub(crate) fn resolve_formatter_impls(&mut self, modules: &IndexMap<Path, IndexSet<Ident>>) {
+        for (scope, impls) in std::mem::take(&mut self.traits.formatter_impls) {
+            let scope_path = scope.self_path_ref();
+            let crate_name = scope.crate_ident_as_path();
+            for (self_ty, kind) in impls {
+                let Some(PathSegment { ident, .. }) = self_ty.segments.first() else { continue };
+                let path = match self.imports.maybe_path(&scope, &GenericBoundKey::Ident(ident.clone())) {
+                    Some(import) if self_ty.leading_colon.is_none() => {
+                        let mut path = import.clone();
+                        path.segments.extend(self_ty.segments.iter().skip(1).cloned());
+                        normalize_import_path(&path, scope_path, scope.crate_ident_ref(), modules)
+                    },
+                    _ if self_ty.segments.len() == 1 =>
+                        scope_path.joined(ident),
+                    _ =>
+                        normalize_import_path(&self_ty, scope_path, scope.crate_ident_ref(), modules)
+                };
+                self.traits.add_formatters(path.crate_named(&crate_name), vec![kind]);
+            }
+        }
+    }
+
     pub fn item_trait_with_ident_for(&self, ident: &Ident, scope: &ScopeChain) -> Option<&TraitModelPart1> {
         self.traits
             .item_trait_with_ident_for(ident, scope)
@@ -287,6 +327,17 @@ impl GlobalContext {
         self.imports.maybe_import(scope, path)
     }
 
+    /// Import of the bare ident which could only be resolved once the entire tree was visited
+    /// (e.g. brought by a glob import), so it was taken as an item of the module (`module::Ident`)
+    pub fn maybe_late_import_path_ref(&self, path: &Path) -> Option<&Path> {
+        match path.segments.last() {
+            Some(last_segment) if self.maybe_scope_item_ref_obj_first(path).is_none() =>
+                self.maybe_import_scope_pair_ref(last_segment, &path.popped())
+                    .map(|(_, import)| import),
+            _ => None
+        }
+    }
+
     pub fn maybe_import_scope_pair_ref(&self, scope_path_last_segment: &PathSegment, scope_path_candidate: &Path) -> Option<(&ScopeChain, &Path)> {
         self.maybe_imports_scope_ref(scope_path_candidate)
             .and_then(|reexport_scope| {
@@ -369,6 +420,46 @@ impl GlobalContext {
         skip
     }
 
+    /// Concrete instantiation of the exported generic struct or enum (e.g. `Page<Identity>`) is fermented
+    /// as the monomorphized item within its own scope where the type parameters are replaced with the arguments
+    pub(crate) fn maybe_generic_instance(&self, object: &ObjectKind) -> Option<(Type, Item, ScopeChain, TypeChain)> {
+        let Some(TypeModelKind::Object(TypeModel { ty: ty @ Type::Path(TypePath { qself: None, path }), nested_arguments, .. })) = object.maybe_type_model_kind_ref() else {
+            return None;
+        };
+        let generic_path = path.arg_less();
+        let ScopeItemKind::Item(item @ (Item::Struct(..) | Item::Enum(..)), ..) = self.maybe_scope_item_ref_obj_first(&generic_path)? else {
+            return None;
+        };
+        let type_params = item.maybe_generics()?.type_params().collect::<Vec<_>>();
+        let arguments = nested_arguments.iter().map(NestedArgument::object).collect::<Vec<_>>();
+        if !item.is_labeled_for_export() || type_params.is_empty() || type_params.len() != arguments.len() || !arguments.iter().all(|arg| arg.is_concrete()) {
+            return None;
+        }
+        let params = IndexMap::<Type, ObjectKind>::from_iter(type_params.iter()
+            .zip(arguments)
+            .map(|(param, arg)| (param.ident.to_type(), arg.clone())));
+        let generic_scope = self.maybe_scope_ref_obj_first(&generic_path)?;
+        let mut type_chain = TypeChain::default();
+        self.scope_register.get(generic_scope)?.inner.iter()
+            .for_each(|(key, object)| {
+                let mut key = key.clone();
+                let mut object = object.clone();
+                key.monomorphize(&params);
+                object.monomorphize(&params);
+                type_chain.insert(key, object);
+            });
+        let ident = item.maybe_ident()?;
+        let self_object = type_chain.get(&ident.to_type())?.clone();
+        type_chain.insert(parse_quote!(Self), self_object.clone());
+        let mut instance = item.clone();
+        instance.monomorphize(&params);
+        let info = generic_scope.info();
+        let scope = ScopeChain::object(
+            ScopeInfo::new(info.attrs.clone(), info.crate_ident.clone(), Scope::new(path.clone(), self_object)),
+            generic_scope.parent_scope()?.clone());
+        Some((ty.clone(), instance, scope, type_chain))
+    }
+
 }
 
 
@@ -380,10 +471,16 @@ impl GlobalContext {
         self.scope_register.type_chain_mut(scope)
     }
     pub fn maybe_scope_ref(&self, path: &Path) -> Option<&ScopeChain> {
-        self.scope_register.maybe_scope(path)
+        self.scope_register.maybe_scope(self.defined_path(path))
     }
     pub fn maybe_scope_ref_obj_first(&self, path: &Path) -> Option<&ScopeChain> {
-        self.scope_register.maybe_first_obj_scope(path)
+        self.scope_register.maybe_first_obj_scope(self.defined_path(path))
+    }
+    /// Re-exported items are registered in the scopes they're defined in
+    fn defined_path<'a>(&'a self, path: &'a Path) -> &'a Path {
+        self.public_paths.iter()
+            .find_map(|(defined, public)| public.eq(path).then_some(defined))
+            .unwrap_or(path)
     }
     pub fn maybe_object_ref_by_value<'a>(&'a self, ty: &'a Type) -> Option<&'a ObjectKind> {
         ScopeSearchKey::maybe_from_ref(ty)
@@ -402,4 +499,33 @@ impl GlobalContext {
     }
 }
 
-
+/// Diagnostics
+impl GlobalContext {
+    pub fn add_module_file(&mut self, scope: &ScopeChain, path: PathBuf) {
+        self.module_files.insert(scope.self_path_ref().clone(), path);
+    }
+    /// File of the closest module containing the path
+    pub fn maybe_module_file(&self, path: &Path) -> Option<&PathBuf> {
+        let mut path = path.clone();
+        while !path.segments.is_empty() {
+            if let Some(file) = self.module_files.get(&path) {
+                return Some(file)
+            }
+            path.segments.pop();
+            path.segments.pop_punct();
+        }
+        None
+    }
+    pub fn item_diagnostic(&self, item: &Item, path: &Path, message: String) -> Diagnostic {
+        self.span_diagnostic(path, item.maybe_ident().map(Ident::span).unwrap_or_else(|| item.span()), message)
+    }
+    pub fn span_diagnostic(&self, path: &Path, span: Span, message: String) -> Diagnostic {
+        Diagnostic::new(format_token_stream(path), self.maybe_module_file(path).cloned(), Some(span.start().line), message)
+    }
+    /// The same construct may fail to compose more than once (e.g. in a few generic contexts)
+    pub fn add_diagnostic(&mut self, diagnostic: Diagnostic) {
+        if !self.diagnostics.contains(&diagnostic) {
+            self.diagnostics.push(diagnostic);
+        }
+    }
+}
@@ -1,13 +1,20 @@
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use proc_macro2::Ident;
-use syn::{Path, PathSegment, UseGroup, UseName, UsePath, UseRename, UseTree};
+use syn::{Path, PathSegment, UseGroup, UseGlob, UseName, UsePath, UseRename, UseTree, Visibility};
 use syn::punctuated::Punctuated;
-use crate::context::ScopeChain;
-use crate::ext::{GenericBoundKey, ToPath};
+use crate::context::{is_visible_from, normalize_path, ScopeChain};
+use crate::ext::{GenericBoundKey, Join, ToPath};
 
 #[derive(Clone, Default)]
 pub struct ImportResolver {
     pub inner: IndexMap<ScopeChain, IndexMap<Path, Path>>,
+    /// Paths of the modules imported with `use path::*` (as written),
+    /// they can only be resolved once the entire tree is visited (see `resolve_globs`)
+    pub globs: IndexMap<ScopeChain, IndexSet<Path>>,
+    /// Visibility of the names brought by the non-private `use` items (re-exports)
+    pub reexports: IndexMap<ScopeChain, IndexMap<Path, Visibility>>,
+    /// Visibility of the glob re-exports (`pub use path::*`) keyed by the path as written
+    pub glob_reexports: IndexMap<ScopeChain, IndexMap<Path, Visibility>>,
 }
 
 impl ImportResolver {
@@ -43,13 +50,122 @@ impl ImportResolver {
             UseTree::Group(UseGroup { items, .. }) =>
                 items.iter()
                     .for_each(|use_tree| self.fold_import_tree(scope, use_tree, current_path.clone())),
-            _ => {
-                // For a glob import, we can't determine the full path statically
-                // Just ignore them for now
+            UseTree::Glob(UseGlob { .. }) => {
+                let path = Path { leading_colon: None, segments: Punctuated::from_iter(current_path.into_iter().map(PathSegment::from)) };
+                self.globs
+                    .entry(scope.clone())
+                    .or_default()
+                    .insert(path);
             }
         }
     }
 
+    /// Records the names brought by the `use` item with non-private visibility,
+    /// the paths themselves are folded with `fold_import_tree`
+    pub(crate) fn fold_reexport_tree(&mut self, scope: &ScopeChain, vis: &Visibility, use_tree: &UseTree, mut current_path: Vec<Ident>) {
+        match use_tree {
+            UseTree::Path(UsePath { ident, tree, .. }) => {
+                current_path.push(ident.clone());
+                self.fold_reexport_tree(scope, vis, tree, current_path);
+            },
+            UseTree::Name(UseName { ident: name, .. }) |
+            UseTree::Rename(UseRename { rename: name, .. }) => if name != "_" {
+                self.reexports
+                    .entry(scope.clone())
+                    .or_default()
+                    .insert(name.to_path(), vis.clone());
+            },
+            UseTree::Group(UseGroup { items, .. }) =>
+                items.iter()
+                    .for_each(|use_tree| self.fold_reexport_tree(scope, vis, use_tree, current_path.clone())),
+            UseTree::Glob(UseGlob { .. }) => {
+                let path = Path { leading_colon: None, segments: Punctuated::from_iter(current_path.into_iter().map(PathSegment::from)) };
+                self.glob_reexports
+                    .entry(scope.clone())
+                    .or_default()
+                    .insert(path, vis.clone());
+            }
+        }
+    }
+
+    /// Resolves glob imports against the names defined in the visited modules
+    /// (keyed by the full path of the module) and the names imported there.
+    /// Precedence: items defined in the module and explicit imports always win over glob imports,
+    /// and if a few glob imports bring the same name, the first one wins.
+    /// Glob re-exports (`pub use inner::*`) are followed until nothing changes.
+    pub(crate) fn resolve_globs(&mut self, modules: &IndexMap<Path, IndexSet<Ident>>) {
+        let mut resolved = true;
+        while resolved {
+            resolved = false;
+            for (scope, globs) in self.globs.clone() {
+                let scope_path = scope.self_path_ref();
+                for glob in globs {
+                    let module_path = normalize_import_path(&glob, scope_path, scope.crate_ident_ref(), modules);
+                    for (name, path) in self.glob_names(&module_path, modules) {
+                        let is_defined = modules.get(scope_path)
+                            .is_some_and(|names| names.contains(&name));
+                        let imports = self.inner.entry(scope.clone()).or_default();
+                        let key = name.to_path();
+                        if !is_defined && !imports.contains_key(&key) {
+                            if let Some(vis) = self.glob_reexports.get(&scope).and_then(|globs| globs.get(&glob)) {
+                                self.reexports
+                                    .entry(scope.clone())
+                                    .or_default()
+                                    .insert(key.clone(), vis.clone());
+                            }
+                            imports.insert(key, path);
+                            resolved = true;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Full paths of the items re-exported from the `reachable` modules with the visibility
+    /// which allows to name them from `from` (see `is_visible_from`) mapped onto the re-exported paths.
+    /// Chains of re-exports are followed down to the path the item is defined with
+    pub(crate) fn public_reexports(&self, reachable: &IndexSet<Path>, from: Option<&Path>, modules: &IndexMap<Path, IndexSet<Ident>>) -> IndexMap<Path, Path> {
+        let mut aliases = IndexMap::new();
+        let mut public = vec![];
+        for (scope, names) in &self.reexports {
+            let scope_path = scope.self_path_ref();
+            for (name, vis) in names {
+                if let Some(path) = self.inner.get(scope).and_then(|imports| imports.get(name)) {
+                    let alias = scope_path.joined(name);
+                    aliases.insert(alias.clone(), normalize_import_path(path, scope_path, scope.crate_ident_ref(), modules));
+                    if reachable.contains(scope_path) && is_visible_from(vis, scope_path, &scope.crate_ident_as_path(), from) {
+                        public.push(alias);
+                    }
+                }
+            }
+        }
+        let mut paths = IndexMap::new();
+        for alias in public {
+            let mut path = &alias;
+            let mut depth = 0;
+            while let Some(target) = aliases.get(path).filter(|_| depth < aliases.len()) {
+                path = target;
+                depth += 1;
+            }
+            paths.entry(path.clone()).or_insert(alias.clone());
+        }
+        paths
+    }
+
+    /// Names visible through `use module_path::*` with their full paths
+    fn glob_names(&self, module_path: &Path, modules: &IndexMap<Path, IndexSet<Ident>>) -> Vec<(Ident, Path)> {
+        let mut names = modules.get(module_path)
+            .map(|names| names.iter().map(|name| (name.clone(), module_path.joined(name))).collect::<Vec<_>>())
+            .unwrap_or_default();
+        if let Some((scope, imports)) = self.inner.iter().find(|(scope, _)| module_path.eq(scope.self_path_ref())) {
+            names.extend(imports.iter()
+                .filter_map(|(alias, path)| alias.get_ident()
+                    .map(|name| (name.clone(), normalize_import_path(path, module_path, scope.crate_ident_ref(), modules)))));
+        }
+        names
+    }
+
     pub fn maybe_scope_imports(&self, scope: &ScopeChain) -> Option<&IndexMap<Path, Path>> {
         self.inner.get(scope)
     }
@@ -92,3 +208,15 @@ impl ImportResolver {
 
 
 }
+
+/// Converts the import path (as written in the module with `scope_path`) into the full path:
+/// `crate::`, `self::`, `super::` and the paths relative to the child modules are resolved,
+/// the rest (external crates, std) is left as is
+pub(crate) fn normalize_import_path(path: &Path, scope_path: &Path, crate_ident: &Ident, modules: &IndexMap<Path, IndexSet<Ident>>) -> Path {
+    match path.segments.first() {
+        Some(PathSegment { ident, .. }) if path.leading_colon.is_none() && modules.contains_key(&scope_path.joined(ident)) =>
+            scope_path.joined(path),
+        _ =>
+            normalize_path(path, scope_path, &crate_ident.to_path())
+    }
+}
@@ -3,11 +3,11 @@ use std::hash::{Hash, Hasher};
 use proc_macro2::Ident;
 use quote::ToTokens;
 use syn::__private::TokenStream2;
-use syn::{Attribute, Generics, parse_quote, Path, Type, PathSegment};
+use syn::{Attribute, Generics, Item, ItemEnum, ItemStruct, parse_quote, Path, Type, PathSegment, VisRestricted, Visibility};
 use crate::composable::CfgAttributes;
 use crate::context::{GenericChain, Scope, ScopeInfo};
-use crate::kind::{ObjectKind, TypeModel};
-use crate::ext::{CRATE, ResolveAttrs, ToPath, ToType, Join, GenericBoundKey, CrateBased};
+use crate::kind::{ObjectKind, ScopeItemKind, TypeModel};
+use crate::ext::{CRATE, SELF, SUPER, ResolveAttrs, ToPath, ToType, Join, GenericBoundKey, CrateBased, Pop};
 use crate::formatter::{format_attrs, format_token_stream};
 
 
@@ -257,6 +257,15 @@ impl ScopeChain {
             ScopeChain::Impl { parent, .. } => Some(parent),
         }
     }
+    /// Generic structs and enums are fermented per instantiation (see `GlobalContext::maybe_generic_instance`)
+    pub fn is_generic_object(&self) -> bool {
+        match self {
+            ScopeChain::Object { info, .. } => matches!(
+                info.self_scope.object.maybe_scope_item(),
+                Some(ScopeItemKind::Item(Item::Struct(ItemStruct { generics, .. }) | Item::Enum(ItemEnum { generics, .. }), ..)) if generics.type_params().next().is_some()),
+            _ => false
+        }
+    }
     pub fn parent_object(&self) -> Option<&ObjectKind> {
         self.parent_scope()
             .map(|scope| scope.self_object_ref())
@@ -271,6 +280,25 @@ impl ScopeChain {
         }
     }
 
+    /// Closest module (or crate root) containing the scope
+    pub fn mod_chain(&self) -> &Self {
+        match self {
+            ScopeChain::CrateRoot { .. } |
+            ScopeChain::Mod { .. } => self,
+            ScopeChain::Trait { parent, .. } |
+            ScopeChain::Fn { parent, .. } |
+            ScopeChain::Object { parent, .. } |
+            ScopeChain::Impl { parent, .. } => parent.mod_chain(),
+        }
+    }
+
+    /// Rewrites leading `crate`, `self` and any number of `super` segments
+    /// into the fully-qualified path relative to the closest module
+    pub fn normalize_path(&self, path: &Path) -> Path {
+        let crate_name = self.crate_ident_as_path();
+        normalize_path(path, &self.mod_chain().self_path_ref().crate_named(&crate_name), &crate_name)
+    }
+
     pub fn obj_root_model_composer(&self) -> fn(TypeModel) -> ObjectKind {
         match self.obj_root_chain() {
             Some(ScopeChain::Trait { .. }) =>
@@ -321,6 +349,51 @@ impl ScopeChain {
     }
 }
 
+/// Rewrites leading `crate`, `self` and any number of `super` segments of the path written
+/// in the module with `mod_path` into the fully-qualified path, the rest is left as is
+pub fn normalize_path(path: &Path, mod_path: &Path, crate_name: &Path) -> Path {
+    if path.leading_colon.is_some() {
+        return path.clone();
+    }
+    let mut segments = path.segments.iter().peekable();
+    let base = match segments.peek() {
+        Some(PathSegment { ident, .. }) if ident == CRATE => {
+            segments.next();
+            crate_name.clone()
+        },
+        Some(PathSegment { ident, .. }) if ident == SELF || ident == SUPER => {
+            let mut base = mod_path.clone();
+            segments.next_if(|PathSegment { ident, .. }| ident == SELF);
+            while segments.next_if(|PathSegment { ident, .. }| ident == SUPER).is_some() {
+                // Can't go above the crate root
+                if base.segments.len() <= 1 {
+                    return path.clone();
+                }
+                base = base.popped();
+            }
+            base
+        },
+        _ => return path.clone()
+    };
+    let mut normalized = base;
+    normalized.segments.extend(segments.cloned());
+    normalized
+}
+
+/// Whether an item declared as `vis` in the module with `mod_path` can be named from the module `from`,
+/// `None` stands for another crate where only `pub` items are visible
+pub fn is_visible_from(vis: &Visibility, mod_path: &Path, crate_name: &Path, from: Option<&Path>) -> bool {
+    let restriction = match (vis, from) {
+        (Visibility::Public(..), _) => return true,
+        (_, None) => return false,
+        (Visibility::Inherited, Some(_)) => mod_path.clone(),
+        (Visibility::Restricted(VisRestricted { path, .. }), Some(_)) => normalize_path(path, mod_path, crate_name),
+    };
+    from.is_some_and(|from| from.segments.len() >= restriction.segments.len() && from.segments.iter()
+        .zip(restriction.segments.iter())
+        .all(|(from, restriction)| from.ident == restriction.ident))
+}
+
 impl ResolveAttrs for ScopeChain {
     fn resolve_attrs(&self) -> Vec<Option<Attribute>> {
         match self {
@@ -1,13 +1,16 @@
 use std::cell::RefCell;
 use std::fmt::Formatter;
 use std::rc::Rc;
-use syn::{Attribute, Item, ItemType, parse_quote, Path, TraitBound, Type, TypeBareFn, TypePath, TypeTraitObject, ItemTrait};
+use indexmap::IndexSet;
+use proc_macro2::{Ident, Span};
+use quote::format_ident;
+use syn::{Attribute, Item, ItemType, parse_quote, Path, PathArguments, PathSegment, TraitBound, Type, TypeBareFn, TypeImplTrait, TypePath, TypeReference, TypeTraitObject, ItemTrait, Visibility};
 use crate::ast::{CommaPunctuated, Depunctuated};
 use crate::composable::TraitModelPart1;
 use crate::composer::{ComposerLink, MaybeMacroLabeled};
-use crate::context::{GlobalContext, ScopeChain, ScopeSearch, ScopeSearchKey};
-use crate::kind::{ObjectKind, ScopeItemKind, SpecialType, TypeModelKind};
-use crate::ext::{DictionaryType, extract_trait_names, FermentableDictionaryType, ToType, AsType, Resolve, ResolveTrait, LifetimeProcessor, MaybeLambdaArgs, MaybeTraitBound};
+use crate::context::{is_visible_from, normalize_import_path, GlobalContext, ScopeChain, ScopeSearch, ScopeSearchKey};
+use crate::kind::{ObjectKind, ScopeItemKind, SpecialType, TraitProxyKind, TypeModelKind};
+use crate::ext::{CrateBased, DictionaryType, extract_trait_names, GenericBoundKey, Join, is_handle_export, MaybeAttrs, FermentableDictionaryType, ToType, AsType, Resolve, ResolveTrait, LifetimeProcessor, MaybeLambdaArgs, MaybeTraitBound, MarkerBound, Proxyable, GenericNestedArg, thread_safety_markers, thread_safety_markers_in_type};
 use crate::lang::Specification;
 use crate::presentation::{FFIFullDictionaryPath, FFIFullPath};
 use crate::print_phase;
@@ -42,6 +45,34 @@ impl ScopeContext {
         let context = self.context.borrow();
         context.config.current_crate.ident().eq(self.scope.crate_ident_ref())
     }
+    /// Whether an item declared as `vis` in this scope can be named from the fermented module (see `Reachability`)
+    pub fn is_visible_from_fermented_mod(&self, vis: &Visibility) -> bool {
+        let crate_name = self.scope.crate_ident_as_path();
+        let mod_path = self.scope.mod_chain().self_path_ref().crate_named(&crate_name);
+        let from = self.is_from_current_crate()
+            .then(|| crate_name.joined(&format_ident!("{}", self.context.borrow().fermented_mod_name())));
+        is_visible_from(vis, &mod_path, &crate_name, from.as_ref())
+    }
+    /// Full path of the value (e.g. a const used in the enum discriminant) written in this scope,
+    /// resolved with the imports of the module the same way as the self types of the formatting impls
+    pub fn full_value_path(&self, path: &Path) -> Path {
+        let mod_scope = self.scope.mod_chain();
+        let scope_path = mod_scope.self_path_ref();
+        let Some(PathSegment { ident, .. }) = path.segments.first() else { return path.clone() };
+        let context = self.context.borrow();
+        let full_path = match context.imports.maybe_path(mod_scope, &GenericBoundKey::ident(ident)) {
+            Some(import) if path.leading_colon.is_none() => {
+                let mut import = import.clone();
+                import.segments.extend(path.segments.iter().skip(1).cloned());
+                normalize_import_path(&import, scope_path, mod_scope.crate_ident_ref(), &context.modules)
+            },
+            _ if path.segments.len() == 1 =>
+                scope_path.joined(ident),
+            _ =>
+                normalize_import_path(path, scope_path, mod_scope.crate_ident_ref(), &context.modules)
+        };
+        full_path.crate_named(&mod_scope.crate_ident_as_path())
+    }
     pub fn with(scope: ScopeChain, context: Rc<RefCell<GlobalContext>>) -> Self {
         Self { scope, context }
     }
@@ -151,7 +182,8 @@ impl ScopeContext {
                 match self.maybe_scope_item_obj_first(path)
                     .or_else(|| self.maybe_scope_item_obj_first(&path.lifetimes_cleaned())) {
                     Some(item) =>
-                        (!item.is_labeled_for_export() && !item.is_labeled_for_register()).then(|| item.scope().to_type()),
+                        ((!item.is_labeled_for_export() && !item.is_labeled_for_register()) || item.maybe_attrs().is_some_and(|attrs| is_handle_export(attrs)))
+                            .then(|| item.scope().to_type()),
                     None =>
                         (!path.is_fermentable_dictionary_type() && !path.is_primitive()).then(|| ty.clone())
                 }
@@ -198,6 +230,117 @@ impl ScopeContext {
         let result = lock.maybe_object_ref_by_value(ty).cloned();
         result
     }
+    /// Instantiation of the exported generic struct or enum lives in the generics module
+    pub fn is_generic_instance(&self, path: &Path) -> bool {
+        self.context.borrow().refined_instances.contains_key(&path.to_type())
+    }
+    /// Structs exported with the `handle` option are passed as the boxed Rust values
+    pub fn is_handle(&self, ty: &Type) -> bool {
+        self.maybe_object_by_value(ty)
+            .is_some_and(|obj| obj.maybe_scope_item()
+                .and_then(MaybeAttrs::maybe_attrs)
+                .is_some_and(|attrs| is_handle_export(attrs)))
+    }
+    /// Thread-safety markers the `*_Proxy` of the trait should implement:
+    /// those required by its supertraits and by the signatures accepting it
+    pub fn trait_proxy_markers(&self, trait_path: &Path) -> IndexSet<Ident> {
+        let mut markers = IndexSet::new();
+        if let Some(ScopeItemKind::Item(Item::Trait(ItemTrait { supertraits, .. }), ..)) = self.maybe_scope_item_obj_first(trait_path) {
+            let supertraits = supertraits.iter()
+                .filter_map(MaybeTraitBound::maybe_trait_bound)
+                .map(|bound| &bound.path);
+            markers.extend(thread_safety_markers(std::iter::once(trait_path).chain(supertraits), trait_path));
+        }
+        let lock = self.context.borrow();
+        lock.scope_register.inner.values()
+            .flat_map(|type_chain| type_chain.inner.values())
+            .for_each(|obj| match obj {
+                ObjectKind::Type(TypeModelKind::Bounds(model)) => model.chain.values()
+                    .for_each(|bounds| {
+                        let paths = bounds.iter()
+                            .filter_map(|bound| match bound.maybe_type() {
+                                Some(Type::Path(TypePath { path, .. })) => Some(path),
+                                _ => None
+                            })
+                            .collect::<Vec<_>>();
+                        markers.extend(thread_safety_markers(paths.iter(), trait_path));
+                    }),
+                _ => if let Some(ty) = obj.maybe_type() {
+                    markers.extend(thread_safety_markers_in_type(&ty, trait_path));
+                }
+            });
+        markers
+    }
+    /// Records the construct of this scope which can't be fermented, the composition skips it
+    pub fn add_diagnostic(&self, span: Span, message: String) {
+        let path = self.scope.self_path_ref().crate_named(&self.scope.crate_ident_as_path());
+        let mut context = self.context.borrow_mut();
+        let diagnostic = context.span_diagnostic(&path, span, message);
+        context.add_diagnostic(diagnostic);
+    }
+    pub fn is_fieldless_enum(&self, ty: &Type) -> bool {
+        self.maybe_object_by_value(ty)
+            .is_some_and(|obj| obj.is_fieldless_enum())
+    }
+    /// Exported traits can be implemented on the foreign side: their `*_Proxy` is accepted in place of
+    /// `impl Trait`, `dyn Trait` behind a reference or a smart pointer and type parameters bounded by `Trait`
+    pub fn maybe_trait_proxy_kind(&self, ty: &Type) -> Option<TraitProxyKind> {
+        match ty {
+            Type::Reference(TypeReference { mutability, elem, .. }) =>
+                self.maybe_proxied_trait_path(elem)
+                    .map(|path| if mutability.is_some() { TraitProxyKind::MutRef(path) } else { TraitProxyKind::Ref(path) }),
+            Type::Path(TypePath { path, .. }) => match path.segments.last() {
+                Some(PathSegment { ident, arguments: PathArguments::AngleBracketed(..) }) => {
+                    let nested_ty = ty.maybe_first_nested_type_ref()
+                        .filter(|nested_ty| matches!(nested_ty, Type::TraitObject(..)))?;
+                    let path = self.maybe_proxied_trait_path(nested_ty)?;
+                    match ident.to_string().as_str() {
+                        "Box" => Some(TraitProxyKind::Box(path)),
+                        "Arc" => Some(TraitProxyKind::Arc(path)),
+                        "Rc" => Some(TraitProxyKind::Rc(path)),
+                        _ => None
+                    }
+                },
+                _ => self.maybe_proxied_trait_path(ty)
+                    .map(TraitProxyKind::Value)
+            },
+            _ => self.maybe_proxied_trait_path(ty)
+                .map(TraitProxyKind::Value)
+        }
+    }
+    fn maybe_proxied_trait_path(&self, ty: &Type) -> Option<Path> {
+        let bounds = match ty {
+            Type::ImplTrait(TypeImplTrait { bounds, .. }) |
+            Type::TraitObject(TypeTraitObject { bounds, .. }) => bounds.iter()
+                .filter_map(MaybeTraitBound::maybe_trait_bound)
+                .map(|TraitBound { path, .. }| match self.full_type_for(&path.to_type()) {
+                    Type::Path(TypePath { qself: None, path }) => Some(path),
+                    // Callbacks (`dyn Fn(..)`) aren't resolved to the paths of traits
+                    _ => None
+                })
+                .collect::<Option<Vec<_>>>()?,
+            Type::Path(..) => match self.maybe_object_by_key(ty) {
+                Some(ObjectKind::Type(TypeModelKind::Bounds(model))) if model.chain.len() == 1 => model.chain.values()
+                    .flatten()
+                    .map(|bound| match bound.maybe_type() {
+                        Some(Type::Path(TypePath { path, .. })) => Some(path),
+                        _ => None
+                    })
+                    .collect::<Option<Vec<_>>>()?,
+                _ => return None
+            },
+            _ => return None
+        };
+        let mut bounds = bounds.into_iter().filter(|path| !path.is_marker_bound());
+        match (bounds.next(), bounds.next()) {
+            (Some(path), None) => match self.maybe_scope_item_obj_first(&path) {
+                Some(ScopeItemKind::Item(item @ Item::Trait(..), ..)) if item.is_labeled_for_export() && matches!(&item, Item::Trait(item_trait) if item_trait.is_proxyable()) =>
+                    Some(path),
+                _ => None
+            },
+            _ => None
+        }
+    }
     pub fn maybe_object_by_predicate_ref(&self, predicate: &ScopeSearch) -> Option<ObjectKind> {
         match predicate {
             ScopeSearch::KeyInScope(search_key, scope) =>
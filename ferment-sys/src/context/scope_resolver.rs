@@ -1,16 +1,16 @@
-use std::collections::HashMap;
 use std::fmt::{Debug, Display, Formatter};
+use indexmap::IndexMap;
 use syn::{Path, Type};
 use crate::context::{ScopeChain, ScopeSearchKey, TypeChain};
 use crate::kind::ObjectKind;
 use crate::ext::LifetimeProcessor;
 use crate::formatter::types_dict;
 
-pub type ScopeRefinement = Vec<(ScopeChain, HashMap<Type, ObjectKind>)>;
+pub type ScopeRefinement = Vec<(ScopeChain, IndexMap<Type, ObjectKind>)>;
 
 #[derive(Clone, Default)]
 pub struct ScopeResolver {
-    pub inner: HashMap<ScopeChain, TypeChain>,
+    pub inner: IndexMap<ScopeChain, TypeChain>,
 }
 
 impl Debug for ScopeResolver {
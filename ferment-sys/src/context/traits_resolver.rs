@@ -1,15 +1,19 @@
-use std::collections::HashMap;
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use proc_macro2::Ident;
 use syn::{ItemTrait, Path,};
 use crate::composable::TraitModelPart1;
 use crate::context::ScopeChain;
-use crate::kind::ObjectKind;
+use crate::kind::{FormatterKind, ObjectKind};
 
 #[derive(Clone, Default)]
 pub struct TraitsResolver {
     pub inner: IndexMap<ScopeChain, IndexMap<Ident, TraitModelPart1>>,
-    pub used_traits_dictionary: HashMap<ScopeChain, Vec<Path>>,
+    pub used_traits_dictionary: IndexMap<ScopeChain, Vec<Path>>,
+    /// `impl Display for Type` and the like by the module scopes they're written in,
+    /// the self types are resolved into `formatters` once all the imports are known
+    pub formatter_impls: IndexMap<ScopeChain, Vec<(Path, FormatterKind)>>,
+    /// Formatting traits implemented by the types (by the full paths of the types)
+    pub formatters: IndexMap<Path, IndexSet<FormatterKind>>,
 }
 
 impl TraitsResolver {
@@ -27,6 +31,26 @@ impl TraitsResolver {
             .extend(trait_names);
     }
 
+    pub fn add_formatters(&mut self, ty: Path, kinds: Vec<FormatterKind>) {
+        if !kinds.is_empty() {
+            self.formatters
+                .entry(ty)
+                .or_default()
+                .extend(kinds);
+        }
+    }
+
+    pub fn add_formatter_impl(&mut self, scope: &ScopeChain, self_ty: Path, kind: FormatterKind) {
+        self.formatter_impls
+            .entry(scope.clone())
+            .or_default()
+            .push((self_ty, kind));
+    }
+
+    pub fn maybe_formatters(&self, ty: &Path) -> Option<&IndexSet<FormatterKind>> {
+        self.formatters.get(ty)
+    }
+
     pub fn item_trait_with_ident_for(&self, ident: &Ident, scope: &ScopeChain) -> Option<&TraitModelPart1> {
         self.inner
             .get(scope)
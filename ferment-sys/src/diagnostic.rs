@@ -0,0 +1,33 @@
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+
+/// Construct which can't be fermented: the item is skipped and the generation continues
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// Full path of the item (e.g. `my_crate::model::Model`)
+    pub item: String,
+    pub file: Option<PathBuf>,
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match (&self.file, self.line) {
+            (Some(file), Some(line)) => write!(f, "{}:{line}: ", file.display())?,
+            (Some(file), None) => write!(f, "{}: ", file.display())?,
+            _ => {}
+        }
+        write!(f, "{}: {}", self.item, self.message)
+    }
+}
+
+impl Diagnostic {
+    pub(crate) fn new<S: Into<String>>(item: S, file: Option<PathBuf>, line: Option<usize>, message: String) -> Self {
+        Self { item: item.into(), file, line, message }
+    }
+    /// Line for the `cargo:warning=` instruction of a build script
+    pub fn cargo_warning(&self) -> String {
+        format!("cargo:warning={}", self.to_string().replace('\n', " "))
+    }
+}
@@ -2,6 +2,7 @@ use std::env::VarError;
 use std::fmt::Debug;
 use std::io;
 use std::process::ExitStatus;
+use crate::{Diagnostic, StaleFile};
 
 #[derive(Debug)]
 pub enum Error {
@@ -13,6 +14,10 @@ pub enum Error {
     Exit(ExitStatus),
     VarError(VarError),
     Cbindgen(cbindgen::Error),
+    /// Generated files differ from the ones on disk (see `Builder::check`)
+    Stale(Vec<StaleFile>),
+    /// Exported items which can't be fermented (see `DiagnosticPolicy::Deny`)
+    Diagnostics(Vec<Diagnostic>),
 }
 
 impl std::fmt::Display for Error {
@@ -26,6 +31,14 @@ impl std::fmt::Display for Error {
             Error::Exit(exit) => std::fmt::Display::fmt(exit, f),
             Error::VarError(err) => std::fmt::Display::fmt(err, f),
             Error::Cbindgen(err) => std::fmt::Display::fmt(err, f),
+            Error::Stale(files) => {
+                f.write_str("Fermentate is out of date:")?;
+                files.iter().try_for_each(|file| write!(f, "\n{file}"))
+            }
+            Error::Diagnostics(diagnostics) => {
+                f.write_str("Unsupported constructs:")?;
+                diagnostics.iter().try_for_each(|diagnostic| write!(f, "\n{diagnostic}"))
+            }
         }
     }
 }
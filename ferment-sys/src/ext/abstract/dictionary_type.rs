@@ -17,6 +17,7 @@ pub trait DictionaryType {
     fn is_void(&self) -> bool;
     fn is_digit(&self) -> bool;
     fn is_128_digit(&self) -> bool;
+    fn is_char(&self) -> bool;
     fn is_bool(&self) -> bool;
     fn is_str(&self) -> bool;
     fn is_string(&self) -> bool;
@@ -42,7 +43,7 @@ impl DictionaryType for Ident {
     }
 
     fn is_digit(&self) -> bool {
-        matches!(self.to_string().as_str(), "i8" | "u8" | "i16" | "u16" | "i32" | "u32" | "i64" | "u64" | "f64" | "isize" | "usize")
+        matches!(self.to_string().as_str(), "i8" | "u8" | "i16" | "u16" | "i32" | "u32" | "i64" | "u64" | "f32" | "f64" | "isize" | "usize")
     }
 
     // 128-bit integers don't currently have a known stable ABI so they aren't FFI-safe, should be exported as [u8/i8; 16] instead
@@ -50,6 +51,11 @@ impl DictionaryType for Ident {
         matches!(self.to_string().as_str(), "i128" | "u128")
     }
 
+    // char isn't FFI-safe (not every u32 is a valid char), so it's exported as a u32 Unicode scalar value
+    fn is_char(&self) -> bool {
+        self.eq("char")
+    }
+
     fn is_bool(&self) -> bool {
         self.eq("bool")
     }
@@ -133,6 +139,10 @@ impl DictionaryType for PathSegment {
         self.ident.is_128_digit()
     }
 
+    fn is_char(&self) -> bool {
+        self.ident.is_char()
+    }
+
     fn is_bool(&self) -> bool {
         self.ident.is_bool()
     }
@@ -199,6 +209,9 @@ impl DictionaryType for Colon2Punctuated<PathSegment> {
     fn is_128_digit(&self) -> bool {
         self.last().map(|seg| seg.is_128_digit()).unwrap_or_default()
     }
+    fn is_char(&self) -> bool {
+        self.last().map(|seg| seg.is_char()).unwrap_or_default()
+    }
 
     fn is_bool(&self) -> bool {
         self.last().map(|seg| seg.is_bool()).unwrap_or_default()
@@ -267,6 +280,9 @@ impl DictionaryType for Path {
     fn is_128_digit(&self) -> bool {
         self.segments.is_128_digit()
     }
+    fn is_char(&self) -> bool {
+        self.segments.is_char()
+    }
 
     fn is_bool(&self) -> bool {
         self.segments.is_bool()
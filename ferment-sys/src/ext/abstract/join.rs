@@ -38,6 +38,7 @@ impl Join<Item> for ScopeChain {
         let self_scope = self.self_scope_ref().joined(item);
         match item {
             Item::Const(..) |
+            Item::Static(..) |
             Item::Type(..) |
             Item::Enum(..) |
             Item::Struct(..) =>
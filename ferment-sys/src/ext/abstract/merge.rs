@@ -2,6 +2,7 @@ use std::fmt::{Debug, Display};
 use std::hash::Hash;
 use indexmap::IndexMap;
 use indexmap::map::{Entry, OccupiedEntry};
+use syn::Visibility;
 use crate::kind::ObjectKind;
 use crate::tree::ScopeTreeExportItem;
 
@@ -58,8 +59,11 @@ pub trait MergeInto {
 
 impl MergeInto for ScopeTreeExportItem {
     fn merge_into(&self, destination: &mut Self) {
-        if let (ScopeTreeExportItem::Tree(_dest_ctx, _, ref mut dest_exports, _dest_attrs),
-            ScopeTreeExportItem::Tree(_src_ctx, _, source_exports, _source_attrs), ) = (destination, &self) {
+        if let (ScopeTreeExportItem::Tree(_dest_ctx, _, ref mut dest_exports, _dest_attrs, dest_vis),
+            ScopeTreeExportItem::Tree(_src_ctx, _, source_exports, _source_attrs, source_vis), ) = (destination, &self) {
+            if let Visibility::Inherited = dest_vis {
+                *dest_vis = source_vis.clone();
+            }
             for (name, source_tree) in source_exports {
                 match dest_exports.entry(name.clone()) {
                     Entry::Occupied(mut o) => {
@@ -1,4 +1,4 @@
-use syn::{Path, PathArguments, PathSegment};
+use syn::{Path, PathArguments, PathSegment, Type, TypePath};
 use crate::ast::Colon2Punctuated;
 
 pub trait ArgsTransform {
@@ -19,4 +19,13 @@ impl ArgsTransform for Path {
     fn arg_less(&self) -> Self {
         Path { segments: self.segments.arg_less(), leading_colon: self.leading_colon }
     }
-}
\ No newline at end of file
+}
+impl ArgsTransform for Type {
+    fn arg_less(&self) -> Self {
+        match self {
+            Type::Path(TypePath { qself: None, path }) =>
+                Type::Path(TypePath { qself: None, path: path.arg_less() }),
+            _ => self.clone()
+        }
+    }
+}
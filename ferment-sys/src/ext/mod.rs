@@ -10,9 +10,11 @@ mod maybe_args;
 mod maybe_trait_bound;
 mod maybe_ident;
 mod path_transform;
+mod proxy;
 mod present;
 mod refine;
 mod resolve;
+mod unsupported;
 mod visitor;
 
 use syn::{Path, PathSegment};
@@ -28,9 +30,11 @@ pub use self::maybe_generic_type::*;
 pub use self::maybe_args::*;
 pub use self::maybe_trait_bound::*;
 pub use self::path_transform::*;
+pub use self::proxy::*;
 pub use self::present::*;
 pub use self::refine::*;
 pub use self::resolve::*;
+pub use self::unsupported::*;
 pub use self::visitor::*;
 
 pub trait CrateBased {
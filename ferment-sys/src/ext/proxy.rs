@@ -0,0 +1,132 @@
+use proc_macro2::Ident;
+use syn::{FnArg, ItemTrait, Path, ReturnType, Signature, TraitItem, TraitItemFn, Type, TypeImplTrait, TypeParamBound, TypeReference, TypeTraitObject};
+use syn::visit::Visit;
+use crate::ext::MaybeTraitBound;
+
+/// Marker bounds which a `*_Proxy` can satisfy by itself
+const MARKER_TRAITS: [&str; 4] = ["Send", "Sync", "Unpin", "Sized"];
+
+/// Markers the `*_Proxy` can't satisfy by itself: it holds raw foreign pointers
+const THREAD_SAFETY_TRAITS: [&str; 2] = ["Send", "Sync"];
+
+pub trait MarkerBound {
+    fn is_marker_bound(&self) -> bool;
+}
+
+impl MarkerBound for Path {
+    fn is_marker_bound(&self) -> bool {
+        self.segments.last()
+            .is_some_and(|segment| MARKER_TRAITS.iter().any(|marker| segment.ident == marker))
+    }
+}
+
+impl MarkerBound for TypeParamBound {
+    fn is_marker_bound(&self) -> bool {
+        self.maybe_trait_bound()
+            .is_none_or(|trait_bound| trait_bound.path.is_marker_bound())
+    }
+}
+
+/// Thread-safety markers which are required from the implementors of the trait
+/// wherever it's bounded together with them (`Arc<dyn Trait + Send + Sync>`, `impl Trait + Send`, `T: Trait + Sync`)
+pub fn thread_safety_markers<'a>(bounds: impl IntoIterator<Item = &'a Path>, trait_path: &Path) -> Vec<Ident> {
+    let bounds = Vec::from_iter(bounds);
+    if !bounds.contains(&trait_path) {
+        return vec![];
+    }
+    bounds.into_iter()
+        .filter_map(|path| path.segments.last())
+        .filter(|segment| THREAD_SAFETY_TRAITS.iter().any(|marker| segment.ident == marker))
+        .map(|segment| segment.ident.clone())
+        .collect()
+}
+
+/// Thread-safety markers required from the trait objects and anonymous generics of the trait within the type
+pub fn thread_safety_markers_in_type(ty: &Type, trait_path: &Path) -> Vec<Ident> {
+    struct MarkerVisitor<'a>(&'a Path, Vec<Ident>);
+    impl MarkerVisitor<'_> {
+        fn visit_bounds<'ast>(&mut self, bounds: impl Iterator<Item = &'ast TypeParamBound>) {
+            let paths = bounds.filter_map(MaybeTraitBound::maybe_trait_bound).map(|bound| &bound.path);
+            self.1.extend(thread_safety_markers(paths, self.0));
+        }
+    }
+    impl<'ast> Visit<'ast> for MarkerVisitor<'_> {
+        fn visit_type_impl_trait(&mut self, node: &'ast TypeImplTrait) {
+            self.visit_bounds(node.bounds.iter());
+            syn::visit::visit_type_impl_trait(self, node);
+        }
+        fn visit_type_trait_object(&mut self, node: &'ast TypeTraitObject) {
+            self.visit_bounds(node.bounds.iter());
+            syn::visit::visit_type_trait_object(self, node);
+        }
+    }
+    let mut visitor = MarkerVisitor(trait_path, vec![]);
+    visitor.visit_type(ty);
+    visitor.1
+}
+
+/// Whether the trait can be implemented on the foreign side: every method should go through the vtable
+/// and the signatures should not depend on the implementor
+pub trait Proxyable {
+    fn is_proxyable(&self) -> bool;
+}
+
+impl Proxyable for ItemTrait {
+    fn is_proxyable(&self) -> bool {
+        let ItemTrait { generics, supertraits, items, .. } = self;
+        generics.params.is_empty() &&
+            supertraits.iter().all(MarkerBound::is_marker_bound) &&
+            items.iter().all(|item| match item {
+                TraitItem::Fn(TraitItemFn { sig, .. }) => sig.is_proxyable(),
+                _ => false
+            })
+    }
+}
+
+impl Proxyable for Signature {
+    fn is_proxyable(&self) -> bool {
+        let Signature { generics, asyncness, inputs, output, .. } = self;
+        let mut typed = inputs.iter().filter_map(|arg| match arg {
+            FnArg::Typed(pat_type) => Some(&*pat_type.ty),
+            FnArg::Receiver(_) => None
+        });
+        let has_ref_receiver = matches!(inputs.first(), Some(FnArg::Receiver(receiver)) if receiver.reference.is_some());
+        let is_implementor_specific = typed.any(is_implementor_specific) ||
+            matches!(output, ReturnType::Type(_, ty) if is_implementor_specific(ty));
+        // The proxy owns the converted result, so it can't lend anything out of it
+        let is_borrowed_output = matches!(output, ReturnType::Type(_, ty) if is_borrowed(ty));
+        has_ref_receiver &&
+            asyncness.is_none() &&
+            generics.type_params().next().is_none() &&
+            !is_implementor_specific &&
+            !is_borrowed_output
+    }
+}
+
+/// `Self` and anonymous generics (`impl Trait`) can't be expressed in a vtable entry
+fn is_implementor_specific(ty: &Type) -> bool {
+    struct ImplementorVisitor(bool);
+    impl<'ast> Visit<'ast> for ImplementorVisitor {
+        fn visit_ident(&mut self, ident: &'ast Ident) {
+            self.0 |= ident == "Self";
+        }
+        fn visit_type_impl_trait(&mut self, _: &'ast TypeImplTrait) {
+            self.0 = true;
+        }
+    }
+    let mut visitor = ImplementorVisitor(false);
+    visitor.visit_type(ty);
+    visitor.0
+}
+
+fn is_borrowed(ty: &Type) -> bool {
+    struct BorrowVisitor(bool);
+    impl<'ast> Visit<'ast> for BorrowVisitor {
+        fn visit_type_reference(&mut self, _: &'ast TypeReference) {
+            self.0 = true;
+        }
+    }
+    let mut visitor = BorrowVisitor(false);
+    visitor.visit_type(ty);
+    visitor.0
+}
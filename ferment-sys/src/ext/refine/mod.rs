@@ -1,17 +1,21 @@
 mod lifetime;
+mod monomorphize;
 mod refine_in_scope;
 mod refine_mut;
 mod refine_with_nested_args;
 mod refine_with_nested_arg;
 mod reexport;
+mod replace_paths;
 mod refine_unrefined;
 mod unrefined;
 
 pub use lifetime::*;
+pub use monomorphize::*;
 pub use refine_in_scope::*;
 pub use refine_mut::*;
 pub use refine_with_nested_arg::*;
 pub use refine_with_nested_args::*;
 pub use reexport::*;
+pub use replace_paths::*;
 pub use refine_unrefined::*;
 pub use unrefined::*;
@@ -0,0 +1,76 @@
+use indexmap::IndexMap;
+use syn::{GenericParam, Generics, Item, ItemEnum, ItemStruct, Type};
+use syn::visit_mut::VisitMut;
+use crate::composable::TypeModeled;
+use crate::kind::{ObjectKind, TypeModelKind};
+
+/// Replaces the type parameters of the exported generic struct or enum with the types it's instantiated with
+/// (e.g. `Vec<T>` within `Page<T>` becomes `Vec<crate::identity::Identity>` within `Page<crate::identity::Identity>`)
+pub trait Monomorphize {
+    fn monomorphize(&mut self, params: &IndexMap<Type, ObjectKind>);
+}
+
+struct ParamReplacer<'a>(&'a IndexMap<Type, ObjectKind>);
+
+impl VisitMut for ParamReplacer<'_> {
+    fn visit_type_mut(&mut self, ty: &mut Type) {
+        match self.0.get(ty).and_then(ObjectKind::maybe_type) {
+            Some(replacement) => *ty = replacement,
+            None => syn::visit_mut::visit_type_mut(self, ty)
+        }
+    }
+}
+
+impl Monomorphize for Type {
+    fn monomorphize(&mut self, params: &IndexMap<Type, ObjectKind>) {
+        ParamReplacer(params).visit_type_mut(self);
+    }
+}
+
+impl Monomorphize for TypeModelKind {
+    fn monomorphize(&mut self, params: &IndexMap<Type, ObjectKind>) {
+        self.type_model_mut().ty.monomorphize(params);
+        self.nested_arguments_iter_mut()
+            .for_each(|nested_argument| nested_argument.object_mut().monomorphize(params));
+    }
+}
+
+impl Monomorphize for ObjectKind {
+    fn monomorphize(&mut self, params: &IndexMap<Type, ObjectKind>) {
+        if let Some(object) = self.maybe_type().and_then(|ty| params.get(&ty)) {
+            *self = object.clone();
+            return;
+        }
+        match self {
+            ObjectKind::Type(kind) |
+            ObjectKind::Item(kind, _) => kind.monomorphize(params),
+            ObjectKind::Empty => {}
+        }
+    }
+}
+
+impl Monomorphize for Item {
+    fn monomorphize(&mut self, params: &IndexMap<Type, ObjectKind>) {
+        let mut replacer = ParamReplacer(params);
+        match self {
+            Item::Struct(ItemStruct { generics, fields, .. }) => {
+                replacer.visit_fields_mut(fields);
+                retain_lifetimes(generics);
+            },
+            Item::Enum(ItemEnum { generics, variants, .. }) => {
+                variants.iter_mut()
+                    .for_each(|variant| replacer.visit_fields_mut(&mut variant.fields));
+                retain_lifetimes(generics);
+            },
+            _ => {}
+        }
+    }
+}
+
+fn retain_lifetimes(generics: &mut Generics) {
+    generics.params = generics.params.iter()
+        .filter(|param| matches!(param, GenericParam::Lifetime(..)))
+        .cloned()
+        .collect();
+    generics.where_clause = None;
+}
@@ -2,7 +2,7 @@ use quote::ToTokens;
 use syn::{Path, PathSegment, TraitBound, Type, TypeArray, TypeParamBound, TypePath, TypeSlice, TypeTraitObject};
 use crate::composable::{GenericBoundsModel, NestedArgument, TraitModel, TypeModel, TypeModeled};
 use crate::context::{GlobalContext, Scope, ScopeChain, ScopeInfo};
-use crate::ext::{AsType, CrateBased, DictionaryType, Join, LifetimeProcessor, Pop, ReexportSeek, RefineMut, ToPath};
+use crate::ext::{ArgsTransform, AsType, CrateBased, DictionaryType, Join, LifetimeProcessor, Pop, ReexportSeek, RefineMut, ToPath};
 use crate::kind::{DictFermentableModelKind, DictTypeModelKind, GroupModelKind, ObjectKind, ScopeItemKind, SmartPointerModelKind, TypeModelKind};
 
 pub trait RefineInScope {
@@ -92,7 +92,7 @@ impl RefineInScope for TypeModelKind {
 
                     let scope_path = model.lifetimes_cleaned().pointer_less();
                     if let Some(found_item) = source.maybe_scope_item_ref_obj_first(&crate_named_import_path)
-                        .or_else(|| determine_scope_item(&mut model, scope_path, scope, source)) {
+                        .or_else(|| scope_path.and_then(|scope_path| determine_scope_item(&mut model, scope_path, scope, source))) {
                         //println!("[INFO] (Import) Scope item found: {}", found_item);
                         refine_ty_with_import_path(model.ty_mut(), found_item.path());
                         if let Some(updated) = found_item.update_with(model) {
@@ -106,17 +106,22 @@ impl RefineInScope for TypeModelKind {
                 }
                 true
             }
-            TypeModelKind::Unknown(model) => {
-                let path = model.lifetimes_cleaned().pointer_less();
+            TypeModelKind::Unknown(model) => if let Some(path) = model.lifetimes_cleaned().pointer_less() {
                 if let Some(mut dictionary_type) = maybe_dict_type_model_kind(&path, model) {
                     //println!("[INFO] (Unknown) Dictionary item found: {}", dictionary_type);
                     refine_nested_arguments(dictionary_type.type_model_mut(), scope, source);
                     *self = TypeModelKind::Dictionary(dictionary_type);
                     true
-                } else if let Some(found_item) = source.maybe_scope_item_ref_obj_first(&path)
+                } else if let Some(import_path) = source.maybe_late_import_path_ref(&path) {
+                    //println!("[INFO] (Unknown) Late import found: {}", import_path.to_token_stream());
+                    *self = TypeModelKind::Imported(model.clone(), import_path.clone());
+                    self.refine_in_scope(scope, source)
+                } else if let Some(found_item) = source.maybe_scope_item_ref_obj_first(&path.arg_less())
+                    .or_else(|| maybe_generic_item(&path, scope, source))
                     .or_else(|| determine_scope_item(model, path, scope, source)) {
                     //println!("[INFO] (Unknown) Scope item found: {}", found_item);
                     refine_ty_with_import_path(model.ty_mut(), found_item.path());
+                    refine_nested_arguments(model, scope, source);
                     if let Some(updated) = found_item.update_with(model.clone()) {
                         //println!("[INFO] (Unknown) Scope item refined (Unknown): {}", updated);
                         *self = updated;
@@ -126,6 +131,8 @@ impl RefineInScope for TypeModelKind {
                     println!("[WARN] (Unknown) Unknown import: {}", model.as_type().to_token_stream());
                     false
                 }
+            } else {
+                false
             }
             TypeModelKind::Dictionary(
                 DictTypeModelKind::NonPrimitiveFermentable(
@@ -154,12 +161,14 @@ impl RefineInScope for TypeModelKind {
                     DictFermentableModelKind::Other(model) |
                     DictFermentableModelKind::I128(model) |
                     DictFermentableModelKind::U128(model) |
+                    DictFermentableModelKind::Char(model) |
                     DictFermentableModelKind::Str(model) |
                     DictFermentableModelKind::String(model)) |
                 DictTypeModelKind::NonPrimitiveOpaque(model) |
                 DictTypeModelKind::LambdaFn(model)) |
             TypeModelKind::FnPointer(model, ..) |
             TypeModelKind::Object(model) |
+            TypeModelKind::FieldlessEnum(model) |
             TypeModelKind::Optional(model) |
             TypeModelKind::TraitType(model) |
             TypeModelKind::Trait(TraitModel { ty: model, ..}) =>
@@ -251,6 +260,8 @@ fn maybe_dict_type_model_kind(crate_named_import_path: &Path, model: &mut TypeMo
             Some(DictTypeModelKind::NonPrimitiveFermentable(DictFermentableModelKind::I128(model.clone())))
         } else if ident.eq("u128") {
             Some(DictTypeModelKind::NonPrimitiveFermentable(DictFermentableModelKind::U128(model.clone())))
+        } else if ident.is_char() {
+            Some(DictTypeModelKind::NonPrimitiveFermentable(DictFermentableModelKind::Char(model.clone())))
         } else if ident.is_str() {
             refine_ty_with_import_path(model.ty_mut(), crate_named_import_path);
             Some(DictTypeModelKind::NonPrimitiveFermentable(DictFermentableModelKind::Str(model.clone())))
@@ -288,6 +299,13 @@ fn maybe_dict_type_model_kind(crate_named_import_path: &Path, model: &mut TypeMo
     })
 }
 
+/// Instantiation of the generic item (e.g. `Page<Version>`) is looked up without arguments in the module it's used in
+fn maybe_generic_item<'a>(path: &Path, scope: &ScopeChain, source: &'a GlobalContext) -> Option<&'a ScopeItemKind> {
+    path.segments.last()
+        .filter(|segment| !segment.arguments.is_none())
+        .and_then(|_| source.maybe_scope_item_ref_obj_first(&scope.mod_chain().self_path_ref().joined(&path.arg_less())))
+}
+
 fn determine_scope_item<'a>(new_ty_to_replace: &mut TypeModel, ty_path: Path, scope: &ScopeChain, source: &'a GlobalContext) -> Option<&'a ScopeItemKind> {
     // There are 2 cases:
     // 1. it's from non-fermented crate
@@ -1,10 +1,10 @@
-use std::collections::HashSet;
+use indexmap::IndexSet;
 use indexmap::IndexMap;
 use syn::{AngleBracketedGenericArguments, Attribute, GenericArgument, ParenthesizedGenericArguments, Path, PathArguments, ReturnType, TraitBound, Type, TypeImplTrait, TypePath, TypeTraitObject, TypeTuple};
 use crate::composable::{NestedArgument, TypeModel};
 use crate::composer::CommaPunctuatedNestedArguments;
 use crate::context::{GlobalContext, ScopeRefinement, ScopeResolver};
-use crate::ext::{GenericCollector, MaybeTraitBound, ResolveAttrs, ToPath, TypeCollector};
+use crate::ext::{GenericCollector, MaybeTraitBound, ReplacePaths, ResolveAttrs, ToPath, TypeCollector};
 use crate::formatter::format_mixin_kinds;
 use crate::kind::{MixinKind, TypeModelKind};
 use crate::print_phase;
@@ -109,23 +109,48 @@ impl RefineMut for ScopeResolver {
             );
     }
 }
+impl GlobalContext {
+    /// Registers the scopes of the concrete instantiations of the exported generic structs and enums
+    /// (the nested ones like `Page<Page<Identity>>` are found within the scopes of the outer ones)
+    fn refine_instances(&mut self) {
+        loop {
+            let instances = self.scope_register.inner.values()
+                .flat_map(|type_chain| type_chain.inner.values())
+                .filter_map(|object| self.maybe_generic_instance(object))
+                .filter(|(ty, ..)| !self.refined_instances.contains_key(ty))
+                .collect::<Vec<_>>();
+            if instances.is_empty() {
+                break;
+            }
+            instances.into_iter()
+                .for_each(|(ty, item, scope, type_chain)| {
+                    self.scope_register.type_chain_mut(&scope).add_many(type_chain.inner.into_iter());
+                    self.refined_instances.insert(ty, (item, scope));
+                });
+        }
+    }
+}
+
 impl RefineMut for GlobalContext {
     type Refinement = ScopeRefinement;
     fn refine_with(&mut self, refined: Self::Refinement) {
         self.scope_register.refine_with(refined);
-        let mut refined_mixins = IndexMap::<MixinKind, HashSet<Option<Attribute>>>::new();
+        self.scope_register.replace_paths(&self.public_paths);
+        self.refine_instances();
+        let mut refined_mixins = IndexMap::<MixinKind, IndexSet<Option<Attribute>>>::new();
         self.scope_register.inner.iter()
             .for_each(|(scope, type_chain)| {
                 let scope_level_attrs = scope.resolve_attrs();
                 type_chain.inner.iter().for_each(|(_conversion, object)| {
                     let object_attrs = object.resolve_attrs();
-                    let mut all_attrs: HashSet<Option<Attribute>> = HashSet::from_iter(object_attrs);
+                    let mut all_attrs: IndexSet<Option<Attribute>> = IndexSet::from_iter(object_attrs);
                     all_attrs.extend(scope_level_attrs.clone());
                     if all_attrs.is_empty() {
                         all_attrs.insert(None);
                     }
 
-                    if let Some(ty) = object.maybe_type() {
+                    // Generic structs and enums are fermented per instantiation
+                    if let Some(ty) = object.maybe_type().filter(|_| !scope.is_generic_object()) {
                         ty.find_generics()
                             .iter()
                             .filter(|ty| self.maybe_custom_type(ty).is_none() && !self.should_skip_from_expanding(object))
@@ -138,7 +163,7 @@ impl RefineMut for GlobalContext {
                     }
 
                     if let Some(TypeModelKind::Bounds(bounds)) = object.maybe_type_model_kind_ref() {
-                        let mut container = HashSet::<Type>::new();
+                        let mut container = IndexSet::<Type>::new();
                         bounds.collect_compositions()
                             .into_iter()
                             .for_each(|field_type| field_type.collect_to(&mut container));
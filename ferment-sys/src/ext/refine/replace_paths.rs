@@ -0,0 +1,93 @@
+use indexmap::IndexMap;
+use syn::{Path, Type};
+use syn::visit_mut::VisitMut;
+use crate::composable::TypeModeled;
+use crate::context::ScopeResolver;
+use crate::kind::{ObjectKind, ScopeItemKind, TypeModelKind};
+
+/// Renames the items which are named by another path in the fermented module
+/// (e.g. the items from the private modules named by the path they're re-exported with)
+pub trait ReplacePaths {
+    fn replace_paths(&mut self, paths: &IndexMap<Path, Path>);
+}
+
+struct PathReplacer<'a>(&'a IndexMap<Path, Path>);
+
+impl VisitMut for PathReplacer<'_> {
+    fn visit_path_mut(&mut self, path: &mut Path) {
+        let replacement = self.0.iter().find(|(from, _)| from.segments.len() <= path.segments.len() && from.segments.iter()
+            .zip(path.segments.iter())
+            .all(|(from, segment)| from.ident == segment.ident));
+        if let Some((from, to)) = replacement {
+            let mut segments = path.segments.iter().skip(from.segments.len() - 1);
+            let arguments = segments.next().map(|segment| segment.arguments.clone()).unwrap_or_default();
+            let mut replaced = to.clone();
+            if let Some(last) = replaced.segments.last_mut() {
+                last.arguments = arguments;
+            }
+            replaced.segments.extend(segments.cloned());
+            path.segments = replaced.segments;
+        }
+        syn::visit_mut::visit_path_mut(self, path);
+    }
+}
+
+impl ReplacePaths for Type {
+    fn replace_paths(&mut self, paths: &IndexMap<Path, Path>) {
+        PathReplacer(paths).visit_type_mut(self);
+    }
+}
+
+impl ReplacePaths for Path {
+    fn replace_paths(&mut self, paths: &IndexMap<Path, Path>) {
+        PathReplacer(paths).visit_path_mut(self);
+    }
+}
+
+impl ReplacePaths for TypeModelKind {
+    fn replace_paths(&mut self, paths: &IndexMap<Path, Path>) {
+        if let TypeModelKind::Imported(_, import_path) = self {
+            import_path.replace_paths(paths);
+        }
+        self.type_model_mut().ty.replace_paths(paths);
+        self.nested_arguments_iter_mut()
+            .for_each(|nested_argument| nested_argument.object_mut().replace_paths(paths));
+    }
+}
+
+impl ReplacePaths for ScopeItemKind {
+    fn replace_paths(&mut self, paths: &IndexMap<Path, Path>) {
+        match self {
+            ScopeItemKind::Item(_, path) |
+            ScopeItemKind::Fn(_, path) => path.replace_paths(paths)
+        }
+    }
+}
+
+impl ReplacePaths for ObjectKind {
+    fn replace_paths(&mut self, paths: &IndexMap<Path, Path>) {
+        match self {
+            ObjectKind::Type(kind) => kind.replace_paths(paths),
+            ObjectKind::Item(kind, _) => kind.replace_paths(paths),
+            ObjectKind::Empty => {}
+        }
+    }
+}
+
+impl ReplacePaths for ScopeResolver {
+    fn replace_paths(&mut self, paths: &IndexMap<Path, Path>) {
+        if paths.is_empty() {
+            return;
+        }
+        self.inner.values_mut()
+            .for_each(|chain| {
+                chain.inner = chain.inner.drain(..)
+                    .map(|(mut ty, mut object)| {
+                        ty.replace_paths(paths);
+                        object.replace_paths(paths);
+                        (ty, object)
+                    })
+                    .collect();
+            });
+    }
+}
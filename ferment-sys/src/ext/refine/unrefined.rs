@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use indexmap::IndexMap;
 use crate::context::{GlobalContext, ScopeRefinement};
 
 pub trait Unrefined: Sized {
@@ -15,7 +15,7 @@ impl Unrefined for GlobalContext {
                     .filter_map(|(holder, object)|
                         self.maybe_refined_object(scope, object)
                             .map(|object_to_refine| (holder.clone(), object_to_refine)))
-                    .collect::<HashMap<_, _>>();
+                    .collect::<IndexMap<_, _>>();
                 if !scope_types_to_refine.is_empty() {
                     scope_updates.push((scope.clone(), scope_types_to_refine));
                 }
@@ -23,4 +23,3 @@ impl Unrefined for GlobalContext {
         scope_updates
     }
 }
-
@@ -110,7 +110,7 @@ where SPEC: Specification {
             Type::Tuple(..) =>
                 Some(FFIFullPath::generic(self.mangle_ident_default().to_path())),
             Type::TraitObject(TypeTraitObject { bounds, .. }) => match bounds.len() {
-                0 => unimplemented!("TODO: FFIResolver::resolve::Type::TraitObject (Empty)"),
+                0 => None,
                 1 => bounds.first()
                     .and_then(MaybeTraitBound::maybe_trait_bound)
                     .and_then(|TraitBound { path, .. }| path.maybe_resolve(source)),
@@ -202,7 +202,7 @@ where SPEC: Specification {
             last_segment.maybe_angle_bracketed_args()
                 .and_then(MaybeGenericType::maybe_generic_type)
                 .and_then(|ty| ty.maybe_resolve(source))
-        } else if last_ident.is_smart_ptr() {
+        } else if last_ident.is_smart_ptr() || source.is_generic_instance(self) {
             Some(FFIFullPath::generic(self.mangle_ident_default().to_path()))
         } else {
             let chunk = if let Some(
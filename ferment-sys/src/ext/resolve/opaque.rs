@@ -69,7 +69,8 @@ impl FermentableDictionaryType for PathSegment {
             self.is_box() ||
             self.is_cow() ||
             self.is_lambda_fn() ||
-            self.is_128_digit()
+            self.is_128_digit() ||
+            self.is_char()
     }
 }
 impl FermentableDictionaryType for Path {
@@ -0,0 +1,166 @@
+use proc_macro2::{Span, TokenStream};
+use quote::ToTokens;
+use syn::{Expr, ExprBinary, ExprCast, ExprGroup, ExprParen, ExprPath, ExprUnary, FnArg, GenericArgument, ImplItem, Item, ItemConst, ItemEnum, ItemFn, ItemImpl, ItemStatic, ItemStruct, ItemTrait, ItemType, Path, PathArguments, ReturnType, Signature, TraitItem, Type, TypeImplTrait, TypeParamBound, TypeParen, TypePath, TypePtr, TypeReference, TypeTraitObject};
+use syn::spanned::Spanned;
+use syn::visit::Visit;
+use syn::visit_mut::VisitMut;
+use crate::ext::MaybeTraitBound;
+
+/// Constructs which can't be fermented: the exported item is rejected with the location of the offending type
+/// before it gets into the scope tree, so the composition doesn't run into them
+pub trait MaybeUnsupported {
+    fn maybe_unsupported(&self) -> Option<(Span, String)>;
+}
+
+impl MaybeUnsupported for Item {
+    fn maybe_unsupported(&self) -> Option<(Span, String)> {
+        let mut visitor = UnsupportedTypeVisitor(None);
+        match self {
+            Item::Fn(ItemFn { sig, .. }) =>
+                visitor.visit_signature_types(sig),
+            Item::Struct(ItemStruct { fields, .. }) =>
+                fields.iter().for_each(|field| visitor.visit_type(&field.ty)),
+            Item::Enum(ItemEnum { variants, .. }) => {
+                variants.iter()
+                    .flat_map(|variant| variant.fields.iter())
+                    .for_each(|field| visitor.visit_type(&field.ty));
+                variants.iter()
+                    .filter_map(|variant| variant.discriminant.as_ref())
+                    .for_each(|(_, expr)| visitor.visit_discriminant(expr));
+            },
+            Item::Type(ItemType { ty, .. }) |
+            Item::Const(ItemConst { ty, .. }) |
+            Item::Static(ItemStatic { ty, .. }) =>
+                visitor.visit_type(ty),
+            Item::Impl(ItemImpl { items, .. }) =>
+                items.iter().for_each(|impl_item| match impl_item {
+                    ImplItem::Fn(impl_item_fn) => visitor.visit_signature_types(&impl_item_fn.sig),
+                    ImplItem::Const(impl_item_const) => visitor.visit_type(&impl_item_const.ty),
+                    _ => {}
+                }),
+            Item::Trait(ItemTrait { items, .. }) =>
+                items.iter().for_each(|trait_item| if let TraitItem::Fn(trait_item_fn) = trait_item {
+                    visitor.visit_signature_types(&trait_item_fn.sig)
+                }),
+            _ => {}
+        }
+        visitor.0
+    }
+}
+
+/// Parenthesized types (e.g. `(u32)` or `Box<(dyn Fn(u32) + Send)>`) are unwrapped before the item gets
+/// into the scope tree, so the composition sees the plain types. The parentheses required by the syntax
+/// (trait objects with a few bounds behind a reference or a pointer) are kept, `TypeKind` looks through them
+pub trait Unparenthesized {
+    fn unparenthesized(self) -> Self;
+}
+
+impl Unparenthesized for Item {
+    fn unparenthesized(mut self) -> Self {
+        UnparenthesizeVisitor.visit_item_mut(&mut self);
+        self
+    }
+}
+
+struct UnparenthesizeVisitor;
+
+impl VisitMut for UnparenthesizeVisitor {
+    fn visit_type_mut(&mut self, ty: &mut Type) {
+        if let Type::Paren(TypeParen { elem, .. }) = ty {
+            *ty = std::mem::replace(&mut **elem, Type::Verbatim(TokenStream::new()));
+            return self.visit_type_mut(ty);
+        }
+        if let Type::Reference(TypeReference { elem, .. }) | Type::Ptr(TypePtr { elem, .. }) = ty {
+            if let Type::Paren(TypeParen { elem: bounded, .. }) = &mut **elem {
+                if has_few_bounds(bounded) {
+                    return syn::visit_mut::visit_type_mut(self, bounded);
+                }
+            }
+        }
+        syn::visit_mut::visit_type_mut(self, ty)
+    }
+}
+
+fn has_few_bounds(ty: &Type) -> bool {
+    match ty {
+        Type::TraitObject(TypeTraitObject { bounds, .. }) |
+        Type::ImplTrait(TypeImplTrait { bounds, .. }) => bounds.len() > 1,
+        _ => false
+    }
+}
+
+struct UnsupportedTypeVisitor(Option<(Span, String)>);
+
+impl UnsupportedTypeVisitor {
+    fn visit_signature_types(&mut self, sig: &Signature) {
+        sig.inputs.iter().for_each(|arg| if let FnArg::Typed(pat_type) = arg {
+            self.visit_type(&pat_type.ty)
+        });
+        if let ReturnType::Type(_, ty) = &sig.output {
+            self.visit_type(ty)
+        }
+    }
+    /// Discriminants are copied as is with the paths made full (see `EnumComposer`),
+    /// so only the constant expressions of literals and paths to the constants are supported
+    fn visit_discriminant(&mut self, expr: &Expr) {
+        if self.0.is_none() && !is_const_expr(expr) {
+            self.0 = Some((expr.span(), format!("`{}` isn't supported as a discriminant: use a literal or a path to a constant", expr.to_token_stream())));
+        }
+    }
+    fn reject(&mut self, ty: &Type, reason: &str) {
+        self.0 = Some((ty.span(), format!("`{}` {reason}", ty.to_token_stream())));
+    }
+}
+
+impl<'ast> Visit<'ast> for UnsupportedTypeVisitor {
+    fn visit_type(&mut self, ty: &'ast Type) {
+        if self.0.is_some() {
+            return;
+        }
+        match ty {
+            Type::Never(..) |
+            Type::Macro(..) |
+            Type::Infer(..) |
+            Type::Verbatim(..) =>
+                self.reject(ty, "can't be expressed in FFI"),
+            Type::ImplTrait(TypeImplTrait { bounds, .. }) if bounds.iter().any(is_callback_bound) =>
+                self.reject(ty, "isn't supported: use a type parameter bounded by the callback or a boxed trait object"),
+            Type::Path(TypePath { qself: None, path }) if is_borrowed_optional_callback(path) =>
+                self.reject(ty, "isn't supported: optional callbacks should be boxed (`Option<Box<dyn Fn(..)>>`)"),
+            _ =>
+                syn::visit::visit_type(self, ty)
+        }
+    }
+}
+
+fn is_const_expr(expr: &Expr) -> bool {
+    match expr {
+        Expr::Lit(..) |
+        Expr::Path(ExprPath { qself: None, .. }) => true,
+        Expr::Unary(ExprUnary { expr, .. }) |
+        Expr::Paren(ExprParen { expr, .. }) |
+        Expr::Group(ExprGroup { expr, .. }) |
+        Expr::Cast(ExprCast { expr, .. }) => is_const_expr(expr),
+        Expr::Binary(ExprBinary { left, right, .. }) => is_const_expr(left) && is_const_expr(right),
+        _ => false
+    }
+}
+
+fn is_callback_bound(bound: &TypeParamBound) -> bool {
+    bound.maybe_trait_bound()
+        .and_then(|trait_bound| trait_bound.path.segments.last())
+        .is_some_and(|segment| matches!(segment.arguments, PathArguments::Parenthesized(..)) &&
+            (segment.ident == "Fn" || segment.ident == "FnMut" || segment.ident == "FnOnce"))
+}
+
+fn is_borrowed_optional_callback(path: &Path) -> bool {
+    match path.segments.last() {
+        Some(segment) if segment.ident == "Option" => match &segment.arguments {
+            PathArguments::AngleBracketed(args) => args.args.iter().any(|arg| matches!(arg,
+                GenericArgument::Type(Type::Reference(TypeReference { elem, .. }))
+                    if matches!(&**elem, Type::TraitObject(TypeTraitObject { bounds, .. }) if bounds.iter().any(is_callback_bound)))),
+            _ => false
+        },
+        _ => false
+    }
+}
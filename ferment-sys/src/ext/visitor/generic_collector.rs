@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use indexmap::IndexSet;
 use quote::ToTokens;
 use syn::{AngleBracketedGenericArguments, Item, ParenthesizedGenericArguments, Path, PathArguments, Signature, TraitBound, Type, TypeArray, TypeImplTrait, TypeParamBound, TypePath, TypeReference, TypeSlice, TypeTraitObject, TypeTuple};
 use crate::ast::AddPunctuated;
@@ -8,18 +8,18 @@ use crate::kind::ScopeItemKind;
 use crate::ext::visitor::TypeCollector;
 
 pub trait GenericCollector where Self: TypeCollector + ToTokens {
-    fn find_generics(&self) -> HashSet<Type> {
+    fn find_generics(&self) -> IndexSet<Type> {
         let compositions = self.collect_compositions();
         // collect all types with generics and ensure their uniqueness
         // since we don't want to implement interface multiple times for same object
-        let mut generics = HashSet::<Type>::new();
+        let mut generics = IndexSet::<Type>::new();
         compositions
             .iter()
             .for_each(|field_type| field_type.collect_to(&mut generics));
         generics
     }
 
-    fn collect_to(&self, generics: &mut HashSet<Type>) {
+    fn collect_to(&self, generics: &mut IndexSet<Type>) {
         generics.extend(self.find_generics());
     }
 }
@@ -28,7 +28,7 @@ impl GenericCollector for Item {}
 impl GenericCollector for Signature {}
 
 impl GenericCollector for Type {
-    fn collect_to(&self, generics: &mut HashSet<Type>) {
+    fn collect_to(&self, generics: &mut IndexSet<Type>) {
         match self {
             Type::Path(TypePath { path, .. }) => {
                 path.collect_to(generics);
@@ -62,7 +62,7 @@ impl GenericCollector for Type {
     }
 }
 impl GenericCollector for Path {
-    fn collect_to(&self, generics: &mut HashSet<Type>) {
+    fn collect_to(&self, generics: &mut IndexSet<Type>) {
         self.segments
             .iter()
             .flat_map(|segment| segment.maybe_angle_bracketed_args()
@@ -73,13 +73,13 @@ impl GenericCollector for Path {
 }
 
 impl GenericCollector for AddPunctuated<TypeParamBound> {
-    fn collect_to(&self, generics: &mut HashSet<Type>) {
+    fn collect_to(&self, generics: &mut IndexSet<Type>) {
         self.iter().for_each(|bound| bound.collect_to(generics))
     }
 }
 
 impl GenericCollector for TypeParamBound {
-    fn collect_to(&self, generics: &mut HashSet<Type>) {
+    fn collect_to(&self, generics: &mut IndexSet<Type>) {
         if let Some(trait_bound) = self.maybe_trait_bound() {
             trait_bound.collect_to(generics)
         }
@@ -87,7 +87,7 @@ impl GenericCollector for TypeParamBound {
 }
 
 impl GenericCollector for TraitBound {
-    fn collect_to(&self, generics: &mut HashSet<Type>) {
+    fn collect_to(&self, generics: &mut IndexSet<Type>) {
         self.path.collect_to(generics)
     }
 }
\ No newline at end of file
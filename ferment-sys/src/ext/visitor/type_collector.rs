@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use indexmap::IndexSet;
 use syn::{Attribute, Fields, FieldsNamed, FieldsUnnamed, FnArg, ImplItem, ImplItemConst, ImplItemFn, ImplItemType, Item, ItemMod, ItemType, Meta, parse_quote, Path, PatType, ReturnType, Signature, TraitItem, TraitItemConst, TraitItemFn, TraitItemType, Type, Variant, TypeParamBound, TraitBound};
 use syn::parse::Parser;
 use crate::ast::{AddPunctuated, CommaPunctuated};
@@ -14,7 +14,7 @@ pub struct MacroAttributes {
 }
 
 pub trait TypeCollector {
-    fn collect_compositions(&self) -> HashSet<Type>;
+    fn collect_compositions(&self) -> IndexSet<Type>;
 }
 fn handle_attributes_with_handler<F: FnMut(MacroAttributes)>(attrs: &[Attribute], mut handler: F) {
     attrs.iter()
@@ -34,13 +34,13 @@ fn handle_attributes_with_handler<F: FnMut(MacroAttributes)>(attrs: &[Attribute]
 }
 
 impl TypeCollector for AddPunctuated<TypeParamBound> {
-    fn collect_compositions(&self) -> HashSet<Type> {
-        HashSet::from_iter(self.iter().flat_map(TypeParamBound::collect_compositions))
+    fn collect_compositions(&self) -> IndexSet<Type> {
+        IndexSet::from_iter(self.iter().flat_map(TypeParamBound::collect_compositions))
     }
 }
 
 impl TypeCollector for TypeParamBound {
-    fn collect_compositions(&self) -> HashSet<Type> {
+    fn collect_compositions(&self) -> IndexSet<Type> {
         self.maybe_trait_bound()
             .map(TypeCollector::collect_compositions)
             .unwrap_or_default()
@@ -48,20 +48,20 @@ impl TypeCollector for TypeParamBound {
 }
 
 impl TypeCollector for TraitBound {
-    fn collect_compositions(&self) -> HashSet<Type> {
+    fn collect_compositions(&self) -> IndexSet<Type> {
         self.path.collect_compositions()
     }
 }
 
 impl TypeCollector for GenericBoundsModel {
-    fn collect_compositions(&self) -> HashSet<Type> {
+    fn collect_compositions(&self) -> IndexSet<Type> {
         self.chain.values().flat_map(|bounds| bounds.iter().map(|bound| parse_quote!(dyn #bound))).collect()
     }
 }
 
 impl TypeCollector for Item {
-    fn collect_compositions(&self) -> HashSet<Type> {
-        let mut type_and_paths = HashSet::<Type>::new();
+    fn collect_compositions(&self) -> IndexSet<Type> {
+        let mut type_and_paths = IndexSet::<Type>::new();
         let mut cache_fields = |fields: &Fields, _attrs: &MacroAttributes| match fields {
             Fields::Unnamed(FieldsUnnamed { unnamed: fields, .. }) |
             Fields::Named(FieldsNamed { named: fields, .. }) =>
@@ -133,8 +133,8 @@ impl TypeCollector for Item {
 
 
 impl TypeCollector for Signature {
-    fn collect_compositions(&self) -> HashSet<Type> {
-        let mut type_and_paths = HashSet::<Type>::new();
+    fn collect_compositions(&self) -> IndexSet<Type> {
+        let mut type_and_paths = IndexSet::<Type>::new();
         self.inputs.iter().for_each(|arg| if let FnArg::Typed(PatType { ty, .. }) = arg {
             type_and_paths.insert(*ty.clone());
         });
@@ -146,19 +146,19 @@ impl TypeCollector for Signature {
 }
 
 impl TypeCollector for Type {
-    fn collect_compositions(&self) -> HashSet<Type> {
-        HashSet::from_iter(self.unique_nested_items())
+    fn collect_compositions(&self) -> IndexSet<Type> {
+        IndexSet::from_iter(self.unique_nested_items())
     }
 }
 
 impl TypeCollector for Path {
-    fn collect_compositions(&self) -> HashSet<Type> {
-        HashSet::from_iter(self.unique_nested_items())
+    fn collect_compositions(&self) -> IndexSet<Type> {
+        IndexSet::from_iter(self.unique_nested_items())
     }
 }
 
 impl TypeCollector for ScopeItemKind {
-    fn collect_compositions(&self) -> HashSet<Type> {
+    fn collect_compositions(&self) -> IndexSet<Type> {
         match self {
             ScopeItemKind::Item(item, ..) => item.collect_compositions(),
             ScopeItemKind::Fn(sig, ..) => sig.collect_compositions(),
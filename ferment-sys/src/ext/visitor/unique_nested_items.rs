@@ -1,5 +1,5 @@
-use std::collections::HashSet;
 use std::hash::Hash;
+use indexmap::IndexSet;
 use quote::ToTokens;
 use syn::{AngleBracketedGenericArguments, BareFnArg, Constraint, Expr, GenericArgument, ParenthesizedGenericArguments, Path, PathArguments, PathSegment, QSelf, ReturnType, TraitBound, Type, TypeArray, TypeBareFn, TypeGroup, TypeImplTrait, TypeParamBound, TypeParen, TypePath, TypePtr, TypeReference, TypeSlice, TypeTraitObject, TypeTuple};
 use syn::punctuated::Punctuated;
@@ -7,7 +7,7 @@ use crate::ext::{MaybeTraitBound, ToType};
 
 pub trait UniqueNestedItems {
     type Item: ToTokens + Eq + Hash;
-    fn unique_nested_items(&self) -> HashSet<Self::Item>;
+    fn unique_nested_items(&self) -> IndexSet<Self::Item>;
 }
 
 impl<A, T, P> UniqueNestedItems for Punctuated<T, P>
@@ -15,49 +15,49 @@ impl<A, T, P> UniqueNestedItems for Punctuated<T, P>
           T: UniqueNestedItems<Item = A> {
     type Item = A;
 
-    fn unique_nested_items(&self) -> HashSet<Self::Item> {
-        HashSet::from_iter(self.iter().flat_map(T::unique_nested_items))
+    fn unique_nested_items(&self) -> IndexSet<Self::Item> {
+        IndexSet::from_iter(self.iter().flat_map(T::unique_nested_items))
     }
 }
 impl UniqueNestedItems for AngleBracketedGenericArguments {
     type Item = Type;
-    fn unique_nested_items(&self) -> HashSet<Self::Item> {
+    fn unique_nested_items(&self) -> IndexSet<Self::Item> {
         self.args.unique_nested_items()
     }
 }
 impl UniqueNestedItems for BareFnArg {
     type Item = Type;
-    fn unique_nested_items(&self) -> HashSet<Self::Item> {
+    fn unique_nested_items(&self) -> IndexSet<Self::Item> {
         self.ty.unique_nested_items()
     }
 }
 impl UniqueNestedItems for Constraint {
     type Item = Type;
-    fn unique_nested_items(&self) -> HashSet<Self::Item> {
+    fn unique_nested_items(&self) -> IndexSet<Self::Item> {
         self.bounds.unique_nested_items()
     }
 }
 impl UniqueNestedItems for Expr {
     type Item = Type;
-    fn unique_nested_items(&self) -> HashSet<Self::Item> {
+    fn unique_nested_items(&self) -> IndexSet<Self::Item> {
         // TODO: Implement this if need
-        HashSet::new()
+        IndexSet::new()
     }
 }
 impl UniqueNestedItems for GenericArgument {
     type Item = Type;
-    fn unique_nested_items(&self) -> HashSet<Self::Item> {
+    fn unique_nested_items(&self) -> IndexSet<Self::Item> {
         match self {
             GenericArgument::Type(ty) => ty.unique_nested_items(),
             GenericArgument::Constraint(constraint) => constraint.unique_nested_items(),
             GenericArgument::Const(expr) => expr.unique_nested_items(),
-            _ => HashSet::new(),
+            _ => IndexSet::new(),
         }
     }
 }
 impl UniqueNestedItems for ParenthesizedGenericArguments {
     type Item = Type;
-    fn unique_nested_items(&self) -> HashSet<Self::Item> {
+    fn unique_nested_items(&self) -> IndexSet<Self::Item> {
         let mut involved = self.inputs.unique_nested_items();
         involved.extend(self.output.unique_nested_items());
         involved
@@ -65,45 +65,45 @@ impl UniqueNestedItems for ParenthesizedGenericArguments {
 }
 impl UniqueNestedItems for Path {
     type Item = Type;
-    fn unique_nested_items(&self) -> HashSet<Self::Item> {
+    fn unique_nested_items(&self) -> IndexSet<Self::Item> {
         self.segments.unique_nested_items()
     }
 }
 impl UniqueNestedItems for PathArguments {
     type Item = Type;
-    fn unique_nested_items(&self) -> HashSet<Self::Item> {
+    fn unique_nested_items(&self) -> IndexSet<Self::Item> {
         match self {
             PathArguments::AngleBracketed(args) => args.unique_nested_items(),
             PathArguments::Parenthesized(args) => args.unique_nested_items(),
-            PathArguments::None => HashSet::new()
+            PathArguments::None => IndexSet::new()
         }
     }
 }
 impl UniqueNestedItems for PathSegment {
     type Item = Type;
-    fn unique_nested_items(&self) -> HashSet<Self::Item> {
+    fn unique_nested_items(&self) -> IndexSet<Self::Item> {
         self.arguments.unique_nested_items()
     }
 }
 impl UniqueNestedItems for QSelf {
     type Item = Type;
-    fn unique_nested_items(&self) -> HashSet<Self::Item> {
+    fn unique_nested_items(&self) -> IndexSet<Self::Item> {
         self.ty.unique_nested_items()
     }
 }
 impl UniqueNestedItems for ReturnType {
     type Item = Type;
-    fn unique_nested_items(&self) -> HashSet<Self::Item> {
+    fn unique_nested_items(&self) -> IndexSet<Self::Item> {
         match self {
             ReturnType::Type(_, ty) => ty.unique_nested_items(),
-            ReturnType::Default => HashSet::new()
+            ReturnType::Default => IndexSet::new()
         }
     }
 }
 impl UniqueNestedItems for Type {
     type Item = Type;
-    fn unique_nested_items(&self) -> HashSet<Self::Item> {
-        let mut involved = HashSet::from([]);
+    fn unique_nested_items(&self) -> IndexSet<Self::Item> {
+        let mut involved = IndexSet::from([]);
         match self {
             Type::Array(TypeArray { elem, .. }) |
             Type::Slice(TypeSlice { elem, .. }) |
@@ -141,9 +141,9 @@ impl UniqueNestedItems for Type {
 }
 impl UniqueNestedItems for TypeParamBound {
     type Item = Type;
-    fn unique_nested_items(&self) -> HashSet<Self::Item> {
+    fn unique_nested_items(&self) -> IndexSet<Self::Item> {
         self.maybe_trait_bound().map(|TraitBound { path, .. }| {
-            let mut involved = HashSet::from([]);
+            let mut involved = IndexSet::from([]);
             involved.insert(path.to_type());
             involved.extend(path.unique_nested_items());
             involved
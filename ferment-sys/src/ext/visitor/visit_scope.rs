@@ -1,14 +1,14 @@
-use std::collections::{HashMap, HashSet};
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use proc_macro2::Ident;
 use quote::ToTokens;
-use syn::{Attribute, ConstParam, Field, FnArg, GenericParam, Generics, ImplItem, ImplItemConst, ImplItemFn, ImplItemType, Item, ItemFn, ItemImpl, ItemMod, ItemTrait, LifetimeParam, Meta, parse_quote, Path, PatType, PredicateType, ReturnType, Signature, TraitBound, TraitItem, TraitItemConst, TraitItemFn, TraitItemType, Type, TypeParam, TypeParamBound, Variant, WhereClause, WherePredicate, TypePath, PathSegment, TraitBoundModifier, ItemEnum, ItemStruct, ItemType, QSelf};
+use syn::{Attribute, ConstParam, Field, FnArg, GenericParam, Generics, ImplItem, ImplItemConst, ImplItemFn, ImplItemType, Item, ItemConst, ItemFn, ItemImpl, ItemMod, ItemStatic, ItemTrait, LifetimeParam, Meta, parse_quote, Path, PatType, PredicateType, ReturnType, Signature, TraitBound, TraitItem, TraitItemConst, TraitItemFn, TraitItemType, Type, TypeParam, TypeParamBound, Variant, WhereClause, WherePredicate, TypePath, PathSegment, TraitBoundModifier, ItemEnum, ItemStruct, ItemType, QSelf};
 use syn::parse::Parser;
+use syn::visit::Visit;
 use crate::ast::{AddPunctuated, CommaPunctuated, CommaPunctuatedTokens};
 use crate::composable::{NestedArgument, TraitDecompositionPart1, TraitModel, TypeModel};
 use crate::composer::{CommaPunctuatedNestedArguments, MaybeMacroLabeled};
 use crate::context::{GenericChain, ScopeChain};
-use crate::kind::{MacroKind, ObjectKind, ScopeItemKind, TypeModelKind};
+use crate::kind::{FormatterKind, MacroKind, ObjectKind, ScopeItemKind, TypeModelKind};
 use crate::ext::{Join, MaybeTraitBound, ToType, GenericBoundKey};
 use crate::ext::maybe_ident::collect_bounds;
 use crate::tree::Visitor;
@@ -31,7 +31,8 @@ impl VisitScope for Item {
                 self.add_to_scope(&scope, visitor);
                 Some(scope)
             },
-            |
+            Item::Const(..) |
+            Item::Static(..) |
             Item::Mod(..) => {
                 self.add_to_scope(scope, visitor);
                 Some(scope.clone())
@@ -44,9 +45,9 @@ impl VisitScope for Item {
         match self {
             Item::Mod(item_mod) =>
                 add_inner_module_conversion(visitor, item_mod, scope),
-            Item::Const(_) => {
-                // TODO: Const scope processing
-            }
+            Item::Const(ItemConst { ty, .. }) |
+            Item::Static(ItemStatic { ty, .. }) =>
+                visitor.add_full_qualified_type_match(scope, ty, true),
             Item::Enum(item_enum) => {
                 let ItemEnum { attrs, generics, ident, variants, .. } = item_enum;
                 let (nested_arguments, inner_args) = add_full_qualified_generics(visitor, generics, scope, true);
@@ -55,10 +56,9 @@ impl VisitScope for Item {
                 } else {
                     scope.to_type()
                 };
-                let self_object = ObjectKind::new_generic_obj_item(
-                    full_ty,
-                    generics,
-                    nested_arguments,
+                let self_object = ObjectKind::model_item(
+                    TypeModelKind::enum_model_kind(item_enum),
+                    TypeModel::new_generic(full_ty, generics.clone(), nested_arguments),
                     ScopeItemKind::item_enum(item_enum, self_scope));
                 if let Some(parent_scope) = scope.parent_scope() {
                     add_itself_conversion(visitor, parent_scope, ident, self_object.clone());
@@ -70,7 +70,9 @@ impl VisitScope for Item {
 
                 variants.iter().for_each(|Variant { fields, .. }|
                     fields.iter().for_each(|Field { ty, .. }|
-                        visitor.add_full_qualified_type_match(scope, ty, true)));
+                        visitor.add_full_qualified_type_match(scope, ty, !mentions_type_params(ty, generics))));
+                extract_instantiations(attrs).iter().for_each(|ty|
+                    visitor.add_full_qualified_type_match(scope, ty, true));
 
             }
             Item::Struct(item_struct) => {
@@ -95,7 +97,9 @@ impl VisitScope for Item {
                 visitor.add_generic_chain(scope, generic_chain);
 
                 fields.iter().for_each(|Field { ty, .. }|
-                    visitor.add_full_qualified_type_match(scope, ty,true));
+                    visitor.add_full_qualified_type_match(scope, ty, !mentions_type_params(ty, generics)));
+                extract_instantiations(attrs).iter().for_each(|ty|
+                    visitor.add_full_qualified_type_match(scope, ty, true));
             }
             Item::Fn(ItemFn { sig, .. }) => {
                 let Signature { ident, generics, .. } = sig;
@@ -392,7 +396,7 @@ fn add_inner_module_conversion(visitor: &mut Visitor, item_mod: &ItemMod, scope:
     if let Some((_, items)) = &item_mod.content {
         items.iter().for_each(|item| match item {
             Item::Use(node) =>
-                visitor.fold_import_tree(scope, &node.tree, vec![]),
+                visitor.fold_item_use(scope, node),
             Item::Mod(..) =>
                 item.add_to_scope(&scope.joined(item), visitor),
             Item::Trait(..) |
@@ -482,7 +486,7 @@ pub fn create_generics_chain(generics: &Generics) -> GenericChain {
     });
     // Dedup per-type trait paths by token string and order deterministically
     for trait_paths in generics_chain.values_mut() {
-        let mut seen_p: HashSet<String> = HashSet::new();
+        let mut seen_p: IndexSet<String> = IndexSet::new();
         trait_paths.retain(|p| seen_p.insert(p.to_token_stream().to_string()));
         trait_paths.sort_by(|a, b| {
             let a_s = a.to_token_stream().to_string();
@@ -494,7 +498,7 @@ pub fn create_generics_chain(generics: &Generics) -> GenericChain {
         });
     }
     // If a bounded type has any restrictive trait bounds, drop its unlimited entries
-    let mut has_restrictive: HashMap<String, bool> = HashMap::new();
+    let mut has_restrictive: IndexMap<String, bool> = IndexMap::new();
     for (bounded_ty, trait_paths) in &generics_chain {
         let ty_s = bounded_ty.to_token_stream().to_string();
         let e = has_restrictive.entry(ty_s).or_insert(false);
@@ -579,11 +583,25 @@ pub fn create_generics_chain_exact(generics: &Generics, key: &GenericBoundKey) -
     GenericChain::new(full)
 }
 
+/// Field types like `Vec<T>` are kept within the scope of the generic item only:
+/// they're monomorphized there per instantiation and can't be expanded as they are
+fn mentions_type_params(ty: &Type, generics: &Generics) -> bool {
+    struct TypeParamVisitor<'a>(&'a Generics, bool);
+    impl<'ast> Visit<'ast> for TypeParamVisitor<'_> {
+        fn visit_ident(&mut self, ident: &'ast Ident) {
+            self.1 |= self.0.type_params().any(|TypeParam { ident: param, .. }| param == ident);
+        }
+    }
+    let mut visitor = TypeParamVisitor(generics, false);
+    visitor.visit_type(ty);
+    visitor.1
+}
+
 fn add_itself_conversion(visitor: &mut Visitor, scope: &ScopeChain, ident: &Ident, object: ObjectKind) {
     visitor.scope_add_one(ident.to_type(), object, scope);
 }
 
-pub fn extract_trait_names(attrs: &[Attribute]) -> Vec<Path> {
+fn export_arguments(attrs: &[Attribute]) -> Vec<Path> {
     let mut paths = Vec::<Path>::new();
     attrs.iter().for_each(|attr| {
         if attr.is_labeled_for_export() {
@@ -601,3 +619,51 @@ pub fn extract_trait_names(attrs: &[Attribute]) -> Vec<Path> {
     });
     paths
 }
+
+/// Instantiations of the generic struct or enum listed in `#[ferment_macro::export(instantiate(Page<Identity>, Page<Document>))]`
+pub fn extract_instantiations(attrs: &[Attribute]) -> Vec<Type> {
+    let mut types = Vec::<Type>::new();
+    attrs.iter().for_each(|attr| {
+        if attr.is_labeled_for_export() {
+            if let Meta::List(meta_list) = &attr.meta {
+                if let Ok(nested) = CommaPunctuated::<Meta>::parse_terminated.parse2(meta_list.tokens.clone()) {
+                    for meta_item in nested.iter() {
+                        if let Meta::List(meta_list) = meta_item {
+                            if meta_list.path.is_ident("instantiate") {
+                                if let Ok(instances) = CommaPunctuated::<Type>::parse_terminated.parse2(meta_list.tokens.clone()) {
+                                    types.extend(instances);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    });
+    types
+}
+
+/// Traits listed in `#[ferment_macro::export(...)]` except the formatting ones (see `extract_formatters`)
+/// and the `handle` option (see `is_handle_export`)
+pub fn extract_trait_names(attrs: &[Attribute]) -> Vec<Path> {
+    export_arguments(attrs)
+        .into_iter()
+        .filter(|path| FormatterKind::from_trait_path(path).is_none() && !path.is_ident("handle"))
+        .collect()
+}
+
+/// Structs labeled with `#[ferment_macro::export(handle)]` are passed as the boxed Rust values
+pub fn is_handle_export(attrs: &[Attribute]) -> bool {
+    export_arguments(attrs)
+        .iter()
+        .any(|path| path.is_ident("handle"))
+}
+
+/// Formatting traits listed in `#[ferment_macro::export(Display, Debug)]` or derived with `#[derive(Debug)]`
+pub fn extract_formatters(attrs: &[Attribute]) -> Vec<FormatterKind> {
+    export_arguments(attrs)
+        .iter()
+        .filter_map(FormatterKind::from_trait_path)
+        .chain(FormatterKind::from_derive(attrs))
+        .collect()
+}
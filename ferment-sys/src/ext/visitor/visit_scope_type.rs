@@ -7,7 +7,7 @@ use crate::composable::{GenericBoundsModel, NestedArgument, TypeModel, TypeModel
 use crate::composer::CommaPunctuatedNestedArguments;
 use crate::context::{GenericChain, GlobalContext, ScopeChain};
 use crate::kind::{DictFermentableModelKind, GroupModelKind, ObjectKind, SmartPointerModelKind, TypeModelKind};
-use crate::ext::{Accessory, AsType, CrateBased, CrateExtension, DictionaryType, GenericBoundKey, Join, PathTransform, Pop, PunctuateOne, ToPath, ToType};
+use crate::ext::{Accessory, AsType, CrateExtension, DictionaryType, GenericBoundKey, Join, PathTransform, Pop, PunctuateOne, ToPath, ToType};
 
 pub trait VisitScopeType<'a> where Self: Sized + 'a {
     type Source;
@@ -201,7 +201,8 @@ impl<'a> VisitScopeType<'a> for Path {
 
     fn visit_scope_type(&self, source: &Self::Source) -> Self::Result {
         let (scope, context, qself) = source;
-        let (mut segments, nested_arguments) = self.segments.visit_scope_type(&(scope, context));
+        let path = scope.normalize_path(self);
+        let (mut segments, nested_arguments) = path.segments.visit_scope_type(&(scope, context));
         match (segments.first(), segments.last()) {
             (Some(PathSegment { ident, .. }), Some(PathSegment { ident: last_ident, arguments: last_arguments })) => {
                 let generic_key = GenericBoundKey::ident(ident);
@@ -215,7 +216,7 @@ impl<'a> VisitScopeType<'a> for Path {
                     // Can be reevaluated after processing entire scope tree:
                     // Because import path can have multiple aliases and we need the most complete one to use mangling correctly
                     // We can also determine the type after processing entire scope (if one in fermented crate)
-                    ObjectKind::imported_model_type(handle_type_path_model(qself, None, segments, nested_arguments), import_path.crate_named(&scope.crate_ident_as_path()))
+                    ObjectKind::imported_model_type(handle_type_path_model(qself, None, segments, nested_arguments), scope.normalize_path(import_path))
                 } else if let Some(generic_bounds) = context.generics.maybe_generic_bounds(scope, &ident.to_type()) {
                     // TODO: multiple bounds handling
                     if let Some(first_bound) = generic_bounds.first() {
@@ -233,14 +234,14 @@ impl<'a> VisitScopeType<'a> for Path {
                             segments.replace_last_with(&new_segments);
                         }
                     }
-                    ObjectKind::unknown_model_type_path(qself, self.leading_colon, segments, nested_arguments)
+                    ObjectKind::unknown_model_type_path(qself, path.leading_colon, segments, nested_arguments)
                 } else {
                     let obj_scope = scope.obj_root_chain().unwrap_or(scope);
                     let len = segments.len();
                     match ident.to_string().as_str() {
                         "Self" => if len <= 1 {
                             segments.replace_last_with(&obj_scope.self_path_ref().segments);
-                            ObjectKind::unknown_model_type_path(qself, self.leading_colon, segments, nested_arguments)
+                            ObjectKind::unknown_model_type_path(qself, path.leading_colon, segments, nested_arguments)
                         } else {
                             let tail = segments.crate_less();
                             let last_segment = segments.pop().unwrap();
@@ -250,36 +251,38 @@ impl<'a> VisitScopeType<'a> for Path {
                             }
                             segments.clear();
                             segments.extend(new_segments);
-                            scope.obj_root_model_composer()(handle_type_path_model(qself, self.leading_colon, segments, nested_arguments))
+                            scope.obj_root_model_composer()(handle_type_path_model(qself, path.leading_colon, segments, nested_arguments))
                         },
                         "Vec" =>
-                            ObjectKind::group_type(GroupModelKind::Vec(handle_type_path_model(qself, self.leading_colon, segments, nested_arguments))),
+                            ObjectKind::group_type(GroupModelKind::Vec(handle_type_path_model(qself, path.leading_colon, segments, nested_arguments))),
                         "Result" if len == 1 =>
-                            ObjectKind::group_type(GroupModelKind::Result(handle_type_path_model(qself, self.leading_colon, segments, nested_arguments))),
+                            ObjectKind::group_type(GroupModelKind::Result(handle_type_path_model(qself, path.leading_colon, segments, nested_arguments))),
                         "Option" =>
-                            ObjectKind::optional_model_type(handle_type_path_model(qself, self.leading_colon, segments, nested_arguments)),
+                            ObjectKind::optional_model_type(handle_type_path_model(qself, path.leading_colon, segments, nested_arguments)),
                         _ if last_ident.to_string().eq("Map") && ident.to_string().eq("serde_json") =>
-                            ObjectKind::object_model_type(handle_type_path_model(qself, self.leading_colon, segments, nested_arguments)),
+                            ObjectKind::object_model_type(handle_type_path_model(qself, path.leading_colon, segments, nested_arguments)),
                         _ if last_ident.is_map() =>
-                            ObjectKind::group_type(GroupModelKind::Map(handle_type_path_model(qself, self.leading_colon, segments, nested_arguments))),
+                            ObjectKind::group_type(GroupModelKind::Map(handle_type_path_model(qself, path.leading_colon, segments, nested_arguments))),
                         _ if last_ident.is_btree_set() =>
-                            ObjectKind::group_type(GroupModelKind::BTreeSet(handle_type_path_model(qself, self.leading_colon, segments, nested_arguments))),
+                            ObjectKind::group_type(GroupModelKind::BTreeSet(handle_type_path_model(qself, path.leading_colon, segments, nested_arguments))),
                         _ if last_ident.is_hash_set() =>
-                            ObjectKind::group_type(GroupModelKind::HashSet(handle_type_path_model(qself, self.leading_colon, segments, nested_arguments))),
+                            ObjectKind::group_type(GroupModelKind::HashSet(handle_type_path_model(qself, path.leading_colon, segments, nested_arguments))),
                         _ if last_ident.eq("IndexMap") =>
-                            ObjectKind::group_type(GroupModelKind::IndexMap(handle_type_path_model(qself, self.leading_colon, segments, nested_arguments))),
+                            ObjectKind::group_type(GroupModelKind::IndexMap(handle_type_path_model(qself, path.leading_colon, segments, nested_arguments))),
                         _ if last_ident.eq("IndexSet") =>
-                            ObjectKind::group_type(GroupModelKind::IndexSet(handle_type_path_model(qself, self.leading_colon, segments, nested_arguments))),
+                            ObjectKind::group_type(GroupModelKind::IndexSet(handle_type_path_model(qself, path.leading_colon, segments, nested_arguments))),
                         _ if ident.is_box() =>
-                            ObjectKind::smart_ptr_type(SmartPointerModelKind::Box(handle_type_path_model(qself, self.leading_colon, segments, nested_arguments))),
+                            ObjectKind::smart_ptr_type(SmartPointerModelKind::Box(handle_type_path_model(qself, path.leading_colon, segments, nested_arguments))),
                         _ if ident.is_cow() =>
-                            ObjectKind::non_primitive_fermentable_type(DictFermentableModelKind::Cow(handle_type_path_model(qself, self.leading_colon, segments, nested_arguments))),
+                            ObjectKind::non_primitive_fermentable_type(DictFermentableModelKind::Cow(handle_type_path_model(qself, path.leading_colon, segments, nested_arguments))),
                         _ if ident.is_primitive() =>
                             ObjectKind::primitive_type(ident),
                         _ if ident.eq("i128") =>
                             ObjectKind::i128_type(ident),
                         _ if ident.eq("u128") =>
                             ObjectKind::u128_type(ident),
+                        _ if ident.is_char() =>
+                            ObjectKind::char_type(ident),
                         _ if ident.is_special_std_trait() =>
                             ObjectKind::unknown_type(last_import_seg.to_type()),
                         _ if ident.is_str() =>
@@ -287,15 +290,15 @@ impl<'a> VisitScopeType<'a> for Path {
                         _ if ident.is_string() =>
                             ObjectKind::string_type(last_import_seg.to_type()),
                         _ if ident.is_lambda_fn() =>
-                            ObjectKind::lambda_fn_model_type(handle_type_path_model(qself, self.leading_colon, segments, nested_arguments)),
+                            ObjectKind::lambda_fn_model_type(handle_type_path_model(qself, path.leading_colon, segments, nested_arguments)),
                         _ => if len == 1 {
                             segments.replace_last_with(&match obj_scope.parent_scope() {
                                 // Global
-                                None if scope.is_crate_root() => scope.crate_ident_as_path().segments.joined(self),
-                                None => scope.self_path_ref().segments.joined(self),
-                                Some(parent) => parent.self_path_ref().segments.joined(self),
+                                None if scope.is_crate_root() => scope.crate_ident_as_path().segments.joined(&path),
+                                None => scope.self_path_ref().segments.joined(&path),
+                                Some(parent) => parent.self_path_ref().segments.joined(&path),
                             });
-                            ObjectKind::unknown_model_type_path(qself, self.leading_colon, segments, nested_arguments)
+                            ObjectKind::unknown_model_type_path(qself, path.leading_colon, segments, nested_arguments)
                         } else if let Some(QSelf { .. }) = qself {
                             // For qualified paths like `<Self::Item as Trait>::Assoc`, ensure the trait path
                             // is fully resolved and replace the first segment accordingly, then rebuild via handler.
@@ -306,9 +309,9 @@ impl<'a> VisitScopeType<'a> for Path {
                                 .cloned()
                                 .unwrap_or_else(|| obj_parent_scope.unwrap_or(scope).self_path_ref().joined(&generic_key));
                             segments.replace_first_with(&tt.segments);
-                            scope.obj_root_model_composer()(handle_type_path_model(qself, self.leading_colon, segments, nested_arguments))
+                            scope.obj_root_model_composer()(handle_type_path_model(qself, path.leading_colon, segments, nested_arguments))
                         } else {
-                            ObjectKind::unknown_model_type_path(qself, self.leading_colon, segments, nested_arguments)
+                            ObjectKind::unknown_model_type_path(qself, path.leading_colon, segments, nested_arguments)
                         }
                     }
                 }
@@ -1,6 +1,5 @@
-use std::collections::{HashMap, HashSet};
 use std::fmt::{Display, Formatter, Write};
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use proc_macro2::{Spacing, TokenTree};
 use quote::{quote, ToTokens};
 use syn::{Attribute, Ident, ItemUse, Path, Signature, Type};
@@ -10,7 +9,7 @@ use crate::kind::{MixinKind, ObjectKind};
 use crate::tree::{ScopeTreeID, ScopeTreeExportItem, ScopeTreeItem};
 
 #[allow(unused)]
-pub fn format_imported_set(dict: &HashSet<ItemUse>) -> String {
+pub fn format_imported_set(dict: &IndexSet<ItemUse>) -> String {
     let debug_imports = dict.iter().map(|i| {
         i.to_token_stream()
     }).collect::<Vec<_>>();
@@ -31,7 +30,7 @@ pub fn format_scope_refinement(dict: &[(ScopeChain, IndexMap<Type, ObjectKind>)]
 }
 
 #[allow(unused)]
-pub fn format_types(dict: &HashSet<Type>) -> String {
+pub fn format_types(dict: &IndexSet<Type>) -> String {
     dict.iter()
         // .map(|item| format_token_stream(item))
         .map(|item| item.to_token_stream().to_string())
@@ -40,14 +39,14 @@ pub fn format_types(dict: &HashSet<Type>) -> String {
 }
 
 #[allow(unused)]
-pub fn format_mixin_kinds(dict: &IndexMap<MixinKind, HashSet<Option<Attribute>>>) -> String {
+pub fn format_mixin_kinds(dict: &IndexMap<MixinKind, IndexSet<Option<Attribute>>>) -> String {
     dict.iter()
         .map(|(item, attrs)| format!("{}:\t {}", item, format_unique_attrs(attrs)))
         .collect::<Vec<_>>()
         .join("\n\t")
 }
 #[allow(unused)]
-pub fn format_mixin_conversions(dict: &IndexMap<GenericBoundsModel, HashSet<Option<Attribute>>>) -> String {
+pub fn format_mixin_conversions(dict: &IndexMap<GenericBoundsModel, IndexSet<Option<Attribute>>>) -> String {
     dict.iter()
         .map(|(item, attrs)| format!("{}:\n\t {}", item, format_unique_attrs(attrs)))
         .collect::<Vec<_>>()
@@ -55,7 +54,7 @@ pub fn format_mixin_conversions(dict: &IndexMap<GenericBoundsModel, HashSet<Opti
 }
 
 #[allow(unused)]
-pub fn format_unique_attrs(dict: &HashSet<Option<Attribute>>) -> String {
+pub fn format_unique_attrs(dict: &IndexSet<Option<Attribute>>) -> String {
     dict.iter()
         .map(|item| item.as_ref().map_or("[None]".to_string(), |a| a.to_token_stream().to_string()))
         .collect::<Vec<_>>()
@@ -148,7 +147,7 @@ pub fn type_vec_obj_conversion_pair(pair: (&Type, &Vec<ObjectKind>)) -> String {
     format!("\t{}: [{}]", format_token_stream(pair.0), format_obj_vec(pair.1))
 }
 #[allow(unused)]
-pub fn format_predicates_dict(vec: &HashMap<Type, Vec<Path>>) -> String {
+pub fn format_predicates_dict(vec: &IndexMap<Type, Vec<Path>>) -> String {
     vec.iter()
         .map(type_vec_path_conversion_pair)
         .collect::<Vec<_>>()
@@ -193,7 +192,7 @@ pub fn format_ident_types_dict(dict: &IndexMap<Ident, Type>) -> String {
 }
 
 #[allow(unused)]
-pub fn format_scope_types_dict(dict: &HashMap<ScopeChain, TypeChain>) -> String {
+pub fn format_scope_types_dict(dict: &IndexMap<ScopeChain, TypeChain>) -> String {
     dict.iter().map(|(scope, tc)| {
         format!("{}: \n\t{}", scope.fmt_short(), format_types_dict(&tc.inner))
     }).collect::<Vec<_>>()
@@ -405,7 +404,7 @@ fn scope_traits_dict(dict: &IndexMap<ScopeChain, IndexMap<Ident, TraitModelPart1
 
 
 
-fn traits_impl_dict(dict: &HashMap<ScopeChain, Vec<Path>>) -> Vec<String> {
+fn traits_impl_dict(dict: &IndexMap<ScopeChain, Vec<Path>>) -> Vec<String> {
     let mut iter = dict.iter()
         .filter_map(|(key, value)| {
             let scopes = quote!(#(#value),*);
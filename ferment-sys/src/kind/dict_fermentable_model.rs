@@ -16,6 +16,7 @@ pub enum DictFermentableModelKind {
     Cow(TypeModel),
     I128(TypeModel),
     U128(TypeModel),
+    Char(TypeModel),
 }
 impl ToType for DictFermentableModelKind {
     fn to_type(&self) -> Type {
@@ -32,7 +33,8 @@ impl<'a> AsType<'a> for DictFermentableModelKind {
             DictFermentableModelKind::Other(model) |
             DictFermentableModelKind::Cow(model) |
             DictFermentableModelKind::I128(model) |
-            DictFermentableModelKind::U128(model) => model.as_type(),
+            DictFermentableModelKind::U128(model) |
+            DictFermentableModelKind::Char(model) => model.as_type(),
         }
     }
 }
@@ -46,6 +48,7 @@ impl TypeModeled for DictFermentableModelKind {
             DictFermentableModelKind::String(model) |
             DictFermentableModelKind::I128(model) |
             DictFermentableModelKind::U128(model) |
+            DictFermentableModelKind::Char(model) |
             DictFermentableModelKind::Cow(model) |
             DictFermentableModelKind::Other(model) => model
         }
@@ -58,6 +61,7 @@ impl TypeModeled for DictFermentableModelKind {
             DictFermentableModelKind::String(model) |
             DictFermentableModelKind::I128(model) |
             DictFermentableModelKind::U128(model) |
+            DictFermentableModelKind::Char(model) |
             DictFermentableModelKind::Cow(model) |
             DictFermentableModelKind::Other(model) => model
         }
@@ -83,6 +87,8 @@ impl Debug for DictFermentableModelKind {
                 format!("Digit128({})", model),
             DictFermentableModelKind::U128(model) =>
                 format!("Digit128({})", model),
+            DictFermentableModelKind::Char(model) =>
+                format!("Char({})", model),
         }))
     }
 }
@@ -0,0 +1,47 @@
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use quote::{format_ident, quote, ToTokens};
+use syn::{Attribute, Meta, Path};
+use syn::parse::Parser;
+use crate::ast::CommaPunctuated;
+
+/// Formatting traits exposed with the `<Type>_to_string` and `<Type>_debug_string` functions
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum FormatterKind {
+    /// `Display` or `ToString`
+    Display,
+    Debug,
+}
+
+impl FormatterKind {
+    pub fn from_trait_path(path: &Path) -> Option<Self> {
+        match path.segments.last()?.ident.to_string().as_str() {
+            "Display" | "ToString" => Some(Self::Display),
+            "Debug" => Some(Self::Debug),
+            _ => None
+        }
+    }
+    /// `#[derive(Debug)]`
+    pub fn from_derive(attrs: &[Attribute]) -> Vec<Self> {
+        attrs.iter()
+            .filter_map(|attr| match &attr.meta {
+                Meta::List(meta_list) if meta_list.path.is_ident("derive") =>
+                    CommaPunctuated::<Path>::parse_terminated.parse2(meta_list.tokens.clone()).ok(),
+                _ => None
+            })
+            .flatten()
+            .filter_map(|path| Self::from_trait_path(&path).filter(|kind| Self::Debug.eq(kind)))
+            .collect()
+    }
+    pub fn fn_ident(&self) -> Ident {
+        match self {
+            Self::Display => format_ident!("to_string"),
+            Self::Debug => format_ident!("debug_string"),
+        }
+    }
+    pub fn format<T: ToTokens>(&self, obj: T) -> TokenStream2 {
+        match self {
+            Self::Display => quote!(ToString::to_string(#obj)),
+            Self::Debug => quote!(format!("{:?}", #obj)),
+        }
+    }
+}
@@ -89,7 +89,7 @@ impl GenericTypeKind {
                 None
             },
             GenericTypeKind::SmartPointer(ptr) => Some(ptr.as_type()),
-            conversion => panic!("TODO: Non-supported generic kind: {}", conversion),
+            GenericTypeKind::Optional(_) => None,
         }
     }
 }
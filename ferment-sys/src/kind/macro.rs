@@ -1,5 +1,5 @@
-use std::collections::HashSet;
 use std::fmt::{Display, Formatter};
+use indexmap::IndexSet;
 use quote::ToTokens;
 use syn::{Attribute, AttrStyle, Item, Lit, Meta, MetaList, parse_quote, Expr, ExprLit, MacroDelimiter, Type};
 use syn::parse::Parser;
@@ -156,7 +156,7 @@ impl CfgMacroType {
     }
 }
 fn merge_cfg_conditions(conditions: Vec<CfgMacroType>) -> Vec<CfgMacroType> {
-    let mut features = HashSet::new();
+    let mut features = IndexSet::new();
     let mut tests = false;
     let mut not_conditions = vec![];
     let mut any_conditions = vec![];
@@ -198,7 +198,7 @@ fn merge_cfg_conditions(conditions: Vec<CfgMacroType>) -> Vec<CfgMacroType> {
         vec![CfgMacroType::Any(any_conditions)]
     }
 }
-pub fn expand_attributes(attrs: &HashSet<Option<Attribute>>) -> Vec<Attribute> {
+pub fn expand_attributes(attrs: &IndexSet<Option<Attribute>>) -> Vec<Attribute> {
     let merged = merge_attributes(attrs);
     if merged.is_empty() {
         return vec![];
@@ -214,7 +214,7 @@ pub fn expand_attributes(attrs: &HashSet<Option<Attribute>>) -> Vec<Attribute> {
         }),
     }]
 }
-pub fn merge_attributes(attrs: &HashSet<Option<Attribute>>) -> CommaPunctuated<Meta> {
+pub fn merge_attributes(attrs: &IndexSet<Option<Attribute>>) -> CommaPunctuated<Meta> {
     if attrs.contains(&None) {
         Punctuated::new()
     } else {
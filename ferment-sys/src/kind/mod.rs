@@ -1,6 +1,7 @@
 mod callback;
 mod dict_type_model;
 mod dict_fermentable_model;
+mod formatter;
 mod generic_type;
 mod group_model;
 mod r#macro;
@@ -13,11 +14,13 @@ mod type_model;
 mod r#type;
 mod field_type;
 mod special;
+mod trait_proxy;
 
 pub use self::callback::*;
 pub use self::dict_type_model::*;
 pub use self::dict_fermentable_model::*;
 pub use self::field_type::*;
+pub use self::formatter::*;
 pub use self::generic_type::*;
 pub use self::group_model::*;
 pub use self::r#macro::*;
@@ -27,5 +30,6 @@ pub use self::scope_item::*;
 pub use self::smart_pointer::*;
 pub use self::smart_pointer_model::*;
 pub use self::special::*;
+pub use self::trait_proxy::*;
 pub use self::r#type::*;
 pub use self::type_model::*;
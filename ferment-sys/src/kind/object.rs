@@ -2,7 +2,7 @@ use std::fmt::{Debug, Display, Formatter};
 use proc_macro2::Ident;
 use quote::ToTokens;
 use syn::__private::TokenStream2;
-use syn::{Attribute, Generics, Item, ItemConst, ItemEnum, ItemFn, ItemImpl, ItemMod, ItemStruct, ItemTrait, ItemType, ParenthesizedGenericArguments, Path, PathSegment, QSelf, Signature, Type};
+use syn::{Attribute, Generics, Item, ItemConst, ItemEnum, ItemFn, ItemImpl, ItemMod, ItemStatic, ItemStruct, ItemTrait, ItemType, ParenthesizedGenericArguments, Path, PathSegment, QSelf, Signature, Type};
 use syn::token::PathSep;
 use crate::ast::{Colon2Punctuated, CommaPunctuated};
 use crate::composable::{NestedArgument, TraitDecompositionPart1, TraitModel, TypeModel, TypeModeled};
@@ -100,6 +100,9 @@ impl ObjectKind {
     pub fn u128_type(ty: &Ident) -> Self {
         Self::non_primitive_fermentable_type(DictFermentableModelKind::U128(TypeModel::new_default(ty.to_type())))
     }
+    pub fn char_type(ty: &Ident) -> Self {
+        Self::non_primitive_fermentable_type(DictFermentableModelKind::Char(TypeModel::new_default(ty.to_type())))
+    }
 
     pub fn new_item(ty: TypeModelKind, item: ScopeItemKind) -> Self {
         Self::Item(ty, item)
@@ -254,6 +257,10 @@ impl ObjectKind {
             ObjectKind::Empty => None
         }
     }
+    pub fn is_fieldless_enum(&self) -> bool {
+        self.maybe_type_model_kind_ref()
+            .is_some_and(TypeModelKind::is_fieldless_enum)
+    }
     pub fn maybe_type_model_kind(&self) -> Option<TypeModelKind> {
         self.maybe_type_model_kind_ref().cloned()
     }
@@ -278,6 +285,15 @@ impl ObjectKind {
         self.maybe_type_model_kind_ref()
             .map(TypeModelKind::to_type)
     }
+    /// Whether the type doesn't depend on the generic parameters
+    pub fn is_concrete(&self) -> bool {
+        match self.maybe_type_model_kind_ref() {
+            Some(TypeModelKind::Bounds(..) | TypeModelKind::TraitType(..)) | None => false,
+            Some(kind) => kind.nested_arguments_ref()
+                .iter()
+                .all(|nested_argument| nested_argument.object().is_concrete())
+        }
+    }
 }
 
 impl TryFrom<(&Item, &Path)> for ObjectKind {
@@ -288,11 +304,14 @@ impl TryFrom<(&Item, &Path)> for ObjectKind {
         match value {
             Item::Trait(ItemTrait { ident, generics, items, supertraits, .. }) =>
                 Ok(ObjectKind::new_trait_item(TraitModel::new(TypeModel::new_generic_ident_non_nested(ident, generics), TraitDecompositionPart1::from_trait_items(ident, items), collect_bounds(supertraits)), item_kind)),
+            Item::Enum(item_enum @ ItemEnum { ident, generics, .. }) =>
+                Ok(ObjectKind::model_item(TypeModelKind::enum_model_kind(item_enum), TypeModel::new_generic_non_nested(ident.to_type(), generics), item_kind)),
             Item::Const(ItemConst { ident, generics, .. }) |
             Item::Struct(ItemStruct { ident, generics, .. }) |
-            Item::Enum(ItemEnum { ident, generics, .. }) |
             Item::Fn(ItemFn { sig: Signature { ident, generics, .. }, .. }) =>
                 Ok(ObjectKind::new_generic_non_nested_obj_item(ident.to_type(), generics, item_kind)),
+            Item::Static(ItemStatic { ident, .. }) =>
+                Ok(ObjectKind::new_generic_non_nested_obj_item(ident.to_type(), &Generics::default(), item_kind)),
             Item::Type(ItemType { ident, generics, ty, .. }) =>
                 Ok(match &**ty {
                     Type::BareFn(..) =>
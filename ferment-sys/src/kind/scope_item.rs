@@ -79,7 +79,8 @@ impl ScopeItemKind {
             ScopeItemKind::Item(item, ..) => match item {
                 Item::Trait(ItemTrait { ident, items, supertraits, .. }) =>
                     Some(TypeModelKind::Trait(TraitModel::new(ty_to_replace, TraitDecompositionPart1::from_trait_items(ident, items), collect_bounds(supertraits)))),
-                Item::Enum(..) |
+                Item::Enum(item_enum) =>
+                    Some(TypeModelKind::enum_model_kind(item_enum)(ty_to_replace)),
                 Item::Struct(..) |
                 Item::Fn(..) |
                 Item::Impl(..) =>
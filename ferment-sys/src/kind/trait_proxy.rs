@@ -0,0 +1,68 @@
+use syn::{Path, Type};
+use crate::context::ScopeContext;
+use crate::ext::{Mangle, Resolve, ToPath, ToType};
+use crate::lang::{NameComposable, Specification};
+use crate::presentable::{Expression, ScopeContextPresentable};
+use crate::presentation::{DictionaryExpr, FFIFullPath};
+
+/// How an argument standing for an implementor of an exported trait receives the foreign `*_Proxy`
+#[derive(Clone, Debug)]
+pub enum TraitProxyKind {
+    /// `impl Trait` or a type parameter bounded by `Trait`: the proxy is moved into the call
+    Value(Path),
+    /// `&dyn Trait` or `&impl Trait`: the proxy is borrowed
+    Ref(Path),
+    /// `&mut dyn Trait` or `&mut impl Trait`: the proxy is borrowed mutably
+    MutRef(Path),
+    /// `Box<dyn Trait>`
+    Box(Path),
+    /// `Arc<dyn Trait>`
+    Arc(Path),
+    /// `Rc<dyn Trait>`
+    Rc(Path),
+}
+
+impl TraitProxyKind {
+    pub fn trait_path(&self) -> &Path {
+        match self {
+            Self::Value(path) |
+            Self::Ref(path) |
+            Self::MutRef(path) |
+            Self::Box(path) |
+            Self::Arc(path) |
+            Self::Rc(path) => path
+        }
+    }
+
+    /// `*_Proxy` lives next to the FFI trait object
+    pub fn proxy_type<SPEC>(&self, source: &ScopeContext) -> Type
+        where SPEC: Specification,
+              FFIFullPath<SPEC>: ToType {
+        let mut path = Resolve::<FFIFullPath<SPEC>>::resolve(&self.trait_path().to_type(), source)
+            .to_type()
+            .to_path();
+        if let Some(segment) = path.segments.last_mut() {
+            segment.ident = SPEC::Name::trait_proxy(segment.ident.clone()).mangle_ident_default();
+        }
+        path.to_type()
+    }
+
+    pub fn expr_from<SPEC>(&self, field_path: SPEC::Expr) -> SPEC::Expr
+        where SPEC: Specification<Expr=Expression<SPEC>>,
+              SPEC::Expr: ScopeContextPresentable {
+        match self {
+            Self::Value(..) =>
+                Expression::deref_expr(Expression::from_raw_box(field_path)),
+            Self::Ref(..) =>
+                Expression::deref_ref(field_path),
+            Self::MutRef(..) =>
+                Expression::deref_mut_ref(field_path),
+            Self::Box(..) =>
+                Expression::from_raw_box(field_path),
+            Self::Arc(..) =>
+                Expression::new_smth(Expression::deref_expr(Expression::from_raw_box(field_path)), DictionaryExpr::Arc),
+            Self::Rc(..) =>
+                Expression::new_smth(Expression::deref_expr(Expression::from_raw_box(field_path)), DictionaryExpr::Rc),
+        }
+    }
+}
@@ -2,7 +2,7 @@ use std::fmt;
 use std::fmt::{Debug, Formatter};
 use proc_macro2::TokenStream;
 use quote::ToTokens;
-use syn::{GenericArgument, Path, PathArguments, PathSegment, Type, TypeImplTrait, TypePath, TypeReference, TypeTraitObject};
+use syn::{GenericArgument, Path, PathArguments, PathSegment, Type, TypeImplTrait, TypeParen, TypePath, TypeReference, TypeTraitObject};
 use syn::parse::{Parse, ParseStream};
 use crate::kind::{CallbackKind, GenericTypeKind, SmartPointerKind};
 use crate::ext::{GenericNestedArg, MaybeAngleBracketedArgs, Primitive};
@@ -98,7 +98,7 @@ impl From<Type> for TypeKind {
                     },
                     _ => match last_ident.to_string().as_str() {
                         // std convertible
-                        "i8" | "u8" | "i16" | "u16" | "i32" | "u32" | "i64" | "u64" | "f64"
+                        "i8" | "u8" | "i16" | "u16" | "i32" | "u32" | "i64" | "u64" | "f32" | "f64"
                         | "isize" | "usize" | "bool" => TypeKind::Primitive(ty),
                         "Box" => TypeKind::Generic(GenericTypeKind::Box(ty)),
                         "Cell" => TypeKind::Generic(GenericTypeKind::SmartPointer(SmartPointerKind::Cell(ty))),
@@ -140,7 +140,8 @@ impl From<Type> for TypeKind {
                 TypeKind::Generic(GenericTypeKind::Slice(ty.clone())),
             Type::BareFn(..) =>
                 TypeKind::Generic(GenericTypeKind::Callback(CallbackKind::FnPointer(ty.clone()))),
-            Type::Reference(TypeReference { elem, .. }) =>
+            Type::Reference(TypeReference { elem, .. }) |
+            Type::Paren(TypeParen { elem, .. }) =>
                 TypeKind::from(*elem),
             Type::ImplTrait(TypeImplTrait { bounds, .. }) |
             Type::TraitObject(TypeTraitObject { bounds, .. }) =>
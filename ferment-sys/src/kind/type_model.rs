@@ -1,6 +1,6 @@
 use std::fmt::{Debug, Display, Formatter};
 use std::hash::{Hash, Hasher};
-use syn::{parse_quote, ParenthesizedGenericArguments, Path, PathArguments, PathSegment, Type, TypePath, TypePtr, TypeReference};
+use syn::{parse_quote, Fields, ItemEnum, ParenthesizedGenericArguments, Path, PathArguments, PathSegment, Type, TypePath, TypePtr, TypeReference, Variant};
 use quote::ToTokens;
 use proc_macro2::TokenStream as TokenStream2;
 use crate::ast::CommaPunctuated;
@@ -12,6 +12,11 @@ use crate::kind::dict_type_model::DictTypeModelKind;
 use crate::ext::{AsType, DictionaryType, MaybeLambdaArgs, Pop, ResolveTrait, ToType};
 use crate::lang::{NameComposable, Specification};
 
+/// Non-generic enums with unit variants only are passed by value
+pub fn is_fieldless_enum(item_enum: &ItemEnum) -> bool {
+    let ItemEnum { generics, variants, .. } = item_enum;
+    generics.params.is_empty() && !variants.is_empty() && variants.iter().all(|Variant { fields, .. }| matches!(fields, Fields::Unit))
+}
 
 #[derive(Clone)]
 pub enum TypeModelKind {
@@ -19,6 +24,8 @@ pub enum TypeModelKind {
     Trait(TraitModel),
     TraitType(TypeModel),
     Object(TypeModel),
+    /// Enum with unit variants only: passed by value as a plain C enum
+    FieldlessEnum(TypeModel),
     Optional(TypeModel),
     FnPointer(TypeModel),
     Bounds(GenericBoundsModel),
@@ -38,6 +45,7 @@ impl TypeModeled for TypeModelKind {
         match self {
             TypeModelKind::TraitType(model) |
             TypeModelKind::Object(model, ..) |
+            TypeModelKind::FieldlessEnum(model) |
             TypeModelKind::Optional(model, ..) |
             TypeModelKind::FnPointer(model) |
             TypeModelKind::Array(model) |
@@ -56,6 +64,7 @@ impl TypeModeled for TypeModelKind {
         match self {
             TypeModelKind::TraitType(model) |
             TypeModelKind::Object(model, ..) |
+            TypeModelKind::FieldlessEnum(model) |
             TypeModelKind::Optional(model, ..) |
             TypeModelKind::FnPointer(model) |
             TypeModelKind::Unknown(model, ..) |
@@ -100,6 +109,13 @@ impl TypeModelKind {
     pub fn unknown_type_ref(ty: &Type) -> Self {
         Self::Unknown(TypeModel::from(ty))
     }
+    pub fn enum_model_kind(item_enum: &ItemEnum) -> fn(TypeModel) -> Self {
+        if is_fieldless_enum(item_enum) {
+            Self::FieldlessEnum
+        } else {
+            Self::Object
+        }
+    }
 
 
     pub fn is_unknown(&self) -> bool {
@@ -120,6 +136,9 @@ impl TypeModelKind {
     pub fn is_optional(&self) -> bool {
         matches!(self, Self::Optional(..))
     }
+    pub fn is_fieldless_enum(&self) -> bool {
+        matches!(self, Self::FieldlessEnum(..))
+    }
 
     pub(crate) fn maybe_trait_object_maybe_model_kind_or_same(&self, source: &ScopeContext) -> TypeModelKind {
         match self {
@@ -181,6 +200,7 @@ impl<'a> AsType<'a> for TypeModelKind {
             TypeModelKind::Dictionary(kind) => kind.as_type(),
             TypeModelKind::TraitType(model) |
             TypeModelKind::Object(model) |
+            TypeModelKind::FieldlessEnum(model) |
             TypeModelKind::Optional(model) |
             TypeModelKind::FnPointer(model) |
             TypeModelKind::Fn(model) |
@@ -225,6 +245,8 @@ impl Debug for TypeModelKind {
                 format!("Trait({model})"),
             TypeModelKind::Object(ty) =>
                 format!("Object({ty})"),
+            TypeModelKind::FieldlessEnum(ty) =>
+                format!("FieldlessEnum({ty})"),
             TypeModelKind::Optional(ty) =>
                 format!("Optional({ty})"),
             TypeModelKind::Unknown(ty) =>
@@ -45,6 +45,7 @@ pub trait NameComposable<SPEC>
     fn ident(ident: Ident) -> Self;
     fn index(ident: usize) -> Self;
     fn unnamed_arg(index: usize) -> Self;
+    fn trait_proxy(ident: Ident) -> Self;
 }
 
 pub trait LangFermentable: Clone + Debug {
@@ -0,0 +1,8 @@
+use crate::composer::{ConstComposer, SourceFermentable};
+use crate::lang::objc::{ObjCFermentate, ObjCSpecification};
+
+impl SourceFermentable<ObjCFermentate> for ConstComposer<ObjCSpecification> {
+    fn ferment(&self) -> ObjCFermentate {
+        ObjCFermentate::Empty
+    }
+}
@@ -15,7 +15,8 @@ impl SourceComposable for GenericComposer<ObjCSpecification> {
                       ffi_aspect: _,
                       attrs: _,
                       interfaces: implementations,
-                      bindings: _
+                      bindings: _,
+                      cloneable: _
                   }| {
                 // println!("OBJC GEN1");
                 // let fields = CommaPunctuated::from_iter(field_composers.iter().map(field_composer));
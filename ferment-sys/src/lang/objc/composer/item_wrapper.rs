@@ -13,6 +13,7 @@ impl ItemComposerWrapper<ObjCSpecification> {
             ItemComposerWrapper::OpaqueStructUnnamed(composer) => composer.borrow().composer.borrow().ferment(),
             ItemComposerWrapper::OpaqueStructNamed(composer) => composer.borrow().composer.borrow().ferment(),
             ItemComposerWrapper::Sig(composer) => composer.borrow().ferment(),
+            ItemComposerWrapper::Const(composer) => composer.borrow().ferment(),
             ItemComposerWrapper::TypeAlias(composer) => composer.borrow().composer.borrow().ferment(),
             ItemComposerWrapper::Trait(composer) => composer.borrow().ferment(),
             ItemComposerWrapper::Impl(composer) => composer.borrow().ferment(),
@@ -12,6 +12,7 @@ mod map;
 mod signature;
 mod r#trait;
 mod r#impl;
+mod r#const;
 mod enum_composer;
 mod item_wrapper;
 mod maybe;
@@ -63,6 +63,8 @@ impl SourceComposable for TargetVarComposer<ObjCSpecification> {
                             FFIVariable::mut_ptr(parse_quote!(uint8_t (*)[16])),
                         TypeModelKind::Dictionary(DictTypeModelKind::NonPrimitiveFermentable(DictFermentableModelKind::U128(..))) =>
                             FFIVariable::mut_ptr(parse_quote!(uint8_t (*)[16])),
+                        TypeModelKind::Dictionary(DictTypeModelKind::NonPrimitiveFermentable(DictFermentableModelKind::Char(..))) =>
+                            FFIVariable::direct(parse_quote!(uint32_t)),
                         TypeModelKind::FnPointer(TypeModel { ty, .. }, ..) =>
                             FFIVariable::direct(Resolve::<SpecialType<ObjCSpecification>>::maybe_resolve(&ty, source)
                                 .map(ToTokens::into_token_stream)
@@ -70,6 +70,8 @@ impl SourceComposable for VarComposer<ObjCSpecification> {
                             FFIVariable::mut_ptr(parse_quote!(NSData)),
                         TypeModelKind::Dictionary(DictTypeModelKind::NonPrimitiveFermentable(DictFermentableModelKind::U128(..))) =>
                             FFIVariable::mut_ptr(parse_quote!(NSData)),
+                        TypeModelKind::Dictionary(DictTypeModelKind::NonPrimitiveFermentable(DictFermentableModelKind::Char(..))) =>
+                            FFIVariable::direct(parse_quote!(uint32_t)),
                         TypeModelKind::FnPointer(TypeModel { ty, .. }, ..) =>
                             FFIVariable::direct(Resolve::<SpecialType<ObjCSpecification>>::maybe_resolve(&ty, source)
                                 .map(ToTokens::into_token_stream)
@@ -576,6 +578,7 @@ pub fn objc_primitive_from_path(path: &Path) -> TokenStream2 {
             "u32" => quote!(uint32_t),
             "i64" => quote!(int32_t),
             "u64" => quote!(uint32_t),
+            "f32" => quote!(float),
             "f64" => quote!(double),
             "isize" => quote!(intptr_t),
             "usize" => quote!(uintptr_t),
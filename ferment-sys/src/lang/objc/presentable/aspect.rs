@@ -110,7 +110,7 @@ impl ScopeContextPresentable for Aspect<TypeContext> {
                     .mangle_ident_default()
                     .to_type()
                     .joined_ident(variant_ident),
-            Aspect::Ffi(TypeContext::Fn { sig_context: FnSignatureContext::TraitInner(_, self_ty, _), .. }) =>
+            Aspect::Ffi(TypeContext::Fn { sig_context: FnSignatureContext::TraitInner(_, self_ty, ..), .. }) =>
                 Resolve::<Type>::resolve(self_ty, source)
                     .mangle_ident_default()
                     .to_type(),
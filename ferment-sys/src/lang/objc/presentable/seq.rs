@@ -168,7 +168,7 @@ impl ScopeContextPresentable for SeqKind<ObjCSpecification> {
                     <ObjCSpecification as Specification>::Attr::from(aspect.attrs()),
                     presentation)
             },
-            SeqKind::Enum(context) => {
+            SeqKind::Enum(_repr, _derives, context) => {
                 println!("OBJC SEQ Enum ({:?})", context);
                 quote!()
                 //println!("SequenceOutput::{}({:?})", self, context);
@@ -204,12 +204,9 @@ impl ScopeContextPresentable for SeqKind<ObjCSpecification> {
                 presentation
                     .to_token_stream()
             },
-            SeqKind::EnumUnitFields(((aspect, (_attrs, _lifetimes, _generics), _is_round), fields)) => {
-                let aspect = aspect.present(source).to_path();
-                let ffi_name = &aspect.segments.last().unwrap().ident;
-                let fields = fields.present(source);
-                println!("OBJC SEQ EnumUnitFields ({}, {})", ffi_name, fields.to_token_stream());
-                Assignment::new(ffi_name.clone(), fields)
+            SeqKind::EnumVariantDiscriminant(variant, expr) => {
+                let variant = variant.present(source);
+                Assignment::new(variant, expr)
                     .to_token_stream()
             },
             // SeqKind::Boxed(conversions) => {
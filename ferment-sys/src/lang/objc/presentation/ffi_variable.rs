@@ -232,6 +232,7 @@ impl Resolve<FFIVariable<ObjCSpecification, TokenStream2>> for TypeModelKind {
                     DictFermentableModelKind::String(TypeModel { ty, .. }) |
                     DictFermentableModelKind::I128(TypeModel { ty, .. }) |
                     DictFermentableModelKind::U128(TypeModel { ty, .. }) |
+                    DictFermentableModelKind::Char(TypeModel { ty, .. }) |
                     DictFermentableModelKind::Other(TypeModel { ty, .. })
                 ) |
                 DictTypeModelKind::NonPrimitiveOpaque(TypeModel { ty, .. })
@@ -239,6 +240,7 @@ impl Resolve<FFIVariable<ObjCSpecification, TokenStream2>> for TypeModelKind {
             TypeModelKind::Trait(TraitModel { ty: TypeModel { ty, .. }, .. }, ..) |
             TypeModelKind::TraitType(TypeModel { ty, .. }) |
             TypeModelKind::Object(TypeModel { ty, .. }) |
+            TypeModelKind::FieldlessEnum(TypeModel { ty, .. }) |
             TypeModelKind::Optional(TypeModel { ty, .. }) |
             TypeModelKind::Array(TypeModel { ty, .. }) |
             TypeModelKind::Slice(TypeModel { ty, .. }) |
@@ -49,6 +49,7 @@ impl Mangle<MangleDefault> for Name<ObjCSpecification> {
                 format!("{}_as_{}_destroy", item_name.mangle_string_default(), trait_name.mangle_string_default())
                     .replace("r#", ""),
             Name::Vtable(trait_name) => format!("{}_VTable", trait_name),
+            Name::TraitProxy(trait_name) => format!("{}_Proxy", trait_name),
             Name::Getter(obj_type, field_name) => format!(
                 "{}_get_{}",
                 obj_type.mangle_ident_default(),
@@ -100,6 +101,7 @@ impl ToTokens for Name<ObjCSpecification> {
 
             Name::Dictionary(dict_field_name) => dict_field_name.to_tokens(tokens),
             Name::Vtable(trait_name) => format_ident!("{trait_name}_VTable").to_tokens(tokens),
+            Name::TraitProxy(trait_name) => format_ident!("{trait_name}_Proxy").to_tokens(tokens),
             Name::ModFn(path) => path.mangle_tokens_default().to_tokens(tokens),
             Name::TraitFn(item_name, trait_name) =>
                 format_ident!("{}_as_{}", item_name.mangle_string_default(), trait_name.mangle_string_default()).to_tokens(tokens),
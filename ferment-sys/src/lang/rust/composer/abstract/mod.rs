@@ -1,4 +1,4 @@
-use syn::{Item, Type};
+use syn::{Item, ItemEnum, ItemStruct, Type};
 use crate::composable::CfgAttributes;
 use crate::composer::{ItemComposerWrapper, MaybeComposer, MaybeMacroLabeled};
 use crate::context::{ScopeChain, ScopeContextLink};
@@ -13,6 +13,9 @@ impl MaybeComposer<RustSpecification> for Item {
             .and_then(|macro_type| match (macro_type, self) {
                 (MacroKind::Opaque, Item::Struct(item)) =>
                     Some(ItemComposerWrapper::opaque_struct(item, TypeContext::r#struct(&item.ident, item.attrs.cfg_attributes(), item.generics.clone()), scope_context)),
+                // Generic structs and enums are fermented per instantiation (see `GlobalContext::maybe_generic_instance`)
+                (MacroKind::Export, Item::Struct(ItemStruct { generics, .. }) | Item::Enum(ItemEnum { generics, .. })) if generics.type_params().next().is_some() =>
+                    None,
                 (MacroKind::Export, Item::Struct(item)) =>
                     Some(ItemComposerWrapper::r#struct(item, TypeContext::r#struct(&item.ident, item.attrs.cfg_attributes(), item.generics.clone()), scope_context)),
                 (MacroKind::Export, Item::Enum(item)) =>
@@ -25,6 +28,10 @@ impl MaybeComposer<RustSpecification> for Item {
                 }),
                 (MacroKind::Export, Item::Fn(item)) =>
                     Some(ItemComposerWrapper::r#fn(item, TypeContext::mod_fn(scope.self_path_ref().crate_named(&scope_context.borrow().scope.crate_ident_as_path()), item), scope_context)),
+                (MacroKind::Export, Item::Const(item)) =>
+                    Some(ItemComposerWrapper::r#const(item, scope.self_path_ref().crate_named(&scope.crate_ident_as_path()), scope_context)),
+                (MacroKind::Export, Item::Static(item)) =>
+                    Some(ItemComposerWrapper::r#static(item, scope.self_path_ref().crate_named(&scope.crate_ident_as_path()), scope_context)),
                 (MacroKind::Export, Item::Trait(item)) =>
                     Some(ItemComposerWrapper::r#trait(item, TypeContext::r#trait(item), scope, scope_context)),
                 (MacroKind::Export, Item::Impl(item)) =>
@@ -1,11 +1,12 @@
 use quote::{quote, ToTokens};
 use syn::{parse_quote, BareFnArg, Lifetime, ParenthesizedGenericArguments, ReturnType, Type, TypeBareFn};
+use syn::spanned::Spanned;
 use crate::ast::{CommaPunctuated, Depunctuated};
 use crate::composable::FieldComposer;
 use crate::composer::{AspectPresentable, AttrComposable, GenericComposerInfo, SourceComposable, ConversionToComposer, CallbackComposer, VarComposer};
 use crate::context::ScopeContext;
 use crate::kind::{CallbackKind, FieldTypeKind, GenericTypeKind, SpecialType, TypeKind};
-use crate::ext::{Accessory, FFISpecialTypeResolve, FFIVarResolve, GenericNestedArg, LifetimeProcessor, Mangle, MaybeParenthesizedArgs, MaybeTraitBound, PunctuateOne, Resolve, ToType, WrapIntoRoundBraces};
+use crate::ext::{Accessory, AsType, FFISpecialTypeResolve, FFIVarResolve, GenericNestedArg, LifetimeProcessor, Mangle, MaybeParenthesizedArgs, MaybeTraitBound, PunctuateOne, Resolve, ToType, WrapIntoRoundBraces};
 use crate::lang::RustSpecification;
 use crate::presentable::{Aspect, ScopeContextPresentable};
 use crate::presentation::{ArgPresentation, DictionaryExpr, DictionaryName, InterfacePresentation, Name};
@@ -35,7 +36,10 @@ impl SourceComposable for CallbackComposer<RustSpecification> {
                 let ParenthesizedGenericArguments { inputs, output, .. } = path.path.segments.last()?.maybe_parenthesized_args()?;
                 (inputs.clone(), output.clone())
             }
-            _ => panic!("Unsupported callback kind: {kind:?}")
+            _ => {
+                source.add_diagnostic(kind.as_type().span(), format!("`{}` isn't supported as a callback", kind.to_token_stream()));
+                return None;
+            }
         };
         let ffi_result = DictionaryName::FFiResult;
         let (return_type, from_result_conversion, dtor_arg) = match output {
@@ -71,7 +75,10 @@ impl SourceComposable for CallbackComposer<RustSpecification> {
                                 (ffi_ty.joined_mut(), DictionaryExpr::callback_dtor(DictionaryExpr::casted_ffi_conversion_from_opt(&ffi_ty, ty.ty(), &ffi_result), &ffi_result))
                             },
                         },
-                        GenericTypeKind::TraitBounds(_) => unimplemented!("TODO: mixins+traits+generics"),
+                        GenericTypeKind::TraitBounds(_) => {
+                            source.add_diagnostic(field_type.span(), format!("`{}` isn't supported as a callback result", field_type.to_token_stream()));
+                            return None;
+                        },
                         _ => {
                             let ffi_ty = FFIVarResolve::<RustSpecification>::special_or_to_ffi_full_path_type(&full_ty, source);
                             (ffi_ty.joined_mut(), DictionaryExpr::callback_dtor(DictionaryExpr::casted_ffi_conversion_from(&ffi_ty, &generic_ty, &ffi_result), &ffi_result))
@@ -82,9 +89,11 @@ impl SourceComposable for CallbackComposer<RustSpecification> {
             },
             ReturnType::Default => (ReturnType::Default, DictionaryExpr::DictionaryName(ffi_result), None),
         };
+        let context = DictionaryName::Context;
+        let context_destructor = DictionaryName::ContextDestructor;
         let mut args = CommaPunctuated::new();
-        let mut ffi_args = CommaPunctuated::new();
-        let mut arg_to_conversions = CommaPunctuated::new();
+        let mut ffi_args = CommaPunctuated::from_iter([bare_fn_arg(context_type())]);
+        let mut arg_to_conversions = CommaPunctuated::from_iter([quote!(self.#context)]);
         inputs
             .iter()
             .enumerate()
@@ -97,25 +106,35 @@ impl SourceComposable for CallbackComposer<RustSpecification> {
             });
         let ffi_type = self.present_ffi_aspect();
         let attrs = self.compose_attributes();
+        let mut field_composers = Depunctuated::from_iter([
+            FieldComposer::named_no_attrs(Name::context(), FieldTypeKind::Type(context_type())),
+        ]);
+        if let Some(dtor_arg) = dtor_arg {
+            field_composers.push(FieldComposer::named_no_attrs(Name::caller(), FieldTypeKind::Type(bare(ffi_args, ReturnType::Type(Default::default(), Box::new(dtor_arg.clone()))))));
+            field_composers.push(FieldComposer::named_no_attrs(Name::destructor(), FieldTypeKind::Type(bare(bare_fn_arg(dtor_arg).punctuate_one(), ReturnType::Default))));
+        } else {
+            field_composers.push(FieldComposer::named_no_attrs(Name::caller(), FieldTypeKind::Type(bare(ffi_args, ReturnType::Default))));
+        }
+        let context_dtor = bare(bare_fn_arg(context_type()).punctuate_one(), ReturnType::Default);
+        field_composers.push(FieldComposer::named_no_attrs(Name::context_destructor(), FieldTypeKind::Type(parse_quote!(Option<#context_dtor>))));
         Some(GenericComposerInfo::<RustSpecification>::callback(
             Aspect::raw_struct_ident(kind.mangle_ident_default()),
             &attrs,
-            if let Some(dtor_arg) = dtor_arg {
-                Depunctuated::from_iter([
-                    FieldComposer::named_no_attrs(Name::caller(), FieldTypeKind::Type(bare(ffi_args, ReturnType::Type(Default::default(), Box::new(dtor_arg.clone()))))),
-                    FieldComposer::named_no_attrs(Name::destructor(), FieldTypeKind::Type(bare(bare_fn_arg(dtor_arg).punctuate_one(), ReturnType::Default)))
-                ])
-            } else {
-                FieldComposer::named_no_attrs(Name::caller(), FieldTypeKind::Type(bare(ffi_args, ReturnType::Default))).punctuate_one()
-            },
+            field_composers,
             Depunctuated::from_iter([
                 InterfacePresentation::send_sync(&attrs, &ffi_type),
+                // The wrapper is the single owner of the context (so it isn't `Clone`) and releases it on drop
+                InterfacePresentation::drop(&attrs, ffi_type.clone(), quote!(if let Some(#context_destructor) = self.#context_destructor { #context_destructor(self.#context) })),
                 InterfacePresentation::callback(&attrs, &lifetimes, ffi_type, args, return_type, arg_to_conversions, from_result_conversion),
             ])
         ))
     }
 }
 
+fn context_type() -> Type {
+    parse_quote!(*const std::os::raw::c_void)
+}
+
 fn bare_fn_arg(ty: Type) -> BareFnArg {
     BareFnArg { attrs: vec![], name: None, ty }
 }
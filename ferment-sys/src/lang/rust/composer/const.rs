@@ -0,0 +1,36 @@
+use quote::{quote, ToTokens};
+use syn::{parse_quote, Type};
+use crate::ast::CommaPunctuated;
+use crate::composer::{ConstComposer, ConversionToComposer, SourceComposable, SourceFermentable, VarComposer};
+use crate::ext::{Mangle, Primitive, ToType};
+use crate::lang::RustSpecification;
+use crate::presentable::ScopeContextPresentable;
+use crate::presentation::{default_doc, BindingPresentation, DocPresentation, Name, RustFermentate};
+
+impl SourceFermentable<RustFermentate> for ConstComposer<RustSpecification> {
+    fn ferment(&self) -> RustFermentate {
+        let Self { path, ty, attrs, is_static, .. } = self;
+        let source = self.context.borrow();
+        let name = Name::<RustSpecification>::ModFn(path.clone());
+        let binding = if ty.is_primitive() {
+            let name = name.mangle_tokens_default();
+            BindingPresentation::Any {
+                attrs: attrs.clone(),
+                body: quote!(#[no_mangle] pub static #name: #ty = #path;)
+            }
+        } else {
+            let (ty, value) = if *is_static && !matches!(ty, Type::Reference(..)) {
+                (parse_quote!(&'static #ty), quote!(&#path))
+            } else {
+                (ty.clone(), path.to_token_stream())
+            };
+            let return_type = VarComposer::<RustSpecification>::key_ref_in_composer_scope(&ty).compose(&source).to_type();
+            let conversion = ConversionToComposer::<RustSpecification>::key_in_composer_scope(Name::obj(), &ty).compose(&source).present(&source);
+            BindingPresentation::regular_non_void_fn_with_body(&(attrs.clone(), vec![], None), name, CommaPunctuated::new(), return_type, quote!(let obj = #value; #conversion))
+        };
+        RustFermentate::Function {
+            comment: DocPresentation::Direct(default_doc(path)),
+            binding
+        }
+    }
+}
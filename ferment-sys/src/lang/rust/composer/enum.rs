@@ -3,7 +3,7 @@ use crate::ast::{CommaPunctuated, Depunctuated};
 use crate::composer::{AspectPresentable, AttrComposable, BindingComposable, DocsComposable, FFIAspect, FFIObjectComposable, GenericsComposable, InterfaceComposable, ItemComposerWrapper, SourceAccessible, SourceFermentable, TypeAspect, NameKindComposable, LifetimesComposable, EnumComposer};
 use crate::lang::{RustSpecification, Specification};
 use crate::presentable::{TypeContext, ArgKind, ScopeContextPresentable};
-use crate::presentation::{DictionaryExpr, InterfacePresentation, RustFermentate};
+use crate::presentation::{DictionaryExpr, DictionaryName, InterfacePresentation, RustFermentate};
 
 impl InterfaceComposable<<RustSpecification as Specification>::Interface> for EnumComposer<RustSpecification>
     where Self: SourceAccessible
@@ -36,6 +36,15 @@ impl InterfaceComposable<<RustSpecification as Specification>::Interface> for En
         to_conversions.push(ArgKind::AttrExhaustive(vec![]));
         destroy_conversions.push(ArgKind::AttrExhaustive(vec![]));
 
+        if self.fieldless {
+            let (ffi_type, target_type) = types.clone();
+            return Depunctuated::from_iter([
+                InterfacePresentation::from(&attrs, ffi_type.clone(), target_type.clone(), DictionaryExpr::MatchFields(quote!(value), from_conversions.present(&source))),
+                InterfacePresentation::from(&attrs, target_type.clone(), ffi_type.clone(), DictionaryExpr::MatchFields(quote!(value), to_conversions.present(&source))),
+                InterfacePresentation::conversion_from(&attrs, &types, DictionaryExpr::casted_from(&target_type, &ffi_type, quote!(*ffi)), &generics, &lifetimes),
+                InterfacePresentation::conversion_to_boxed(&attrs, &types, DictionaryExpr::casted_from(&ffi_type, &target_type, DictionaryName::Obj), &generics, &lifetimes),
+            ])
+        }
         let from_body = DictionaryExpr::MatchFields(quote!(ffi_ref), from_conversions.present(&source));
         let to_body = DictionaryExpr::MatchFields(quote!(obj), to_conversions.present(&source));
         let drop_body = DictionaryExpr::MatchFields(quote!(self), destroy_conversions.present(&source));
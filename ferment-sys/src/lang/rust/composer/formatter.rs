@@ -0,0 +1,82 @@
+use quote::{format_ident, quote};
+use syn::{parse_quote, Attribute, Generics};
+use crate::ast::{inherited_named_field, CommaPunctuated, DelimiterTrait, Depunctuated};
+use crate::composer::{AspectPresentable, AttrComposable, EnumComposer, GenericsComposable, ItemComposer, SourceAccessible, SourceFermentable, TypeAspect};
+use crate::ext::{is_handle_export, Accessory, CrateBased, Join, ToPath};
+use crate::lang::RustSpecification;
+use crate::presentable::TypeContext;
+use crate::presentation::{ArgPresentation, BindingPresentation, Name, RustFermentate};
+
+/// `<Type>_to_string` and `<Type>_debug_string` bindings of the types implementing `Display` and `Debug` (see `FormatterKind`):
+/// the strings are owned by the caller and have to be freed with `str_destroy`
+pub trait FormattersComposable: Sized
+    + SourceFermentable<RustFermentate>
+    + SourceAccessible
+    + TypeAspect<TypeContext>
+    + AttrComposable<Vec<Attribute>>
+    + GenericsComposable<Option<Generics>> {
+    /// Opaque types and handles are passed by the pointers to the Rust types
+    fn is_opaque(&self) -> bool;
+    /// Fieldless enums are passed by value
+    fn is_fieldless(&self) -> bool {
+        false
+    }
+
+    fn compose_formatters(&self) -> Depunctuated<BindingPresentation> {
+        let mut bindings = Depunctuated::new();
+        if self.compose_generics().is_some_and(|Generics { params, .. }| !params.is_empty()) {
+            return bindings;
+        }
+        let source = self.source_ref();
+        let self_path = source.scope.self_path_ref().crate_named(&source.scope.crate_ident_as_path());
+        let kinds = source.context.borrow().traits.maybe_formatters(&self_path).cloned().unwrap_or_default();
+        if kinds.is_empty() {
+            return bindings;
+        }
+        let attrs = self.compose_attributes();
+        let ffi_type = self.present_ffi_aspect();
+        let target_type = self.present_target_aspect();
+        let (obj_type, obj) = if self.is_opaque() {
+            (target_type.joined_const(), quote!(&*obj))
+        } else if self.is_fieldless() {
+            (ffi_type.clone(), quote!(&<#target_type as From<#ffi_type>>::from(obj)))
+        } else {
+            (ffi_type.joined_const(), quote!(&<#ffi_type as ferment::FFIConversionFrom<#target_type>>::ffi_from_const(obj)))
+        };
+        kinds.iter().for_each(|kind| {
+            let value = kind.format(&obj);
+            bindings.push(BindingPresentation::regular_non_void_fn_with_body(
+                &(attrs.clone(), vec![], None),
+                Name::ModFn(target_type.to_path().joined(&kind.fn_ident())),
+                CommaPunctuated::from_iter([ArgPresentation::Field(inherited_named_field(format_ident!("obj"), obj_type.clone()))]),
+                parse_quote!(*mut std::os::raw::c_char),
+                quote!(<std::os::raw::c_char as ferment::FFIConversionTo<String>>::ffi_to(#value))));
+        });
+        bindings
+    }
+
+    fn ferment_with_formatters(&self) -> RustFermentate {
+        let mut fermentate = self.ferment();
+        if let RustFermentate::Item { bindings, .. } = &mut fermentate {
+            bindings.extend(self.compose_formatters());
+        }
+        fermentate
+    }
+}
+
+impl<I> FormattersComposable for ItemComposer<RustSpecification, I>
+    where I: DelimiterTrait + ?Sized,
+          Self: SourceFermentable<RustFermentate> + TypeAspect<TypeContext> + AttrComposable<Vec<Attribute>> + GenericsComposable<Option<Generics>> {
+    fn is_opaque(&self) -> bool {
+        self.ffi_conversions_composer.is_none() || is_handle_export(&self.source_ref().scope.info().attrs)
+    }
+}
+
+impl FormattersComposable for EnumComposer<RustSpecification> {
+    fn is_opaque(&self) -> bool {
+        false
+    }
+    fn is_fieldless(&self) -> bool {
+        self.fieldless
+    }
+}
@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use indexmap::IndexSet;
 use syn::Attribute;
 use crate::ast::{BraceWrapped, CommaPunctuated};
 use crate::composable::CfgAttributes;
@@ -10,7 +10,7 @@ use crate::presentable::{ScopeContextPresentable, TypeContext};
 use crate::presentation::{DocPresentation, FFIObjectPresentation, present_struct, RustFermentate};
 
 impl GenericComposer<RustSpecification> {
-    pub fn mixin(context: (&MixinKind, &HashSet<Option<Attribute>>), scope_link: &ScopeContextLink) -> Option<ComposerLink<Self>> {
+    pub fn mixin(context: (&MixinKind, &IndexSet<Option<Attribute>>), scope_link: &ScopeContextLink) -> Option<ComposerLink<Self>> {
         let (mixin, attrs) = context;
         let attrs = expand_attributes(attrs);
         Self::new(mixin, TypeContext::mixin(mixin, attrs.cfg_attributes()), attrs, scope_link)
@@ -30,10 +30,11 @@ impl SourceComposable for GenericComposer<RustSpecification> {
                       ffi_aspect,
                       attrs,
                       interfaces,
-                      bindings
+                      bindings,
+                      cloneable
                   }| {
                 let struct_body = BraceWrapped::new(CommaPunctuated::from_iter(field_composers.iter().map(field_composer)));
-                let ffi_presentation = FFIObjectPresentation::Full(present_struct(ffi_aspect.present(source), &attrs, struct_body.present(source)));
+                let ffi_presentation = FFIObjectPresentation::Full(present_struct(ffi_aspect.present(source), &attrs, cloneable, struct_body.present(source)));
 
                 RustFermentate::Item {
                     attrs,
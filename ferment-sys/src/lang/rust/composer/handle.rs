@@ -0,0 +1,95 @@
+use quote::{format_ident, quote};
+use syn::Generics;
+use crate::ast::{inherited_named_field, CommaPunctuated, DelimiterTrait, Depunctuated};
+use crate::composer::{AspectPresentable, FieldsContext, ItemComposer, SourceComposable, VarComposer};
+use crate::ext::{is_handle_export, Accessory, Conversion, Join, ToPath, ToType};
+use crate::lang::{FromDictionary, RustSpecification};
+use crate::lang::rust::composer::formatter::FormattersComposable;
+use crate::presentable::{Expression, ScopeContextPresentable};
+use crate::presentation::{ArgPresentation, BindingPresentation, DictionaryName, Name, RustFermentate};
+
+/// Structs exported with `#[ferment_macro::export(handle)]` are passed as the boxed Rust values (`*mut <Type>`),
+/// so the methods borrow them without converting the FFI struct back and forth.
+/// The FFI struct is kept as the snapshot of the value:
+/// - `<Type>_from_ffi(ffi)` creates the handle from the FFI struct
+/// - `<Type>_to_ffi(obj)` converts the (cloned) value into the FFI struct
+/// - `<Type>_handle_get_<field>(obj)` and `<Type>_handle_set_<field>(obj, value)` convert the single field
+/// - `<Type>_handle_destroy(obj)` drops the value
+pub trait HandleComposable: FormattersComposable
+    + FieldsContext<RustSpecification> {
+    fn is_handle(&self) -> bool {
+        is_handle_export(&self.source_ref().scope.info().attrs)
+    }
+
+    fn compose_handle_bindings(&self) -> Depunctuated<BindingPresentation> {
+        let mut bindings = Depunctuated::new();
+        if !self.is_handle() || self.compose_generics().is_some_and(|Generics { params, .. }| !params.is_empty()) {
+            return bindings;
+        }
+        let source = self.source_ref();
+        let attrs = self.compose_attributes();
+        let aspect = (attrs, vec![], None);
+        let ffi_type = self.present_ffi_aspect();
+        let target_type = self.present_target_aspect();
+        let target_path = target_type.to_path();
+        let obj_arg = |obj_type| CommaPunctuated::from_iter([ArgPresentation::Field(inherited_named_field(format_ident!("obj"), obj_type))]);
+        bindings.push(BindingPresentation::regular_non_void_fn_with_body(
+            &aspect,
+            Name::ModFn(target_path.joined(&format_ident!("from_ffi"))),
+            CommaPunctuated::from_iter([ArgPresentation::Field(inherited_named_field(format_ident!("ffi"), ffi_type.joined_const()))]),
+            target_type.joined_mut(),
+            quote!(ferment::boxed(<#ffi_type as ferment::FFIConversionFrom<#target_type>>::ffi_from_const(ffi)))));
+        bindings.push(BindingPresentation::regular_non_void_fn_with_body(
+            &aspect,
+            Name::ModFn(target_path.joined(&format_ident!("to_ffi"))),
+            obj_arg(target_type.joined_const()),
+            ffi_type.joined_mut(),
+            quote!(<#ffi_type as ferment::FFIConversionTo<#target_type>>::ffi_to((*obj).clone()))));
+        bindings.push(BindingPresentation::regular_void_fn_with_body(
+            &aspect,
+            Name::ModFn(target_path.joined(&format_ident!("handle_destroy"))),
+            obj_arg(target_type.joined_mut()),
+            quote!(ferment::unbox_any(obj);)));
+        self.field_composers_ref()
+            .iter()
+            .for_each(|composer| {
+                let field_name = composer.tokenized_name();
+                let field_type = VarComposer::<RustSpecification>::key_ref_in_composer_scope(composer.ty())
+                    .compose(&source)
+                    .to_type();
+                let to_conversion = Conversion::expr_to(composer, Some(Expression::obj_name(&composer.name).cloned()))
+                    .compose(&source)
+                    .present(&source);
+                let from_conversion = Conversion::expr_from(composer, Some(Expression::Name(Name::dictionary_name(DictionaryName::Value))))
+                    .compose(&source)
+                    .present(&source);
+                bindings.push(BindingPresentation::regular_non_void_fn_with_body(
+                    &aspect,
+                    Name::ModFn(target_path.joined(&format_ident!("handle_get_{}", field_name.to_string()))),
+                    obj_arg(target_type.joined_const()),
+                    field_type.clone(),
+                    quote!(let obj = &*obj; #to_conversion)));
+                bindings.push(BindingPresentation::regular_void_fn_with_body(
+                    &aspect,
+                    Name::ModFn(target_path.joined(&format_ident!("handle_set_{}", field_name.to_string()))),
+                    CommaPunctuated::from_iter([
+                        ArgPresentation::Field(inherited_named_field(format_ident!("obj"), target_type.joined_mut())),
+                        ArgPresentation::Field(inherited_named_field(format_ident!("value"), field_type))
+                    ]),
+                    quote!(let obj = &mut *obj; obj.#field_name = #from_conversion;)));
+            });
+        bindings
+    }
+
+    fn ferment_with_handle(&self) -> RustFermentate {
+        let mut fermentate = self.ferment_with_formatters();
+        if let RustFermentate::Item { bindings, .. } = &mut fermentate {
+            bindings.extend(self.compose_handle_bindings());
+        }
+        fermentate
+    }
+}
+
+impl<I> HandleComposable for ItemComposer<RustSpecification, I>
+    where I: DelimiterTrait + ?Sized,
+          Self: FormattersComposable + FieldsContext<RustSpecification> {}
@@ -10,6 +10,7 @@ impl SourceFermentable<RustFermentate> for ImplComposer<RustSpecification> {
             let fermentate = sig_composer.borrow().ferment();
             items.push(fermentate);
         });
+        self.consts.iter().for_each(|const_composer| items.push(const_composer.borrow().ferment()));
         let vtable = self.vtable.as_ref()
             .map(|composer| {
                 let composer = composer.borrow();
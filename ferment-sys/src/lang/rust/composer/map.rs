@@ -78,8 +78,8 @@ impl SourceComposable for MapComposer<RustSpecification> {
                 BindingPresentableContext::<RustSpecification>::dtor((dtor_context, Default::default())),
                 // BindingPresentableContext::<RustSpecification>::key_by_value(get_context.clone()),
                 // BindingPresentableContext::<RustSpecification>::set_key_for_value(get_context.clone(), key_type.clone()),
-                BindingPresentableContext::<RustSpecification>::value_by_key(get_context.clone()),
-                BindingPresentableContext::<RustSpecification>::set_value_for_key(get_context, value_type.clone())
+                BindingPresentableContext::<RustSpecification>::value_by_key(get_context.clone(), key_type.clone(), value_type.clone()),
+                BindingPresentableContext::<RustSpecification>::set_value_for_key(get_context, key_type.clone(), value_type.clone())
             ])
 
         ))
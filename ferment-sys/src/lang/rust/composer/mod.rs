@@ -1,5 +1,8 @@
 mod item;
+mod r#const;
 mod r#enum;
+mod formatter;
+mod handle;
 mod generic;
 mod signature;
 mod r#trait;
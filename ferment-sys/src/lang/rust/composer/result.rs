@@ -32,15 +32,19 @@ impl SourceComposable for ResultComposer<RustSpecification> {
 
         let ok_is_primitive = type_ok.is_primitive();
         let error_is_primitive = type_error.is_primitive();
+        let ok_is_fieldless = source.is_fieldless_enum(type_ok);
+        let error_is_fieldless = source.is_fieldless_enum(type_error);
         let error_is_optional = type_error.is_optional();
 
         let map_var_name = Name::o();
         let var_ok = VarComposer::<RustSpecification>::value(type_ok).compose(source);
         let var_error = VarComposer::<RustSpecification>::value(type_error).compose(source);
-        let from_conversion_expr_ok = ConversionFromComposer::<RustSpecification>::value_ref_maybe_expr(&map_var_name, type_ok, ok_is_primitive.then(|| Expression::DictionaryExpr(DictionaryExpr::deref(&map_var_name)))).compose(source);
-        let from_conversion_expr_error = ConversionFromComposer::<RustSpecification>::value_ref_maybe_expr(&map_var_name, type_error, error_is_primitive.then(|| Expression::DictionaryExpr(DictionaryExpr::deref(&map_var_name)))).compose(source);
+        let from_conversion_expr_ok = ConversionFromComposer::<RustSpecification>::value_ref_maybe_expr(&map_var_name, type_ok, (ok_is_primitive || ok_is_fieldless).then(|| Expression::DictionaryExpr(DictionaryExpr::deref(&map_var_name)))).compose(source);
+        let from_conversion_expr_error = ConversionFromComposer::<RustSpecification>::value_ref_maybe_expr(&map_var_name, type_error, (error_is_primitive || error_is_fieldless).then(|| Expression::DictionaryExpr(DictionaryExpr::deref(&map_var_name)))).compose(source);
         let to_conversion_expr_ok = ConversionToComposer::<RustSpecification>::value_ref_maybe_expr(&map_var_name, type_ok, ok_is_primitive.then(|| Expression::boxed_tokens(&map_var_name))).compose(source);
         let to_conversion_expr_error = ConversionToComposer::<RustSpecification>::value_ref_maybe_expr(&map_var_name, type_error, error_is_primitive.then(|| Expression::boxed_tokens(&map_var_name))).compose(source);
+        let to_conversion_expr_ok = if ok_is_fieldless { Expression::boxed(to_conversion_expr_ok) } else { to_conversion_expr_ok };
+        let to_conversion_expr_error = if error_is_fieldless { Expression::boxed(to_conversion_expr_error) } else { to_conversion_expr_error };
         let destroy_conversion_expr_ok = ConversionDropComposer::<RustSpecification>::value_ref(&name_self_ok, type_ok).compose(source).unwrap_or_else(|| Expression::black_hole(name_self_ok.clone()));
         let destroy_conversion_expr_error = ConversionDropComposer::<RustSpecification>::value_ref(&name_self_error, type_error).compose(source).unwrap_or_else(|| Expression::black_hole(name_self_error.clone()));
         let from_conversion_ok = Expression::map_o_expr(from_conversion_expr_ok).present(source);
@@ -63,8 +67,8 @@ impl SourceComposable for ResultComposer<RustSpecification> {
             #destroy_conversion_error;
         };
 
-        let var_ok = if ok_is_primitive { var_ok.joined_mut() } else { var_ok };
-        let var_error = if error_is_primitive { var_error.joined_mut() } else { var_error };
+        let var_ok = if ok_is_primitive || ok_is_fieldless { var_ok.joined_mut() } else { var_ok };
+        let var_error = if error_is_primitive || error_is_fieldless { var_error.joined_mut() } else { var_error };
 
         let field_composers = Depunctuated::from_iter([
             FieldComposer::named_no_attrs(<RustSpecification as Specification>::Name::ok(), FieldTypeKind::Var(var_ok.clone())),
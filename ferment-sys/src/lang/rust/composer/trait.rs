@@ -1,14 +1,16 @@
-use quote::ToTokens;
-use syn::parse_quote;
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use quote::{format_ident, quote, ToTokens};
+use syn::{parse_quote, Attribute, FnArg, Pat, PatIdent, PatType, Receiver, ReturnType, Type, TypeReference};
 use syn::token::Comma;
-use crate::ast::{BraceWrapped, CommaPunctuated};
-use crate::composable::FieldComposer;
-use crate::composer::{AspectPresentable, AttrComposable, SourceAccessible, SourceFermentable, TraitComposer};
-use crate::ext::{Mangle, ToPath};
+use crate::ast::{BraceWrapped, CommaPunctuated, Depunctuated};
+use crate::composable::{FieldComposer, FnSignatureContext};
+use crate::composer::{AspectPresentable, AttrComposable, ConversionDropComposer, ConversionFromComposer, ConversionToComposer, SigComposerLink, SourceAccessible, SourceComposable, SourceFermentable, TraitComposer, TypeAspect};
+use crate::context::ScopeContext;
+use crate::ext::{Mangle, ToPath, ToType};
 use crate::kind::FieldTypeKind;
 use crate::lang::{FromDictionary, RustSpecification};
-use crate::presentable::ScopeContextPresentable;
-use crate::presentation::{DictionaryName, DocPresentation, FFIObjectPresentation, Name, RustFermentate};
+use crate::presentable::{ScopeContextPresentable, TypeContext};
+use crate::presentation::{ArgPresentation, BindingPresentation, DictionaryName, DocPresentation, FFIObjectPresentation, InterfacePresentation, Name, RustFermentate};
 
 impl SourceFermentable<RustFermentate> for TraitComposer<RustSpecification> {
     fn ferment(&self) -> RustFermentate {
@@ -17,6 +19,12 @@ impl SourceFermentable<RustFermentate> for TraitComposer<RustSpecification> {
         let ffi_type = self.present_ffi_aspect();
         let mangled_ty = ffi_type.mangle_ident_default();
         let vtable_name = Name::<RustSpecification>::Vtable(mangled_ty.clone());
+        let trait_object_name = Name::<RustSpecification>::TraitObj(mangled_ty.clone());
+        let (proxy, proxy_defaults, proxy_interfaces, proxy_bindings) = if self.proxyable {
+            self.compose_proxy(&attrs, &mangled_ty)
+        } else {
+            (FFIObjectPresentation::Empty, FFIObjectPresentation::Empty, Depunctuated::new(), Depunctuated::new())
+        };
         RustFermentate::Trait {
             comment: DocPresentation::Empty,
             vtable: FFIObjectPresentation::TraitVTable {
@@ -30,7 +38,7 @@ impl SourceFermentable<RustFermentate> for TraitComposer<RustSpecification> {
             },
             trait_object: FFIObjectPresentation::TraitObject {
                 attrs,
-                name: Name::<RustSpecification>::TraitObj(mangled_ty).to_path(),
+                name: trait_object_name.to_path(),
                 fields: BraceWrapped::new(
                     CommaPunctuated::from_iter([
                         FieldComposer::<RustSpecification>::named_no_attrs(
@@ -40,8 +48,223 @@ impl SourceFermentable<RustFermentate> for TraitComposer<RustSpecification> {
                             Name::dictionary_name(DictionaryName::Vtable),
                             FieldTypeKind::Type(parse_quote!(*const #vtable_name))),
                     ])).present(&self.context().borrow())
-                }
+            },
+            proxy,
+            proxy_defaults,
+            proxy_interfaces,
+            proxy_bindings,
+        }
+    }
+}
+
+impl TraitComposer<RustSpecification> {
+    /// Foreign implementation of the trait: the trait object holds the foreign context and vtable,
+    /// the proxy implements the trait by calling through the vtable and releases the context on drop
+    fn compose_proxy(&self, attrs: &[Attribute], mangled_ty: &Ident) -> (FFIObjectPresentation, FFIObjectPresentation, Depunctuated<InterfacePresentation>, Depunctuated<BindingPresentation>) {
+        let source = self.context().borrow();
+        let trait_ty = self.present_target_aspect();
+        let vtable_name = Name::<RustSpecification>::Vtable(mangled_ty.clone());
+        let trait_object_name = Name::<RustSpecification>::TraitObj(mangled_ty.clone());
+        let proxy_name = Name::<RustSpecification>::TraitProxy(mangled_ty.clone());
+        let proxy_ty = proxy_name.to_type();
+        let defaults_prefix = format_ident!("{}_Defaults", proxy_name.mangle_ident_default());
+        let object = DictionaryName::Object;
+        let vtable = DictionaryName::Vtable;
+        let context_destructor = DictionaryName::ContextDestructor;
+        let context_destructor_ty: Type = parse_quote!(Option<unsafe extern "C" fn(object: *const ())>);
+        let mut methods = TokenStream2::new();
+        let mut forwarded_methods = vec![];
+        self.methods.iter()
+            .filter_map(|composer| compose_proxy_method(composer, &trait_ty, &proxy_ty, &defaults_prefix))
+            .for_each(|(proxy_method, forwarded_method)| {
+                methods.extend(proxy_method);
+                forwarded_methods.push(forwarded_method);
+            });
+        let mut interfaces = Depunctuated::from_iter([
+            InterfacePresentation::trait_impl(attrs, trait_ty.clone(), proxy_ty.clone(), methods),
+            InterfacePresentation::drop(attrs, proxy_ty.clone(), quote!(if let Some(#context_destructor) = self.#context_destructor { #context_destructor(self.#object.#object) })),
+        ]);
+        // The proxy holds the raw foreign pointers, so it's only as thread-safe as the foreign side promises
+        let markers = source.trait_proxy_markers(&trait_ty.to_path());
+        if !markers.is_empty() {
+            interfaces.push(InterfacePresentation::markers(attrs, &proxy_ty, Vec::from_iter(markers)));
+        }
+        // Defaulted methods which are left null in the vtable fall back to the Rust implementation.
+        // Each of them gets a wrapper which keeps its default body and forwards the rest to the proxy,
+        // so the default body still calls the foreign overrides. The wrapper borrows the proxy
+        // the same way as the receiver of the defaulted method does
+        let mut defaults = TokenStream2::new();
+        forwarded_methods.iter()
+            .filter(|(_, has_default, _)| *has_default)
+            .for_each(|(default_ident, ..)| {
+                let defaults_ident = format_ident!("{defaults_prefix}_{default_ident}");
+                let forwarded = TokenStream2::from_iter(forwarded_methods.iter()
+                    .filter(|(ident, ..)| ident != default_ident)
+                    .map(|(.., forwarded)| forwarded.clone()));
+                interfaces.push(InterfacePresentation::trait_impl(attrs, trait_ty.clone(), parse_quote!(#defaults_ident<'_>), forwarded));
+                defaults.extend(quote!(#(#attrs)* enum #defaults_ident<'a> { Ref(&'a #proxy_ty), Mut(&'a mut #proxy_ty) }));
+            });
+        let proxy_defaults = if defaults.is_empty() {
+            FFIObjectPresentation::Empty
+        } else {
+            FFIObjectPresentation::Full(defaults)
+        };
+        let proxy = FFIObjectPresentation::TraitProxy {
+            attrs: attrs.to_owned(),
+            name: proxy_name.to_path(),
+            fields: BraceWrapped::new(
+                CommaPunctuated::from_iter([
+                    FieldComposer::<RustSpecification>::named_no_attrs(
+                        Name::dictionary_name(object.clone()),
+                        FieldTypeKind::Type(trait_object_name.to_type())),
+                    FieldComposer::<RustSpecification>::named_no_attrs(
+                        Name::dictionary_name(context_destructor.clone()),
+                        FieldTypeKind::Type(context_destructor_ty.clone())),
+                ])).present(&source)
+        };
+        let aspect = (attrs.to_owned(), vec![], None);
+        let bindings = Depunctuated::from_iter([
+            BindingPresentation::Constructor {
+                aspect: aspect.clone(),
+                name: Name::<RustSpecification>::Constructor(proxy_ty.clone()).to_token_stream(),
+                ty: proxy_ty.clone(),
+                ctor_arguments: CommaPunctuated::from_iter([
+                    ArgPresentation::inherited_field(&[], Name::<RustSpecification>::dictionary_name(object.clone()).mangle_ident_default(), parse_quote!(*const ())),
+                    ArgPresentation::inherited_field(&[], Name::<RustSpecification>::dictionary_name(vtable.clone()).mangle_ident_default(), parse_quote!(*const #vtable_name)),
+                    ArgPresentation::inherited_field(&[], Name::<RustSpecification>::dictionary_name(context_destructor.clone()).mangle_ident_default(), context_destructor_ty),
+                ]),
+                body_presentation: quote!({ #object: #trait_object_name { #object, #vtable }, #context_destructor }),
+            },
+            BindingPresentation::Destructor {
+                aspect,
+                name: Name::<RustSpecification>::Destructor(proxy_ty.clone()).to_token_stream(),
+                var: parse_quote!(*mut #proxy_ty),
             }
+        ]);
+        (proxy, proxy_defaults, interfaces, bindings)
     }
 }
 
+/// Proxy method converts the arguments, calls the vtable entry, releases the converted arguments
+/// and takes ownership of the result. Also returns the method forwarded from the defaults wrappers to the proxy
+fn compose_proxy_method(composer: &SigComposerLink<RustSpecification>, trait_ty: &Type, proxy_ty: &Type, defaults_prefix: &Ident) -> Option<(TokenStream2, (Ident, bool, TokenStream2))> {
+    let composer = composer.borrow();
+    let source = composer.source_ref();
+    let TypeContext::Fn { sig_context: FnSignatureContext::TraitInner(sig, _, _, has_default), attrs, .. } = composer.type_context_ref() else {
+        return None;
+    };
+    let has_default = *has_default;
+    let mut sig = sig.clone();
+    let mut arg_names = CommaPunctuated::<Ident>::new();
+    let mut ffi_args = CommaPunctuated::<TokenStream2>::new();
+    let mut arg_conversions = TokenStream2::new();
+    let mut arg_destructors = TokenStream2::new();
+    let mut receiver_mutability = None;
+    sig.inputs.iter_mut().enumerate().for_each(|(index, arg)| match arg {
+        // Proxyable methods take `self` by reference only (see `Proxyable`)
+        FnArg::Receiver(Receiver { mutability, .. }) => {
+            receiver_mutability = *mutability;
+            ffi_args.push(match mutability {
+                Some(..) => quote!(&mut self.object),
+                None => quote!(&self.object as *const _ as *mut _),
+            });
+        },
+        FnArg::Typed(PatType { pat, ty, .. }) => {
+            let ident = match &**pat {
+                Pat::Ident(PatIdent { ident, .. }) => ident.clone(),
+                _ => Name::<RustSpecification>::UnnamedArg(index).mangle_ident_default()
+            };
+            **pat = parse_quote!(#ident);
+            **ty = full_sig_type(&source, ty);
+            let name = Name::<RustSpecification>::Ident(ident.clone());
+            let conversion = ConversionToComposer::<RustSpecification>::key_in_composer_scope(name.clone(), ty).compose(&source).present(&source);
+            arg_conversions.extend(quote!(let #ident = #conversion;));
+            if let Some(destructor) = ConversionDropComposer::<RustSpecification>::key_expr_in_composer_scope(name, ty, None).compose(&source) {
+                let destructor = destructor.present(&source);
+                arg_destructors.extend(quote!(#destructor;));
+            }
+            ffi_args.push(ident.to_token_stream());
+            arg_names.push(ident);
+        }
+    });
+    if let ReturnType::Type(_, ty) = &mut sig.output {
+        **ty = full_sig_type(&source, ty);
+    }
+    let ident = &sig.ident;
+    let defaults_ident = format_ident!("{defaults_prefix}_{ident}");
+    let call_with = |caller: TokenStream2| {
+        let call = quote!(#caller(#ffi_args));
+        match &sig.output {
+            ReturnType::Default => quote! {
+                #arg_conversions
+                #call;
+                #arg_destructors
+            },
+            ReturnType::Type(_, ty) => {
+                let ffi_result = Name::<RustSpecification>::dictionary_name(DictionaryName::FFiResult);
+                let result_conversion = ConversionFromComposer::<RustSpecification>::key_in_composer_scope(ffi_result.clone(), ty).compose(&source).present(&source);
+                let result_destructor = ConversionDropComposer::<RustSpecification>::key_expr_in_composer_scope(ffi_result.clone(), ty, None).compose(&source)
+                    .map(|destructor| destructor.present(&source));
+                quote! {
+                    #arg_conversions
+                    let #ffi_result = #call;
+                    #arg_destructors
+                    let result = #result_conversion;
+                    #result_destructor;
+                    result
+                }
+            }
+        }
+    };
+    let (defaults, forward) = match receiver_mutability {
+        Some(..) => (
+            quote!(&mut #defaults_ident::Mut(self)),
+            // Shared defaults can't call `&mut self` methods, they only have `&Self`
+            quote!(match self {
+                Self::Mut(proxy) => <#proxy_ty as #trait_ty>::#ident(proxy, #arg_names),
+                Self::Ref(..) => unreachable!("`&mut self` method called through a shared reference")
+            })
+        ),
+        None => (
+            quote!(&#defaults_ident::Ref(self)),
+            quote!(match self {
+                Self::Ref(proxy) => <#proxy_ty as #trait_ty>::#ident(proxy, #arg_names),
+                Self::Mut(proxy) => <#proxy_ty as #trait_ty>::#ident(proxy, #arg_names)
+            })
+        )
+    };
+    let body = if has_default {
+        let call = call_with(ident.to_token_stream());
+        quote! {
+            match (*self.object.vtable).#ident {
+                Some(#ident) => { #call }
+                None => <#defaults_ident as #trait_ty>::#ident(#defaults, #arg_names)
+            }
+        }
+    } else {
+        call_with(quote!(((*self.object.vtable).#ident)))
+    };
+    let forwarded_method = quote! {
+        #(#attrs)*
+        #sig {
+            #forward
+        }
+    };
+    Some((quote! {
+        #(#attrs)*
+        #sig {
+            unsafe { #body }
+        }
+    }, (ident.clone(), has_default, forwarded_method)))
+}
+
+/// Proxy lives in the fermented module, so the signature types should be given with their full paths
+fn full_sig_type(source: &ScopeContext, ty: &Type) -> Type {
+    match ty {
+        Type::Reference(TypeReference { lifetime, mutability, elem, .. }) => {
+            let elem = full_sig_type(source, elem);
+            parse_quote!(&#lifetime #mutability #elem)
+        },
+        _ => source.full_type_for(ty)
+    }
+}
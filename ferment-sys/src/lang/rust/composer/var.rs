@@ -57,8 +57,14 @@ impl SourceComposable for VarComposer<RustSpecification> {
                                 FFIVariable::mut_ptr(ty),
                             TypeModelKind::Dictionary(DictTypeModelKind::Primitive(TypeModel { ty, .. })) =>
                                 ty.to_direct_var(),
+                            TypeModelKind::FieldlessEnum(TypeModel { ty, .. }) =>
+                                Resolve::<FFIFullPath<RustSpecification>>::resolve(&ty, source)
+                                    .to_type()
+                                    .to_direct_var(),
                             TypeModelKind::Dictionary(DictTypeModelKind::NonPrimitiveFermentable(DictFermentableModelKind::I128(..) | DictFermentableModelKind::U128(..))) =>
                                 FFIVariable::mut_ptr(parse_quote!([u8; 16])),
+                            TypeModelKind::Dictionary(DictTypeModelKind::NonPrimitiveFermentable(DictFermentableModelKind::Char(..))) =>
+                                FFIVariable::direct(parse_quote!(u32)),
                             TypeModelKind::FnPointer(TypeModel { ty, .. }, ..) =>
                                 Resolve::<SpecialType<RustSpecification>>::maybe_resolve(&ty, source)
                                     .map(|special| special.to_type())
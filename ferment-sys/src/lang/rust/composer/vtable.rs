@@ -1,5 +1,6 @@
+use proc_macro2::Ident;
 use quote::{format_ident, quote, ToTokens};
-use syn::{FnArg, PatType, Receiver, ReturnType, Signature};
+use syn::{FnArg, PatType, Receiver, ReturnType, Signature, TraitItem, TraitItemFn};
 use syn::__private::TokenStream2;
 use syn::token::{Const, RArrow, Semi};
 use crate::ast::{CommaPunctuated, Depunctuated};
@@ -22,10 +23,10 @@ impl SourceComposable for VTableComposer<RustSpecification> {
         let trait_ty = self.raw_target_type_aspect().present(source);
         let full_target_type = source.full_type_for(&target_type);
         let full_type = source.full_type_for(&trait_ty);
-        let maybe_item_trait = source.maybe_item_trait(&full_type.to_path());
-        if maybe_item_trait.is_none() {
+        let Some(item_trait) = source.maybe_item_trait(&full_type.to_path()) else {
             return BindingPresentation::Empty;
-        }
+        };
+        let has_default = |ident: &Ident| item_trait.items.iter().any(|item| matches!(item, TraitItem::Fn(TraitItemFn { sig, default: Some(..), .. }) if ident == &sig.ident));
         let mut methods_declarations = CommaPunctuated::new();
         let mut methods_implementations = Depunctuated::new();
         self.vtable_method_composers
@@ -34,14 +35,15 @@ impl SourceComposable for VTableComposer<RustSpecification> {
                 let method_composer = method_composer.borrow();
                 let method_scope_context = method_composer.source_ref();
                 let method_ty_context = method_composer.type_context();
-                let sig_context = match &method_ty_context {
-                    TypeContext::Fn { sig_context, .. } => sig_context,
+                let (path, sig_context) = match &method_ty_context {
+                    TypeContext::Fn { path, sig_context, .. } => (path, sig_context),
                     _ => panic!("Not a function")
                 };
 
                 let sig = sig_context.maybe_signature().unwrap();
                 let Signature { ident, output, inputs, .. } = sig;
-                let name = Name::<RustSpecification>::TraitImplVtableFn(trait_ty.mangle_ident_default(), method_scope_context.scope.to_type().mangle_ident_default());
+                // Default methods are composed in the trait scope, so the name is based on the implementor path
+                let name = Name::<RustSpecification>::TraitImplVtableFn(trait_ty.mangle_ident_default(), path.to_type().mangle_ident_default());
                 let mut args = CommaPunctuated::new();
                 let mut args_conversions = CommaPunctuated::new();
                 inputs.iter().for_each(|arg| match arg {
@@ -64,7 +66,7 @@ impl SourceComposable for VTableComposer<RustSpecification> {
                     FnArg::Typed(PatType { ty, attrs, pat, .. }) => {
                         let name = Name::<RustSpecification>::pat(pat);
                         args.push(ArgPresentation::inherited_field(attrs, name.mangle_ident_default(), VarComposer::<RustSpecification>::key_ref_in_composer_scope(ty).compose(&method_scope_context).to_type()));
-                        args_conversions.push(ArgPresentation::attr_tokens(attrs, ConversionFromComposer::<RustSpecification>::key_in_composer_scope(name, ty).compose(&method_scope_context).present(source)));
+                        args_conversions.push(ArgPresentation::attr_tokens(attrs, ConversionFromComposer::<RustSpecification>::key_in_composer_scope(name, ty).compose(&method_scope_context).present(&method_scope_context)));
                     }
                 });
 
@@ -79,7 +81,8 @@ impl SourceComposable for VTableComposer<RustSpecification> {
                 let output_conversion = presentable_output_conversion.present(&method_scope_context);
                 methods_declarations.push(BindingPresentation::StaticVTableInnerFnDeclaration {
                     name: name.to_token_stream(),
-                    fn_name: ident.clone()
+                    fn_name: ident.clone(),
+                    nullable: has_default(ident)
                 });
                 methods_implementations.push(BindingPresentation::StaticVTableInnerFn {
                     aspect: (vec![], vec![], None),
@@ -14,6 +14,10 @@ impl Resolve<FFIVariable<RustSpecification, Type>> for ScopeSearchKey {
         Some(self.resolve(source))
     }
     fn resolve(&self, source: &ScopeContext) -> FFIVariable<RustSpecification, Type> {
+        let ty = self.to_type();
+        if source.is_fieldless_enum(&ty) {
+            return ty.resolve(source);
+        }
         Resolve::<SpecialType<RustSpecification>>::maybe_resolve(self, source)
             .map(FFIFullPath::from)
             .or_else(|| Resolve::<TypeModelKind>::resolve(self, source)
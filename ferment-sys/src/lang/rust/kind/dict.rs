@@ -31,6 +31,8 @@ impl Resolve<FFIVariable<RustSpecification, Type>> for DictTypeModelKind {
                     }),
             DictTypeModelKind::NonPrimitiveFermentable(DictFermentableModelKind::I128(..) | DictFermentableModelKind::U128(..)) =>
                 FFIVariable::mut_ptr(parse_quote!([u8; 16])),
+            DictTypeModelKind::NonPrimitiveFermentable(DictFermentableModelKind::Char(..)) =>
+                FFIVariable::mut_ptr(parse_quote!(u32)),
             DictTypeModelKind::NonPrimitiveFermentable(DictFermentableModelKind::SmartPointer(SmartPointerModelKind::Box(model))) |
             DictTypeModelKind::NonPrimitiveFermentable(DictFermentableModelKind::Cow(model)) => {
                 let ty = model.as_type();
@@ -4,13 +4,15 @@ mod presentable;
 mod ext;
 mod presentation;
 mod tree;
+mod unwind;
 mod writer;
 
 use std::cell::RefCell;
 use std::fmt::{Display, Formatter};
 use std::path::PathBuf;
 use std::rc::Rc;
-use cargo_metadata::{MetadataCommand, Package, Target};
+use cargo_metadata::{DepKindInfo, DependencyKind, Metadata, MetadataCommand, Node, NodeDep, Package, PackageId, Target, TargetKind};
+use indexmap::{IndexMap, IndexSet};
 use proc_macro2::Ident;
 use quote::format_ident;
 use syn::Attribute;
@@ -21,7 +23,12 @@ use crate::tree::{FileTreeProcessor, ScopeTreeExportItem};
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct Crate {
     pub name: String,
+    /// Directory of the crate root file, the fermentate is written there
     pub root_path: PathBuf,
+    /// Crate root file if it isn't `{root_path}/lib.rs` (e.g. `[lib] path = "src/ffi.rs"`)
+    pub root_file: Option<PathBuf>,
+    /// Enabled features, `None` if they're unknown (see `CfgMode::Evaluate`)
+    pub features: Option<Vec<String>>,
 }
 
 impl Display for Crate {
@@ -30,17 +37,35 @@ impl Display for Crate {
     }
 }
 impl Crate {
+    /// Crate root is taken from the lib target of the package in the current directory (`src/lib.rs` if it's unknown)
     pub fn current_with_name(name: &str) -> Self {
-        Self { name: name.to_string(), root_path: std::path::Path::new("src").to_path_buf() }
+        MetadataCommand::new()
+            .no_deps()
+            .exec()
+            .ok()
+            .and_then(|metadata| metadata.packages.iter()
+                .filter(|package| package.name.replace('-', "_") == name.replace('-', "_"))
+                .find_map(|package| lib_target(&package.targets))
+                .map(|Target { src_path, .. }| Self::with_root_file(name, PathBuf::from(src_path))))
+            .unwrap_or_else(|| Self::new(name, std::path::Path::new("src").to_path_buf()))
     }
     pub fn new(name: &str, root_path: PathBuf) -> Self {
-        Self { name: name.to_string(), root_path }
+        Self { name: name.to_string(), root_path, root_file: None, features: None }
+    }
+    pub fn with_root_file(name: &str, root_file: PathBuf) -> Self {
+        let root_path = root_file.parent().map(std::path::Path::to_path_buf).unwrap_or_default();
+        Self { root_file: Some(root_file), ..Self::new(name, root_path) }
+    }
+    pub fn with_features<I: IntoIterator<Item = S>, S: AsRef<str>>(mut self, features: I) -> Self {
+        self.features = Some(features.into_iter().map(|feature| feature.as_ref().to_string()).collect());
+        self
     }
     pub fn ident(&self) -> Ident {
         format_ident!("{}", self.name)
     }
     pub fn root_path(&self) -> PathBuf {
-        self.root_path.join("lib.rs")
+        self.root_file.clone()
+            .unwrap_or_else(|| self.root_path.join("lib.rs"))
     }
 
     pub fn process(&self, attrs: Vec<Attribute>, context: &Rc<RefCell<GlobalContext>>) -> Result<ScopeTreeExportItem, error::Error> {
@@ -48,20 +73,110 @@ impl Crate {
     }
 }
 
-pub(crate) fn find_crates_paths(crate_names: Vec<&str>) -> Vec<Crate> {
-    MetadataCommand::new()
-        .exec()
-        .map(|metadata| crate_names.into_iter()
-            .filter_map(|crate_name|
-                metadata.packages
-                    .iter()
-                    .find_map(|Package { targets, name, .. }| match targets.first() {
-                        Some(Target { src_path, .. }) if name.as_str() == crate_name =>
-                            src_path.parent().map(|parent_path| Crate::new(name.replace("-", "_").as_str(), PathBuf::from(parent_path))),
-                        _ =>
-                            None
-                    }))
+/// Resolves the external crates with the dependency graph of the current package (see `resolve_crates`),
+/// returns the resolved crates and the errors of the unresolved ones
+pub(crate) fn find_crates_paths(current_crate: &str, crate_names: Vec<&str>) -> (Vec<Crate>, Vec<String>) {
+    match MetadataCommand::new().exec() {
+        Ok(metadata) =>
+            resolve_crates(&metadata, current_crate, &crate_names),
+        Err(err) =>
+            (vec![], crate_names.iter().map(|name| format!("`{name}`: can't read the cargo metadata: {err}")).collect())
+    }
+}
+
+/// Crates are looked up among the dependencies of the current package first: by the names they're used
+/// in the code (renamed dependencies are `{new_name} = { package = "..." }`) or by the package names.
+/// Then among all the packages of the dependency graph, which is ambiguous if it has several versions of the package.
+/// Crates are fermented from their library targets, features are taken from the resolved dependency graph
+pub(crate) fn resolve_crates(metadata: &Metadata, current_crate: &str, crate_names: &[&str]) -> (Vec<Crate>, Vec<String>) {
+    let current_crate = normalize_crate_name(current_crate);
+    let nodes = metadata.resolve.as_ref()
+        .map(|resolve| resolve.nodes.iter().map(|node| (&node.id, node)).collect::<IndexMap<_, _>>())
+        .unwrap_or_default();
+    let current_package = metadata.packages.iter()
+        .find(|Package { name, targets, .. }|
+            normalize_crate_name(name.as_str()) == current_crate || lib_target(targets).is_some_and(|target| normalize_crate_name(&target.name) == current_crate))
+        .or_else(|| metadata.root_package());
+    let dependencies = current_package
+        .and_then(|package| nodes.get(&package.id))
+        .map(|Node { deps, .. }| deps.iter()
+            .filter(|NodeDep { dep_kinds, .. }| dep_kinds.is_empty() || dep_kinds.iter().any(|DepKindInfo { kind, .. }| DependencyKind::Normal.eq(kind)))
+            .collect::<Vec<_>>())
+        .unwrap_or_default();
+    let package_by_id = |id: &PackageId| metadata.packages.iter().find(|package| package.id.eq(id));
+    let mut crates = vec![];
+    let mut errors = vec![];
+    for crate_name in crate_names {
+        let name = normalize_crate_name(crate_name);
+        let dependency = dependencies.iter()
+            .find(|dep| dep.name.as_str() == name)
+            .or_else(|| dependencies.iter().find(|dep| package_by_id(&dep.pkg).is_some_and(|package| normalize_crate_name(package.name.as_str()) == name)));
+        let resolved = match dependency {
+            Some(NodeDep { name, pkg, .. }) =>
+                package_by_id(pkg)
+                    .map(|package| (name.to_string(), package))
+                    .ok_or_else(|| format!("`{crate_name}`: package `{pkg}` isn't in the cargo metadata")),
+            None => {
+                let candidates = metadata.packages.iter()
+                    .filter(|Package { name: package_name, id, .. }| normalize_crate_name(package_name.as_str()) == name && (nodes.is_empty() || nodes.contains_key(id)))
+                    .collect::<Vec<_>>();
+                match candidates.as_slice() {
+                    [] =>
+                        Err(format!("`{crate_name}` isn't a dependency of `{current_crate}`")),
+                    [package] =>
+                        Ok((lib_target(&package.targets).map_or_else(|| name.clone(), |target| normalize_crate_name(&target.name)), *package)),
+                    packages =>
+                        Err(format!("`{crate_name}` is ambiguous: the dependency graph has versions {} of it, add it as a dependency of `{current_crate}`",
+                                    packages.iter().map(|package| package.version.to_string()).collect::<Vec<_>>().join(", ")))
+                }
+            }
+        };
+        match resolved.and_then(|(name, package)| match lib_target(&package.targets) {
+            Some(Target { src_path, .. }) => {
+                let crate_config = Crate::with_root_file(&name, PathBuf::from(src_path));
+                Ok(match nodes.get(&package.id) {
+                    Some(Node { features, .. }) => crate_config.with_features(features.iter().map(ToString::to_string)),
+                    None => crate_config
+                })
+            },
+            None =>
+                Err(format!("`{crate_name}`: package `{} {}` has no library target", package.name, package.version))
+        }) {
+            Ok(crate_config) => crates.push(crate_config),
+            Err(err) => errors.push(err)
+        }
+    }
+    (crates, errors)
+}
+
+/// Crate names are package names with `-` replaced by `_`
+fn normalize_crate_name(name: &str) -> String {
+    name.replace('-', "_")
+}
+
+/// Library target of the package (bins, tests, examples, etc. aren't fermented)
+fn lib_target(targets: &[Target]) -> Option<&Target> {
+    targets.iter()
+        .find(|Target { kind, .. }| kind.iter().any(|kind| matches!(kind, TargetKind::Lib | TargetKind::RLib | TargetKind::DyLib | TargetKind::CDyLib | TargetKind::StaticLib | TargetKind::ProcMacro)))
+}
+
+/// Features enabled for the crate being built, only known inside its build script (`CARGO_FEATURE_*`)
+pub(crate) fn build_script_features() -> Option<Vec<String>> {
+    std::env::var_os("CARGO_MANIFEST_DIR")
+        .and(std::env::var_os("OUT_DIR"))
+        .map(|_| std::env::vars()
+            .filter_map(|(key, _)| key.strip_prefix("CARGO_FEATURE_").map(str::to_lowercase))
             .collect())
-        .unwrap_or_default()
+}
 
+/// Cfg options of the target the crate is built for, only known inside its build script (`CARGO_CFG_*`),
+/// e.g. `target_os = "ios"` is `target_os => {"ios"}`, `unix` is `unix => {""}`
+pub(crate) fn build_script_cfg() -> Option<IndexMap<String, IndexSet<String>>> {
+    std::env::var_os("CARGO_CFG_TARGET_OS")
+        .map(|_| std::env::vars()
+            .filter_map(|(key, value)| key.strip_prefix("CARGO_CFG_")
+                .map(str::to_lowercase)
+                .filter(|name| name != "feature")
+                .map(|name| (name, value.split(',').map(String::from).collect())))
+            .collect())
 }
@@ -1,5 +1,6 @@
 use quote::{quote, ToTokens};
-use syn::{parse_quote, Expr, ExprAssign, ExprCall, ReturnType, Visibility};
+use syn::{parse_quote, Expr, ExprAssign, ExprCall, Path, ReturnType, Visibility};
+use crate::AsyncMode;
 use crate::composer::{SourceComposable, CommaPunctuatedArgs, ConversionDropComposer, ConversionFromComposer};
 use crate::context::ScopeContext;
 use crate::kind::SmartPointerKind;
@@ -63,7 +64,7 @@ impl ScopeContextPresentable for BindingPresentableContext<RustSpecification> {
             },
             Self::RegFn(path, signature_aspect, is_async, arguments, return_type, input_conversions, return_type_conversion) => BindingPresentation::RegularFunction {
                 aspect: signature_aspect.clone(),
-                is_async: *is_async,
+                asyncness: is_async.then(|| async_aspect(source)),
                 arguments: arguments.present(source),
                 name: Name::<RustSpecification>::ModFn(path.clone()).mangle_tokens_default(),
                 input_conversions: input_conversions.present(source),
@@ -72,7 +73,7 @@ impl ScopeContextPresentable for BindingPresentableContext<RustSpecification> {
             },
             Self::RegFn2(path, signature_aspect, is_async, argument_names, arguments, return_type, full_fn_path, input_conversions, return_type_conversion) => BindingPresentation::RegularFunction2 {
                 aspect: signature_aspect.clone(),
-                is_async: *is_async,
+                asyncness: is_async.then(|| async_aspect(source)),
                 argument_names: argument_names.clone(),
                 arguments: arguments.present(source),
                 name: Name::<RustSpecification>::ModFn(path.clone()).mangle_tokens_default(),
@@ -81,11 +82,12 @@ impl ScopeContextPresentable for BindingPresentableContext<RustSpecification> {
                 return_type: return_type.clone(),
                 output_conversions: <<RustSpecification as Specification>::Expr as ScopeContextPresentable>::present(return_type_conversion, source).to_token_stream()
             },
-            Self::TraitVTableInnerFn((attrs, ..), ident, name_and_args, return_type_conversion) => BindingPresentation::TraitVTableInnerFn {
+            Self::TraitVTableInnerFn((attrs, ..), ident, name_and_args, return_type_conversion, nullable) => BindingPresentation::TraitVTableInnerFn {
                 attrs: attrs.clone(),
                 name: Name::<RustSpecification>::VTableInnerFn(ident.clone()).mangle_tokens_default(),
                 name_and_args: present_signature(Visibility::Inherited, WrapIntoRoundBraces::wrap(name_and_args.present(source))),
                 output_expression: return_type_conversion.clone(),
+                nullable: *nullable,
             },
             Self::SmartPointer(aspect, signature_aspect, SmartPointerKind::Mutex(..), SmartPointerPresentableContext::Ctor(ctor_arg_composer, from_arg_conversion)) => {
                 let ty = aspect.present(source);
@@ -495,16 +497,20 @@ impl ScopeContextPresentable for BindingPresentableContext<RustSpecification> {
                     })
                 )
             },
-            Self::ValueByKey(aspect, signature_aspect, map_type, key_var, value_var) => {
+            Self::ValueByKey(aspect, signature_aspect, map_type, key_var, value_var, key_type, value_type) => {
                 let ffi = DictionaryName::Ffi;
                 let ffi_ref = DictionaryName::FfiRef;
                 let key = DictionaryName::Key;
                 let keys = DictionaryName::Keys;
                 let values = DictionaryName::Values;
-                let value_is_primitive = value_var.is_primitive();
+                let value_is_primitive = value_var.is_primitive() || source.is_fieldless_enum(value_type);
                 let get_key = quote!(*#ffi_ref.#keys.add(i));
-                let from_key_conversion = ConversionFromComposer::<RustSpecification>::value_pat_tokens(&key, key_var).compose(source).present(source);
-                let from_key_2_conversion = ConversionFromComposer::<RustSpecification>::value_pat_tokens(&get_key, key_var).compose(source).present(source);
+                let (from_key_conversion, from_key_2_conversion) = if source.is_fieldless_enum(key_type) {
+                    (key.to_token_stream(), get_key.clone())
+                } else {
+                    (ConversionFromComposer::<RustSpecification>::value_pat_tokens(&key, key_var).compose(source).present(source),
+                     ConversionFromComposer::<RustSpecification>::value_pat_tokens(&get_key, key_var).compose(source).present(source))
+                };
                 let get_value = quote!(*#ffi_ref.#values.add(i));
                 let (return_type, return_value_expr) = if value_is_primitive {
                     (value_var.joined_mut(), InterfacesMethodExpr::Boxed(get_value.to_token_stream()).to_token_stream())
@@ -530,7 +536,7 @@ impl ScopeContextPresentable for BindingPresentableContext<RustSpecification> {
                         std::ptr::null_mut()
                     })
             }
-            Self::SetValueForKey(aspect, signature_aspect, map_type, key_type, value_type, _) => {
+            Self::SetValueForKey(aspect, signature_aspect, map_type, key_var, value_var, key_type, value_type) => {
                 let ffi = DictionaryName::Ffi;
                 let ffi_ref = DictionaryName::FfiRef;
                 let key = DictionaryName::Key;
@@ -539,12 +545,21 @@ impl ScopeContextPresentable for BindingPresentableContext<RustSpecification> {
                 let values = DictionaryName::Values;
                 let old_value = DictionaryName::OldValue;
                 let new_value = DictionaryName::NewValue;
-                let from_key_conversion = ConversionFromComposer::<RustSpecification>::value_pat_tokens(&key, key_type).compose(source).present(source);
-                let from_key_2_conversion = ConversionFromComposer::<RustSpecification>::value_pat_tokens(quote!(*#ffi_ref.#keys.add(i)), key_type).compose(source).present(source);
-                let destroy_value = ConversionDropComposer::<RustSpecification>::value(Name::pat_tokens(&old_value), value_type)
-                    .compose(source)
-                    .map(|expr| DictionaryExpr::IfNotNull(old_value.to_token_stream(), expr.present(source).terminated()).to_token_stream())
-                    .unwrap_or_default();
+                let get_key = quote!(*#ffi_ref.#keys.add(i));
+                let (from_key_conversion, from_key_2_conversion) = if source.is_fieldless_enum(key_type) {
+                    (key.to_token_stream(), get_key)
+                } else {
+                    (ConversionFromComposer::<RustSpecification>::value_pat_tokens(&key, key_var).compose(source).present(source),
+                     ConversionFromComposer::<RustSpecification>::value_pat_tokens(get_key, key_var).compose(source).present(source))
+                };
+                let destroy_value = if source.is_fieldless_enum(value_type) {
+                    Default::default()
+                } else {
+                    ConversionDropComposer::<RustSpecification>::value(Name::pat_tokens(&old_value), value_var)
+                        .compose(source)
+                        .map(|expr| DictionaryExpr::IfNotNull(old_value.to_token_stream(), expr.present(source).terminated()).to_token_stream())
+                        .unwrap_or_default()
+                };
 
 
                 BindingPresentation::regular_void_fn_with_body(
@@ -552,8 +567,8 @@ impl ScopeContextPresentable for BindingPresentableContext<RustSpecification> {
                     Name::<RustSpecification>::SetValueForKey(aspect.present(source)),
                     CommaPunctuatedArgs::from_iter([
                         ArgPresentation::no_attr_tokens(quote!(#ffi: *mut #map_type)),
-                        ArgPresentation::no_attr_tokens(quote!(#key: #key_type)),
-                        ArgPresentation::no_attr_tokens(quote!(#value: #value_type)),
+                        ArgPresentation::no_attr_tokens(quote!(#key: #key_var)),
+                        ArgPresentation::no_attr_tokens(quote!(#value: #value_var)),
                     ]),
                     quote! {
                         let #ffi_ref = &*#ffi;
@@ -662,3 +677,8 @@ impl ScopeContextPresentable for BindingPresentableContext<RustSpecification> {
     }
 }
 
+
+fn async_aspect(source: &ScopeContext) -> (AsyncMode, Path) {
+    let context = source.context.borrow();
+    (context.config.async_mode, context.executor.clone())
+}
@@ -216,6 +216,21 @@ impl ScopeContextPresentable for Expression<RustSpecification> {
             Self::CastConversionExprTokens(ConversionAspect { aspect: FFIAspect::To, kind: ConversionExpressionKind::ComplexOpt }, expr, ffi_ty, ty) =>
                 DictionaryExpr::casted_ffi_conversion(DictionaryName::InterfaceTo, FFIConversionToMethod::Opt, ffi_ty, ty, expr).to_token_stream(),
 
+            Self::CastConversionExprTokens(ConversionAspect { aspect: FFIAspect::From, kind: ConversionExpressionKind::Value }, expr, ffi_ty, ty) =>
+                DictionaryExpr::casted_from(ty, ffi_ty, expr).to_token_stream(),
+            Self::CastConversionExprTokens(ConversionAspect { aspect: FFIAspect::To, kind: ConversionExpressionKind::Value }, expr, ffi_ty, ty) =>
+                DictionaryExpr::casted_from(ffi_ty, ty, expr).to_token_stream(),
+            Self::CastConversionExprTokens(ConversionAspect { aspect: FFIAspect::From, kind: ConversionExpressionKind::Scalar }, expr, ..) =>
+                InterfacesMethodExpr::FromScalar(expr.to_token_stream()).to_token_stream(),
+            Self::CastConversionExprTokens(ConversionAspect { aspect: FFIAspect::To, kind: ConversionExpressionKind::Scalar }, expr, ffi_ty, ty) =>
+                DictionaryExpr::casted_from(ffi_ty, ty, expr).to_token_stream(),
+            Self::CastConversionExprTokens(ConversionAspect { aspect: FFIAspect::From, kind: ConversionExpressionKind::ScalarOpt }, expr, ..) =>
+                InterfacesMethodExpr::FromOptScalar(expr.to_token_stream()).to_token_stream(),
+            Self::CastConversionExprTokens(ConversionAspect { aspect: FFIAspect::To, kind: ConversionExpressionKind::ScalarOpt }, expr, ..) =>
+                InterfacesMethodExpr::ToOptScalar(expr.to_token_stream()).to_token_stream(),
+            Self::CastConversionExprTokens(ConversionAspect { aspect: FFIAspect::Drop, kind: ConversionExpressionKind::ScalarOpt }, expr, ..) =>
+                InterfacesMethodExpr::DestroyOptPrimitive(expr.to_token_stream()).to_token_stream(),
+
             Self::CastConversionExprTokens(ConversionAspect { aspect: FFIAspect::Drop, kind: ConversionExpressionKind::Complex }, expr, ..) =>
                 InterfacesMethodExpr::UnboxAny(expr.to_token_stream()).to_token_stream(),
             Self::CastConversionExprTokens(ConversionAspect { aspect: FFIAspect::Drop, kind: ConversionExpressionKind::ComplexOpt }, expr, ..) =>
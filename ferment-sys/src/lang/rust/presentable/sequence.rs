@@ -3,7 +3,7 @@ use syn::__private::TokenStream2;
 use syn::{Path, PathSegment};
 use crate::ast::{Assignment, BraceWrapped, Lambda, ParenWrapped};
 use crate::context::ScopeContext;
-use crate::ext::{LifetimeProcessor, Mangle, Terminated, ToPath, WrapIntoCurlyBraces, WrapIntoRoundBraces};
+use crate::ext::{ArgsTransform, LifetimeProcessor, Mangle, Terminated, ToPath, WrapIntoCurlyBraces, WrapIntoRoundBraces};
 use crate::lang::RustSpecification;
 use crate::presentable::{ScopeContextPresentable, SeqKind};
 use crate::presentation::{present_struct, DictionaryExpr, DictionaryName, InterfacesMethodExpr};
@@ -31,7 +31,8 @@ impl ScopeContextPresentable for SeqKind<RustSpecification> {
             },
             SeqKind::FromNamedFields(((aspect, ..), fields)) |
             SeqKind::ToNamedFields(((aspect, ..), fields)) =>
-                DictionaryExpr::type_destruct(aspect.present(source).lifetimes_cleaned(), fields.present(source))
+                // Arguments of the instantiated generic struct are inferred
+                DictionaryExpr::type_destruct(aspect.present(source).lifetimes_cleaned().arg_less(), fields.present(source))
                     .to_token_stream(),
             SeqKind::TypeAliasFromConversion((_, fields)) => {
                 fields.present(source)
@@ -82,6 +83,7 @@ impl ScopeContextPresentable for SeqKind<RustSpecification> {
                         .map(|PathSegment { ident, .. }| ident.clone())
                         .unwrap_or_else(|| ffi_type.mangle_ident_default()),
                     aspect.attrs(),
+                    true,
                     WrapIntoRoundBraces::wrap(fields.present(source)).terminated()
                 )
             },
@@ -94,14 +96,15 @@ impl ScopeContextPresentable for SeqKind<RustSpecification> {
                         .map(|PathSegment { ident, .. }| ident.clone())
                         .unwrap_or_else(|| ffi_type.mangle_ident_default()),
                     aspect.attrs(),
+                    true,
                     WrapIntoCurlyBraces::wrap(fields.present(source))
                 )
             },
-            SeqKind::Enum(context) => {
+            SeqKind::Enum(repr, derives, context) => {
                 let enum_presentation = context.present(source);
                 quote! {
-                    #[repr(C)]
-                    #[derive(Clone)]
+                    #[repr(#repr)]
+                    #[derive(#derives)]
                     #[non_exhaustive]
                     pub enum #enum_presentation
                 }
@@ -123,16 +126,9 @@ impl ScopeContextPresentable for SeqKind<RustSpecification> {
             SeqKind::NoFieldsConversion(aspect) =>
                 aspect.present(source)
                     .to_token_stream(),
-            SeqKind::EnumUnitFields(((aspect, ..), fields)) => {
-                let path = aspect.present(source).to_path();
-                let left = if let Some(last_segment) = path.segments.last() {
-                    last_segment.ident.to_token_stream()
-                } else {
-                    path.to_token_stream()
-                };
-                Assignment::new(left, fields.present(source))
-                    .to_token_stream()
-            },
+            SeqKind::EnumVariantDiscriminant(variant, expr) =>
+                Assignment::new(variant.present(source), expr)
+                    .to_token_stream(),
             SeqKind::StructFrom(field_context, conversions) => {
                 let conversions = conversions.present(source);
                 let field_path = field_context.present(source);
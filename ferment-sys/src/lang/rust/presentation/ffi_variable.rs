@@ -66,6 +66,10 @@ impl Resolve<FFIVariable<RustSpecification, Type>> for Type {
         let refined = source.maybe_special_or_regular_ffi_full_path::<RustSpecification>(&full_ty)
             .map(|ffi_path| ffi_path.to_type())
             .unwrap_or_else(|| parse_quote!(#self));
+        if Resolve::<SpecialType<RustSpecification>>::maybe_resolve(&full_ty, source).is_none() &&
+            source.is_fieldless_enum(&full_ty) {
+            return FFIVariable::direct(refined);
+        }
         resolve_type_variable(refined, source)
     }
 }
@@ -88,6 +92,8 @@ impl Resolve<FFIVariable<RustSpecification, Type>> for TypeModelKind {
                 FFIVariable::direct(composition.to_type()),
             TypeModelKind::Dictionary(DictTypeModelKind::NonPrimitiveFermentable(DictFermentableModelKind::I128(..) | DictFermentableModelKind::U128(..))) =>
                 FFIVariable::mut_ptr(parse_quote!([u8; 16])),
+            TypeModelKind::Dictionary(DictTypeModelKind::NonPrimitiveFermentable(DictFermentableModelKind::Char(..))) =>
+                FFIVariable::mut_ptr(parse_quote!(u32)),
             TypeModelKind::Dictionary(DictTypeModelKind::NonPrimitiveFermentable(DictFermentableModelKind::SmartPointer(SmartPointerModelKind::Box(TypeModel { ty, .. })))) |
             TypeModelKind::Dictionary(DictTypeModelKind::NonPrimitiveFermentable(DictFermentableModelKind::Cow(TypeModel { ty, .. }))) => match ty.maybe_first_nested_type_ref() {
                 Some(nested_full_ty) =>
@@ -163,6 +169,8 @@ impl Resolve<FFIVariable<RustSpecification, Type>> for Path {
                 Some(FFIVariable::direct(self.to_type()))
             } else if last_ident.is_128_digit() {
                 Some(FFIVariable::mut_ptr(parse_quote!([u8; 16])))
+            } else if last_ident.is_char() {
+                Some(FFIVariable::mut_ptr(parse_quote!(u32)))
             } else if last_ident.is_optional() {
                 arguments.maybe_angle_bracketed_args()
                     .and_then(AngleBracketedGenericArguments::maybe_generic_type)
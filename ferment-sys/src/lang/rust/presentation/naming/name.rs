@@ -43,6 +43,8 @@ impl ToTokens for Name<RustSpecification> {
                 dict_field_expr.to_token_stream(),
             Name::Vtable(trait_name) =>
                 format_ident!("{}_VTable", trait_name).to_token_stream(),
+            Name::TraitProxy(trait_name) =>
+                format_ident!("{}_Proxy", trait_name).to_token_stream(),
             Name::ModFn(path) =>
                 path.mangle_tokens_default(),
             Name::TraitFn(item_name, trait_name) =>
@@ -138,6 +140,8 @@ impl Mangle<MangleDefault> for Name<RustSpecification> {
                 format!("{}_as_{}_destroy", item_name.mangle_ident_default(), trait_name.mangle_ident_default()),
             Name::Vtable(trait_name) =>
                 format!("{}_VTable", trait_name),
+            Name::TraitProxy(trait_name) =>
+                format!("{}_Proxy", trait_name),
             Name::Getter(obj_type, field_name) =>
                 format!("{}_get_{}", obj_type.mangle_ident_default(), field_name.to_string().replace("r#", "")),
             Name::Setter(obj_type, field_name) =>
@@ -1,5 +1,7 @@
 use crate::ast::{Depunctuated, SemiPunctuated};
-use crate::composer::{GenericComposer, SourceAccessible, SourceComposable, SourceFermentable};
+use crate::composer::{GenericComposer, MaybeComposer, SourceAccessible, SourceComposable, SourceFermentable};
+use crate::context::ScopeContext;
+use crate::kind::{GenericTypeKind, MixinKind};
 use crate::lang::RustSpecification;
 use crate::presentation::RustFermentate;
 use crate::tree::{CrateTree, ScopeTree};
@@ -10,13 +12,24 @@ impl SourceFermentable<RustFermentate> for CrateTree {
         let source = self.source_ref();
         let reg_conversions = Depunctuated::from_iter(crates.iter().map(SourceFermentable::<RustFermentate>::ferment));
         let generic_imports = SemiPunctuated::from_iter(imported.iter().cloned());
+        let (refined_mixins, refined_instances) = {
+            let context = source.context.borrow();
+            (context.refined_mixins.clone(), context.refined_instances.clone())
+        };
         let generic_conversions = Depunctuated::from_iter(
-            source.context
-                .borrow()
-                .refined_mixins
+            refined_mixins
                 .iter()
-                .filter_map(|mixin_context| GenericComposer::<RustSpecification>::mixin(mixin_context, self.context()))
-                .flat_map(|composer| composer.borrow().compose(&source)));
+                .filter_map(|mixin_context| match mixin_context.0 {
+                    // Instantiations of the exported generic structs and enums are fermented as the regular items
+                    MixinKind::Generic(GenericTypeKind::AnyOther(ref ty)) if refined_instances.contains_key(ty) => {
+                        let (item, scope) = &refined_instances[ty];
+                        MaybeComposer::<RustSpecification>::maybe_composer(item, scope, &ScopeContext::cell_with(scope.clone(), source.context.clone()))
+                            .map(|composer| Some(composer.ferment()))
+                    },
+                    _ => GenericComposer::<RustSpecification>::mixin(mixin_context, self.context())
+                        .map(|composer| composer.borrow().compose(&source))
+                })
+                .flatten());
 
         RustFermentate::Root {
             mods: Depunctuated::from_iter([
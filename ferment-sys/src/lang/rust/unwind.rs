@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+use proc_macro2::Ident;
+use syn::{parse_quote, Expr, Fields, File, ItemEnum, ItemFn, ItemMod, Path, ReturnType, Type, TypePath, TypePtr};
+use syn::visit::Visit;
+use syn::visit_mut::VisitMut;
+use crate::ext::Join;
+
+/// Wraps the body of every generated `extern "C"` function into `ferment::catch_panic`,
+/// so a panic is turned into the thread-local last error instead of unwinding into C
+pub(crate) struct CatchUnwind {
+    /// Fieldless enums may have no zero discriminant, so their first declared variant is returned instead.
+    /// Keyed by the full path of the enum (`crate::{mod_name}::types::...`)
+    fieldless_enums: HashMap<Path, Ident>,
+    /// Path of the module being visited
+    mod_path: Path,
+}
+
+impl CatchUnwind {
+    pub(crate) fn new(file: &File, mod_name: &str) -> Self {
+        let mod_ident = Ident::new(mod_name, proc_macro2::Span::call_site());
+        let mut catch_unwind = Self { fieldless_enums: HashMap::new(), mod_path: parse_quote!(crate::#mod_ident) };
+        catch_unwind.visit_file(file);
+        catch_unwind
+    }
+
+    /// Values returned after a caught panic. Types other than pointers and fieldless enums
+    /// (primitives mostly) have to implement `Default`: zeroed values would be UB for references,
+    /// fn pointers, `NonNull`, `NonZero*`, etc., so such types fail to compile instead
+    fn fallback_value(&self, output: &ReturnType) -> Expr {
+        match output {
+            ReturnType::Default =>
+                parse_quote!(()),
+            ReturnType::Type(_, ty) => match &**ty {
+                Type::Ptr(TypePtr { mutability: Some(..), .. }) =>
+                    parse_quote!(std::ptr::null_mut()),
+                Type::Ptr(..) =>
+                    parse_quote!(std::ptr::null()),
+                Type::Path(TypePath { qself: None, path }) => match self.maybe_first_variant(path) {
+                    Some(variant) =>
+                        parse_quote!(#path::#variant),
+                    None =>
+                        parse_quote!(<#ty as Default>::default()),
+                },
+                _ =>
+                    parse_quote!(<#ty as Default>::default()),
+            }
+        }
+    }
+
+    /// Paths of the fermentate are either full (`crate::...`) or relative to the current module
+    fn maybe_first_variant(&self, path: &Path) -> Option<&Ident> {
+        match path.segments.first() {
+            Some(segment) if segment.ident == "crate" =>
+                self.fieldless_enums.get(path),
+            _ => {
+                let mut full_path = self.mod_path.clone();
+                full_path.segments.extend(path.segments.iter().cloned());
+                self.fieldless_enums.get(&full_path)
+            }
+        }
+    }
+
+    fn with_mod<F: FnOnce(&mut Self)>(&mut self, ident: &Ident, f: F) {
+        let parent = self.mod_path.clone();
+        self.mod_path = parent.joined(ident);
+        f(self);
+        self.mod_path = parent;
+    }
+}
+
+impl<'ast> Visit<'ast> for CatchUnwind {
+    fn visit_item_enum(&mut self, item_enum: &'ast ItemEnum) {
+        let ItemEnum { ident, variants, .. } = item_enum;
+        if variants.iter().all(|variant| matches!(variant.fields, Fields::Unit)) {
+            if let Some(variant) = variants.first() {
+                self.fieldless_enums.insert(self.mod_path.joined(ident), variant.ident.clone());
+            }
+        }
+    }
+    fn visit_item_mod(&mut self, item_mod: &'ast ItemMod) {
+        self.with_mod(&item_mod.ident, |visitor| syn::visit::visit_item_mod(visitor, item_mod));
+    }
+}
+
+impl VisitMut for CatchUnwind {
+    fn visit_item_fn_mut(&mut self, item_fn: &mut ItemFn) {
+        if item_fn.sig.abi.is_none() {
+            return;
+        }
+        let body = &item_fn.block;
+        let fallback = self.fallback_value(&item_fn.sig.output);
+        *item_fn.block = parse_quote!({ ferment::catch_panic(move || #body, || #fallback) });
+    }
+    fn visit_item_mod_mut(&mut self, item_mod: &mut ItemMod) {
+        let ident = item_mod.ident.clone();
+        self.with_mod(&ident, |visitor| syn::visit_mut::visit_item_mod_mut(visitor, item_mod));
+    }
+}
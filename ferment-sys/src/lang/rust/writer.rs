@@ -1,19 +1,121 @@
-use std::fs::File;
-use std::io::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+use proc_macro2::TokenStream as TokenStream2;
 use quote::ToTokens;
+use syn::{AttrStyle, Item, ItemMod};
+use syn::ext::IdentExt;
+use syn::visit_mut::VisitMut;
 use crate::composer::SourceFermentable;
-use crate::Error;
+use crate::{DiagnosticPolicy, Error, FermentateLayout, PanicPolicy, StaleFile};
 use crate::lang::RustSpecification;
+use crate::lang::rust::unwind::CatchUnwind;
 use crate::presentation::RustFermentate;
 use crate::tree::CrateTree;
-use crate::writer::{CrateTreeWrite, Writer};
+use crate::writer::{read_existing, CrateTreeWrite, Writer};
 
 impl CrateTreeWrite<RustSpecification> for Writer {
     fn write(&self, crate_tree: &CrateTree) -> Result<(), Error> {
-        let mut output = File::create(self.config.expansion_path())
-            .map_err(Error::from)?;
-        let fermentate = SourceFermentable::<RustFermentate>::ferment(crate_tree);
-        output.write_all(fermentate.to_token_stream().to_string().as_bytes())
-            .map_err(Error::from)
+        let fermentate = self.rust_fermentate(crate_tree)?;
+        let files = self.rust_files(fermentate)?;
+        let redundant = self.redundant_rust_path();
+        if redundant.exists() {
+            fs::remove_file(redundant)?;
+        }
+        for (path, contents) in files {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            fs::write(path, contents)?;
+        }
+        Ok(())
     }
 }
+
+impl Writer {
+    pub(crate) fn rust_fermentate(&self, crate_tree: &CrateTree) -> Result<TokenStream2, Error> {
+        let fermentate = SourceFermentable::<RustFermentate>::ferment(crate_tree).to_token_stream();
+        if self.config.diagnostic_policy == DiagnosticPolicy::Deny {
+            let diagnostics = crate_tree.diagnostics();
+            if !diagnostics.is_empty() {
+                return Err(Error::Diagnostics(diagnostics));
+            }
+        }
+        match self.config.panic_policy {
+            PanicPolicy::Propagate =>
+                Ok(fermentate),
+            PanicPolicy::CatchUnwind => {
+                let mut file = syn::parse2::<syn::File>(fermentate)?;
+                CatchUnwind::new(&file, &self.config.mod_name).visit_file_mut(&mut file);
+                Ok(file.to_token_stream())
+            }
+        }
+    }
+
+    /// Compares the Rust fermentate with the files on disk
+    #[allow(unused)]
+    pub(crate) fn check_rust(&self, crate_tree: &CrateTree) -> Result<Vec<StaleFile>, Error> {
+        let fermentate = self.rust_fermentate(crate_tree)?;
+        let mut stale = vec![];
+        for (path, contents) in self.rust_files(fermentate)? {
+            let actual = read_existing(&path)?;
+            stale.extend(StaleFile::rust(path, &contents, actual.as_deref()));
+        }
+        let redundant = self.redundant_rust_path();
+        if let Some(actual) = read_existing(&redundant)? {
+            stale.push(StaleFile::redundant(redundant, &actual));
+        }
+        Ok(stale)
+    }
+
+    /// `src/{mod_name}.rs` and `src/{mod_name}/mod.rs` can't coexist, so the one of the other layout is removed
+    fn redundant_rust_path(&self) -> PathBuf {
+        match self.config.layout {
+            FermentateLayout::Compact | FermentateLayout::Pretty => self.config.expansion_dir().join("mod.rs"),
+            FermentateLayout::Modules => self.config.expansion_path(),
+        }
+    }
+
+    /// Lays out the fermentate according to `FermentateLayout`: returns the files to write with their contents
+    pub(crate) fn rust_files(&self, fermentate: TokenStream2) -> Result<Vec<(PathBuf, String)>, Error> {
+        match self.config.layout {
+            FermentateLayout::Compact =>
+                Ok(vec![(self.config.expansion_path(), fermentate.to_string())]),
+            FermentateLayout::Pretty =>
+                Ok(vec![(self.config.expansion_path(), prettyplease::unparse(&syn::parse2(fermentate)?))]),
+            FermentateLayout::Modules => {
+                let mut files = Vec::new();
+                split_modules(syn::parse2(fermentate)?, self.config.expansion_dir().join("mod.rs"), &mut files);
+                Ok(files
+                    .into_iter()
+                    .map(|(path, file)| (path, prettyplease::unparse(&file)))
+                    .collect())
+            }
+        }
+    }
+}
+
+/// Moves the content of every inline module of the file into its own file:
+/// `name.rs` for a leaf module and `name/mod.rs` for a module with nested modules
+fn split_modules(mut file: syn::File, path: PathBuf, files: &mut Vec<(PathBuf, syn::File)>) {
+    let dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+    let mut nested_files = Vec::new();
+    for item in file.items.iter_mut() {
+        if let Item::Mod(item_mod @ ItemMod { content: Some(..), .. }) = item {
+            let (_, items) = item_mod.content.take().unwrap();
+            let (attrs, outer_attrs) = item_mod.attrs.drain(..)
+                .partition(|attr| matches!(attr.style, AttrStyle::Inner(..)));
+            item_mod.attrs = outer_attrs;
+            item_mod.semi = Some(Default::default());
+            let name = item_mod.ident.unraw().to_string();
+            let has_nested_modules = items.iter().any(|item| matches!(item, Item::Mod(ItemMod { content: Some(..), .. })));
+            let mod_path = if has_nested_modules {
+                dir.join(&name).join("mod.rs")
+            } else {
+                dir.join(format!("{name}.rs"))
+            };
+            split_modules(syn::File { shebang: None, attrs, items }, mod_path, &mut nested_files);
+        }
+    }
+    files.push((path, file));
+    files.extend(nested_files);
+}
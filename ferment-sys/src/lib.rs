@@ -18,11 +18,15 @@ mod lang;
 mod config;
 pub(crate) mod writer;
 mod cbindgen;
+mod staleness;
+mod diagnostic;
 
 pub use self::error::Error;
 pub use self::builder::Builder;
-pub use self::config::Config;
+pub use self::config::{AsyncMode, CfgMode, Config, DiagnosticPolicy, FermentateLayout, PanicPolicy};
+pub use self::diagnostic::Diagnostic;
 pub use self::lang::{Lang, rust::Crate};
+pub use self::staleness::StaleFile;
 pub use self::builder::Builder as Ferment;
 
 #[cfg(feature = "objc")]
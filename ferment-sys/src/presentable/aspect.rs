@@ -5,7 +5,7 @@ use crate::composable::FnSignatureContext;
 use crate::composer::{AspectArgComposers, AttrComposable, ComposerLinkRef, FieldsContext, GenericsComposable, LifetimesComposable, NameKindComposable, TypeAspect};
 use crate::context::ScopeContext;
 use crate::kind::{GenericTypeKind, MixinKind};
-use crate::ext::{Accessory, Join, LifetimeProcessor, Mangle, Resolve, ResolveTrait, ToType};
+use crate::ext::{Accessory, ArgsTransform, Join, Mangle, Resolve, ResolveTrait, ToType};
 use crate::lang::Specification;
 use crate::presentable::{TypeContext, ScopeContextPresentable, NameTreeContext};
 
@@ -68,8 +68,9 @@ impl ScopeContextPresentable for Aspect<TypeContext> {
                               TypeContext::Struct { ident, .. }) =>
                 Resolve::<Type>::resolve(ident, source),
             Aspect::Target(TypeContext::EnumVariant { ident, variant_ident, .. }) =>
+                // Arguments of the instantiated generic enum are inferred
                 Resolve::<Type>::resolve(ident, source)
-                    .lifetimes_cleaned()
+                    .arg_less()
                     .joined_ident(variant_ident),
             Aspect::Target(TypeContext::Trait { path, .. } | TypeContext::Impl { path, .. }) |
             Aspect::RawTarget(TypeContext::Impl { trait_: Some(path), .. } | TypeContext::Impl { path, .. }) =>
@@ -112,7 +113,7 @@ impl ScopeContextPresentable for Aspect<TypeContext> {
                     .mangle_ident_default()
                     .to_type()
                     .joined_ident(variant_ident),
-            Aspect::Ffi(TypeContext::Fn { sig_context: FnSignatureContext::TraitInner(_, self_ty, _), .. }) =>
+            Aspect::Ffi(TypeContext::Fn { sig_context: FnSignatureContext::TraitInner(_, self_ty, ..), .. }) =>
                 Resolve::<Type>::resolve(self_ty, source)
                     .mangle_ident_default()
                     .to_type(),
@@ -126,6 +127,7 @@ impl ScopeContextPresentable for Aspect<TypeContext> {
                     }).unwrap_or_else(|| path.to_type()),
             Aspect::RawTarget(TypeContext::EnumVariant { ident, variant_ident, .. }) =>
                 Resolve::<Type>::resolve(ident, source)
+                    .arg_less()
                     .joined_ident(variant_ident),
         }
     }
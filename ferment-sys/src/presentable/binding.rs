@@ -29,13 +29,13 @@ pub enum BindingPresentableContext<SPEC>
 
     SmartPointer(Aspect<SPEC::TYC>, SignatureAspect<SPEC>, SmartPointerKind, SmartPointerPresentableContext<SPEC>),
 
-    TraitVTableInnerFn(SignatureAspect<SPEC>, Ident, CommaPunctuatedArgKinds<SPEC>, ReturnType),
+    TraitVTableInnerFn(SignatureAspect<SPEC>, Ident, CommaPunctuatedArgKinds<SPEC>, ReturnType, bool),
 
     ArrayGetAtIndex(Aspect<SPEC::TYC>, SignatureAspect<SPEC>, Type, Type),
     ArraySetAtIndex(Aspect<SPEC::TYC>, SignatureAspect<SPEC>, Type, Type),
 
-    ValueByKey(Aspect<SPEC::TYC>, SignatureAspect<SPEC>, Type, Type, Type),
-    SetValueForKey(Aspect<SPEC::TYC>, SignatureAspect<SPEC>, Type, Type, Type, Type),
+    ValueByKey(Aspect<SPEC::TYC>, SignatureAspect<SPEC>, Type, Type, Type, Type, Type),
+    SetValueForKey(Aspect<SPEC::TYC>, SignatureAspect<SPEC>, Type, Type, Type, Type, Type),
     KeyByValue(Aspect<SPEC::TYC>, SignatureAspect<SPEC>, Type, Type, Type),
     SetKeyForValue(Aspect<SPEC::TYC>, SignatureAspect<SPEC>, Type, Type, Type, Type),
     ResultOk(SignatureAspect<SPEC>, Type, SPEC::Var),
@@ -104,9 +104,9 @@ impl<SPEC> BindingPresentableContext<SPEC>
         Type,
         Type,
         Type,
-    )) -> Self {
-        let (obj_type, signature_context, map_type, key_type, value_type) = context;
-        Self::ValueByKey(obj_type, signature_context, map_type, key_type, value_type)
+    ), key_type: Type, value_type: Type) -> Self {
+        let (obj_type, signature_context, map_type, key_var, value_var) = context;
+        Self::ValueByKey(obj_type, signature_context, map_type, key_var, value_var, key_type, value_type)
     }
     pub fn set_key_for_value(context: (
         Aspect<<SPEC as Specification>::TYC>,
@@ -124,9 +124,9 @@ impl<SPEC> BindingPresentableContext<SPEC>
         Type,
         Type,
         Type,
-    ), value_type: Type) -> Self {
+    ), key_type: Type, value_type: Type) -> Self {
         let (obj_type, signature_context, map_type, key_var, value_var) = context;
-        Self::SetValueForKey(obj_type, signature_context, map_type, key_var, value_var, value_type)
+        Self::SetValueForKey(obj_type, signature_context, map_type, key_var, value_var, key_type, value_type)
     }
 
     pub fn ctor_result_ok(context: (
@@ -116,6 +116,12 @@ pub enum ConversionExpressionKind {
     PrimitiveOpt,
     Complex,
     ComplexOpt,
+    /// Fieldless enums are passed by value and converted with `From`
+    Value,
+    /// `char` is passed by value as the Unicode scalar value (`u32`)
+    Scalar,
+    /// `Option<char>` is passed as a nullable pointer to the Unicode scalar value (`*mut u32`)
+    ScalarOpt,
     OpaqueOpt,
     PrimitiveGroup,
     PrimitiveOptGroup,
@@ -1,7 +1,7 @@
 use std::fmt::Debug;
 use proc_macro2::Ident;
 use quote::ToTokens;
-use syn::Type;
+use syn::{Expr, Type};
 use ferment_macro::Display;
 use crate::ast::CommaPunctuated;
 use crate::composer::{AspectCommaPunctuatedArgKinds, AttrComposable, TypeAspect, VariantComposable, FieldsConversionComposable, SourceComposable, ComposerLinkRef, AspectTerminatedArgKinds, AspectPresentableArgKinds, CommaPunctuatedArgKinds};
@@ -21,7 +21,7 @@ pub enum SeqKind<SPEC>
     ToStub(AspectCommaPunctuatedArgKinds<SPEC>),
     NamedVariantFields(AspectCommaPunctuatedArgKinds<SPEC>),
     UnnamedVariantFields(AspectCommaPunctuatedArgKinds<SPEC>),
-    EnumUnitFields(AspectCommaPunctuatedArgKinds<SPEC>),
+    EnumVariantDiscriminant(Box<SeqKind<SPEC>>, Expr),
 
     Variants(Aspect<SPEC::TYC>, SPEC::Attr, CommaPunctuated<SeqKind<SPEC>>),
     Unit(Aspect<SPEC::TYC>),
@@ -30,7 +30,8 @@ pub enum SeqKind<SPEC>
     NamedStruct(AspectCommaPunctuatedArgKinds<SPEC>),
     UnnamedStruct(AspectCommaPunctuatedArgKinds<SPEC>),
     StubStruct(AspectCommaPunctuatedArgKinds<SPEC>),
-    Enum(Box<SeqKind<SPEC>>),
+    /// FFI enum with its repr, derives and variants
+    Enum(CommaPunctuated<Ident>, CommaPunctuated<Ident>, Box<SeqKind<SPEC>>),
 
     StructFrom(Box<SeqKind<SPEC>>, Box<SeqKind<SPEC>>),
     StructTo(Box<SeqKind<SPEC>>, Box<SeqKind<SPEC>>),
@@ -91,8 +92,8 @@ impl<SPEC> SeqKind<SPEC>
     pub fn obj<C>(_ctx: &ComposerLinkRef<C>) -> Self {
         Self::Obj
     }
-    pub fn unit_fields(context: &AspectCommaPunctuatedArgKinds<SPEC>) -> Self {
-        Self::EnumUnitFields(context.clone())
+    pub fn discriminant(variant: SeqKind<SPEC>, expr: &Expr) -> Self {
+        Self::EnumVariantDiscriminant(variant.into(), expr.clone())
     }
     pub fn brace_variants(context: &AspectCommaPunctuatedArgKinds<SPEC>) -> Self {
         Self::NamedVariantFields(context.clone())
@@ -106,8 +107,9 @@ impl<SPEC> SeqKind<SPEC>
     pub fn bypass(sequence: SeqKind<SPEC>) -> Self {
         sequence
     }
-    pub fn r#enum(context: SeqKind<SPEC>) -> Self {
-        Self::Enum(Box::new(context))
+    pub fn r#enum<C>(composer_ref: &ComposerLinkRef<C>) -> Self
+        where C: AttrComposable<SPEC::Attr> + TypeAspect<SPEC::TYC> + VariantComposable<SPEC> {
+        Self::Enum(C::compose_repr(composer_ref), C::compose_derives(composer_ref), Box::new(Self::variants(composer_ref)))
     }
     pub fn fields_from<C>(ctx: &ComposerLinkRef<C>) -> Self
         where C: FieldsConversionComposable<SPEC> + 'static {
@@ -39,6 +39,8 @@ impl ToTokens for ArgPresentation {
         match self {
             Self::Arm(arm) => arm.to_tokens(tokens),
             Self::Field(field) => field.to_tokens(tokens),
+            // An empty expression (i.e. no destructor for the field) has nothing to put the attributes on
+            Self::AttrTokens(_, expr) if expr.is_empty() => {}
             Self::AttrTokens(attr, expr) => {
                 quote!(#(#attr)*) .to_tokens(tokens);
                 expr.to_tokens(tokens);
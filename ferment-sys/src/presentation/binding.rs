@@ -1,9 +1,10 @@
 use proc_macro2::{Ident, TokenStream as TokenStream2};
 use quote::{format_ident, quote, ToTokens};
-use syn::{Attribute, BareFnArg, Generics, parse_quote, ReturnType, Type, Visibility};
+use syn::{Attribute, BareFnArg, Generics, parse_quote, Path, ReturnType, Type, Visibility};
 use crate::ast::{CommaPunctuated, CommaPunctuatedTokens, Depunctuated};
 use crate::composer::{CommaPunctuatedArgs, SemiPunctuatedArgs, SignatureAspect};
 use crate::ext::{Accessory, ArgsTransform, Mangle, Pop, PunctuateOne, Terminated, ToPath, ToType};
+use crate::AsyncMode;
 use crate::lang::RustSpecification;
 use crate::presentation::{ArgPresentation, DictionaryName, InterfacePresentation, InterfacesMethodExpr, Name};
 
@@ -74,7 +75,7 @@ pub enum BindingPresentation {
     RegularFunction {
         aspect: SignatureAspect<RustSpecification>,
         name: TokenStream2,
-        is_async: bool,
+        asyncness: Option<(AsyncMode, Path)>,
         arguments: CommaPunctuatedArgs,
         input_conversions: TokenStream2,
         return_type: ReturnType,
@@ -90,7 +91,7 @@ pub enum BindingPresentation {
     RegularFunction2 {
         aspect: SignatureAspect<RustSpecification>,
         name: TokenStream2,
-        is_async: bool,
+        asyncness: Option<(AsyncMode, Path)>,
         argument_names: CommaPunctuatedTokens,
         arguments: CommaPunctuatedArgs,
         full_fn_path: Type,
@@ -111,10 +112,13 @@ pub enum BindingPresentation {
         name: TokenStream2,
         name_and_args: TokenStream2,
         output_expression: ReturnType,
+        /// Methods with default implementations may be missing in the vtables of the foreign implementors
+        nullable: bool,
     },
     StaticVTableInnerFnDeclaration {
         name: TokenStream2,
-        fn_name: Ident
+        fn_name: Ident,
+        nullable: bool
     },
     StaticVTableInnerFn {
         aspect: SignatureAspect<RustSpecification>,
@@ -200,18 +204,112 @@ pub fn present_function<T: ToTokens, N: ToTokens>(
     }
 }
 
+fn runtime_arg() -> ArgPresentation {
+    ArgPresentation::Field(crate::ast::inherited_named_field(format_ident!("runtime"), parse_quote!(*const std::os::raw::c_void)))
+}
+
+/// `AsyncMode::Blocking`: the calling thread is blocked on the supplied executor until the future is resolved
+fn present_blocking_function<N: ToTokens>(
+    aspect: &SignatureAspect<RustSpecification>,
+    executor: &Path,
+    name: N,
+    arguments: &CommaPunctuatedArgs,
+    return_type: &ReturnType,
+    future: TokenStream2,
+    output_conversions: &TokenStream2
+) -> TokenStream2 {
+    let mut args = runtime_arg().punctuate_one();
+    args.extend(arguments.clone());
+    present_pub_function(
+        aspect,
+        name,
+        args,
+        return_type.clone(),
+        quote! {
+            let executor = &*(runtime as *const #executor);
+            let obj = ferment::Executor::block_on(executor, async { #future });
+            #output_conversions
+        }
+    )
+}
+
+/// `AsyncMode::Callback`: the future is spawned onto the supplied executor and the function returns immediately.
+/// The arguments are converted before returning, so the future owns the converted values (and it should be `Send`).
+/// The converted result (or a task error) is passed to the generated `<name>_Completion` on an executor thread,
+/// the returned `ferment::CancellationHandle` allows to cancel the task
+fn present_spawning_function<N: ToTokens>(
+    aspect: &SignatureAspect<RustSpecification>,
+    executor: &Path,
+    name: N,
+    arguments: &CommaPunctuatedArgs,
+    return_type: &ReturnType,
+    call: TokenStream2,
+    output_conversions: &TokenStream2
+) -> TokenStream2 {
+    let (attrs, ..) = aspect;
+    let completion_name = format_ident!("{}_Completion", name.to_token_stream().to_string());
+    let (on_result_signature, on_result) = match return_type {
+        ReturnType::Default => (
+            present_signature(TokenStream2::default(), quote!((context: *const std::os::raw::c_void))),
+            quote!(Ok(_) => (completion.on_result)(completion.context))
+        ),
+        ReturnType::Type(_, ty) => (
+            present_signature(TokenStream2::default(), quote!((context: *const std::os::raw::c_void, result: #ty))),
+            quote!(Ok(result) => (completion.on_result)(completion.context, result))
+        )
+    };
+    let on_error_signature = present_signature(TokenStream2::default(), quote!((context: *const std::os::raw::c_void, error: *mut std::os::raw::c_char)));
+    let completion = present_struct(&completion_name, attrs, true, quote!({
+        pub context: *const std::os::raw::c_void,
+        pub on_result: #on_result_signature,
+        pub on_error: #on_error_signature,
+    }));
+    let mut args = CommaPunctuated::from_iter([runtime_arg(), ArgPresentation::Field(crate::ast::inherited_named_field(format_ident!("completion"), parse_quote!(#completion_name)))]);
+    args.extend(arguments.clone());
+    let function = present_pub_function(
+        aspect,
+        name,
+        args,
+        parse_quote!(-> *mut ferment::CancellationHandle),
+        quote! {
+            let executor = &*(runtime as *const #executor);
+            let future = #call;
+            let (task, handle) = ferment::Task::new(async move {
+                let obj = future.await;
+                #output_conversions
+            });
+            ferment::Executor::spawn(executor, async move {
+                let completion = completion;
+                match task.await {
+                    #on_result,
+                    Err(err) => (completion.on_error)(completion.context, <std::os::raw::c_char as ferment::FFIConversionTo<String>>::ffi_to(err))
+                }
+            });
+            ferment::boxed(handle)
+        }
+    );
+    quote! {
+        #completion
+        unsafe impl Send for #completion_name {}
+        #function
+    }
+}
+
 pub fn present_signature<A: ToTokens, S: ToTokens>(acc: A, signature: S) -> TokenStream2 {
     quote!(#acc unsafe extern "C" fn #signature)
 }
 
+/// The types owning a foreign resource (like the context of a callback) aren't `Clone`
 pub fn present_struct<Name: ToTokens, Impl: ToTokens>(
     name: Name,
     attrs: &Vec<Attribute>,
+    cloneable: bool,
     implementation: Impl
 ) -> TokenStream2 {
+    let derive_clone = cloneable.then(|| quote!(#[derive(Clone)]));
     quote! {
         #[repr(C)]
-        #[derive(Clone)]
+        #derive_clone
         #(#attrs)*
         pub struct #name #implementation
     }
@@ -287,24 +385,27 @@ impl ToTokens for BindingPresentation {
                     CommaPunctuated::from_iter([quote!(obj: #obj_var), quote!(value: #field_type)]),
                     ReturnType::Default,
                     quote!((*obj).#field_name = value;)),
-            Self::RegularFunction { aspect, is_async: true, name, arguments, input_conversions, return_type, output_conversions } => {
-                let mut args = ArgPresentation::Field(crate::ast::inherited_named_field(format_ident!("runtime"), parse_quote!(*const std::os::raw::c_void))).punctuate_one();
-                args.extend(arguments.clone());
-                present_pub_function(
+            Self::RegularFunction { aspect, asyncness: Some((AsyncMode::Blocking, executor)), name, arguments, input_conversions, return_type, output_conversions } =>
+                present_blocking_function(
                     aspect,
+                    executor,
                     name,
-                    args,
-                    return_type.clone(),
-                    quote! {
-                        let rt = &*(runtime as *const tokio::runtime::Runtime);
-                        let obj = rt.block_on(async {
-                            #input_conversions .await
-                        });
-                        #output_conversions
-                    }
-                )
-            },
-            Self::RegularFunction { aspect, is_async: false, name, arguments, input_conversions, return_type, output_conversions } =>
+                    arguments,
+                    return_type,
+                    quote!(#input_conversions .await),
+                    output_conversions
+                ),
+            Self::RegularFunction { aspect, asyncness: Some((AsyncMode::Callback, executor)), name, arguments, input_conversions, return_type, output_conversions } =>
+                present_spawning_function(
+                    aspect,
+                    executor,
+                    name,
+                    arguments,
+                    return_type,
+                    input_conversions.clone(),
+                    output_conversions
+                ),
+            Self::RegularFunction { aspect, asyncness: None, name, arguments, input_conversions, return_type, output_conversions } =>
                 present_pub_function(
                     aspect,
                     name,
@@ -320,35 +421,27 @@ impl ToTokens for BindingPresentation {
                     return_type.clone(),
                     body.to_token_stream()
                 ),
-            Self::RegularFunction2 { aspect, is_async: true, name, argument_names, arguments, full_fn_path, input_conversions, return_type, output_conversions } => {
-                let mut args = ArgPresentation::Field(crate::ast::inherited_named_field(format_ident!("runtime"), parse_quote!(*const std::os::raw::c_void))).punctuate_one();
-                args.extend(arguments.clone());
-                present_pub_function(
+            Self::RegularFunction2 { aspect, asyncness: Some((AsyncMode::Blocking, executor)), name, argument_names, arguments, full_fn_path, input_conversions, return_type, output_conversions } =>
+                present_blocking_function(
                     aspect,
+                    executor,
                     name,
-                    args.clone(),
-                    return_type.clone(),
-                    quote! {
-                        let rt = unsafe { &*(runtime as *const tokio::runtime::Runtime) };
-                        #input_conversions;
-                        let obj = rt.block_on(async {
-                            #full_fn_path(#argument_names).await
-                        });
-                        #output_conversions
-                    }
-                    // quote! {
-                    //     let rt = unsafe { &*(runtime as *mut tokio::runtime::Runtime) };
-                    //     #input_conversions;
-                    //     let obj = rt.block_on(tokio::task::spawn_blocking(move || {
-                    //         tokio::runtime::Handle::current().block_on(async {
-                    //             #full_fn_path(#argument_names).await
-                    //         })
-                    //     })).unwrap();
-                    //     #output_conversions
-                    // }
-                )
-            },
-            Self::RegularFunction2 { aspect, is_async: false, name, argument_names, arguments, full_fn_path, input_conversions, return_type, output_conversions } =>
+                    arguments,
+                    return_type,
+                    quote!({ #input_conversions; #full_fn_path(#argument_names).await }),
+                    output_conversions
+                ),
+            Self::RegularFunction2 { aspect, asyncness: Some((AsyncMode::Callback, executor)), name, argument_names, arguments, full_fn_path, input_conversions, return_type, output_conversions } =>
+                present_spawning_function(
+                    aspect,
+                    executor,
+                    name,
+                    arguments,
+                    return_type,
+                    quote!({ #input_conversions; #full_fn_path(#argument_names) }),
+                    output_conversions
+                ),
+            Self::RegularFunction2 { aspect, asyncness: None, name, argument_names, arguments, full_fn_path, input_conversions, return_type, output_conversions } =>
                 present_pub_function(
                     aspect,
                     name,
@@ -370,7 +463,7 @@ impl ToTokens for BindingPresentation {
                 };
                 let caller_signature = present_signature(TokenStream2::default(), quote!((#ffi_args) #result_impl));
                 let implementation = quote!({ caller: #caller_signature, });
-                let definition = present_struct(name, attrs, implementation);
+                let definition = present_struct(name, attrs, true, implementation);
                 quote! {
                     #definition
                     #conversion
@@ -385,12 +478,16 @@ impl ToTokens for BindingPresentation {
                 };
                 #bindings
             },
-            Self::TraitVTableInnerFn { attrs, name, name_and_args, output_expression } =>
+            Self::TraitVTableInnerFn { attrs, name, name_and_args, output_expression, nullable: false } =>
                 quote!(#(#attrs)* pub #name: #name_and_args #output_expression),
+            Self::TraitVTableInnerFn { attrs, name, name_and_args, output_expression, nullable: true } =>
+                quote!(#(#attrs)* pub #name: Option<#name_and_args #output_expression>),
             Self::StaticVTableInnerFn { aspect, name, args, output, body } =>
                 present_function(Visibility::Inherited, aspect, name, args.clone(), output.clone(), body.clone()),
-            Self::StaticVTableInnerFnDeclaration { name, fn_name } =>
+            Self::StaticVTableInnerFnDeclaration { name, fn_name, nullable: false } =>
                 quote!(#fn_name: #name),
+            Self::StaticVTableInnerFnDeclaration { name, fn_name, nullable: true } =>
+                quote!(#fn_name: Some(#name)),
             Self::Any { attrs, body } =>
                 quote!(#(#attrs)* #body)
 
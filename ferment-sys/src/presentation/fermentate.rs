@@ -63,6 +63,10 @@ pub enum RustFermentate {
         comment: DocPresentation,
         vtable: FFIObjectPresentation,
         trait_object: FFIObjectPresentation,
+        proxy: FFIObjectPresentation,
+        proxy_defaults: FFIObjectPresentation,
+        proxy_interfaces: Depunctuated<InterfacePresentation>,
+        proxy_bindings: Depunctuated<BindingPresentation>,
     },
     TraitVTable {
         vtable: BindingPresentation,
@@ -135,10 +139,14 @@ impl ToTokens for RustFermentate {
             },
             Self::Mod { attrs, name, imports , conversions } =>
                 quote!(#(#attrs)* pub mod #name { #imports #conversions }).to_tokens(tokens),
-            Self::Trait { comment, vtable, trait_object } => {
+            Self::Trait { comment, vtable, trait_object, proxy, proxy_defaults, proxy_interfaces, proxy_bindings } => {
                 comment.to_tokens(tokens);
                 trait_object.to_tokens(tokens);
                 vtable.to_tokens(tokens);
+                proxy.to_tokens(tokens);
+                proxy_defaults.to_tokens(tokens);
+                proxy_interfaces.to_tokens(tokens);
+                proxy_bindings.to_tokens(tokens);
             },
             // Self::CrateTree(tree) =>
             //     <CrateTree as SourceFermentable<RustFermentate>>::ferment(tree)
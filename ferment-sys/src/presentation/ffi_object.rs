@@ -15,6 +15,11 @@ pub enum FFIObjectPresentation {
         attrs: Vec<Attribute>,
         fields: TokenStream2
     },
+    TraitProxy {
+        name: Path,
+        attrs: Vec<Attribute>,
+        fields: TokenStream2
+    },
     Full(TokenStream2),
     Empty,
 }
@@ -28,7 +33,11 @@ impl ToTokens for FFIObjectPresentation {
                 presentation.to_tokens(tokens),
             Self::TraitVTable { name: Path { segments, .. }, attrs, fields } |
             Self::TraitObject { name: Path { segments, .. }, attrs, fields } => if let Some(PathSegment { ident, .. }) = segments.last() {
-                present_struct(ident, attrs, fields).to_tokens(tokens)
+                present_struct(ident, attrs, true, fields).to_tokens(tokens)
+            },
+            // The proxy owns the foreign context, so it can't be cloned
+            Self::TraitProxy { name: Path { segments, .. }, attrs, fields } => if let Some(PathSegment { ident, .. }) = segments.last() {
+                present_struct(ident, attrs, false, fields).to_tokens(tokens)
             },
         }
     }
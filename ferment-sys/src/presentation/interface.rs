@@ -1,5 +1,5 @@
-use quote::{quote, ToTokens};
-use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote, ToTokens};
+use proc_macro2::{Ident, TokenStream as TokenStream2};
 use syn::{Attribute, GenericParam, Generics, Lifetime, LifetimeParam, ReturnType, Type};
 use crate::ast::{CommaPunctuated, CommaPunctuatedTokens};
 use crate::composer::{CommaPunctuatedArgs, TypePair};
@@ -49,14 +49,29 @@ pub enum InterfacePresentation {
         output: ReturnType,
         body: TokenStream2,
     },
-    SendAndSync {
+    Markers {
         attrs: Vec<Attribute>,
         ffi_type: Type,
+        markers: Vec<Ident>,
     },
     Drop {
         attrs: Vec<Attribute>,
         ty: Type,
         body: TokenStream2
+    },
+    From {
+        attrs: Vec<Attribute>,
+        types: (
+            Type, // From
+            Type // Into
+        ),
+        body: TokenStream2
+    },
+    TraitImpl {
+        attrs: Vec<Attribute>,
+        trait_ty: Type,
+        ty: Type,
+        methods: TokenStream2
     }
 }
 
@@ -101,6 +116,15 @@ impl InterfacePresentation {
     pub fn drop<T: ToTokens>(attrs: &[Attribute], ty: Type, body: T) -> Self {
         InterfacePresentation::Drop { attrs: attrs.to_owned(), ty, body: body.to_token_stream() }
     }
+    /// `impl From<from_ty> for ty`: the body converts the `value`
+    pub fn from<T: ToTokens>(attrs: &[Attribute], from_ty: Type, ty: Type, body: T) -> Self {
+        InterfacePresentation::From { attrs: attrs.to_owned(), types: (from_ty, ty), body: body.to_token_stream() }
+    }
+
+    /// `impl trait_ty for ty`
+    pub fn trait_impl<T: ToTokens>(attrs: &[Attribute], trait_ty: Type, ty: Type, methods: T) -> Self {
+        InterfacePresentation::TraitImpl { attrs: attrs.to_owned(), trait_ty, ty, methods: methods.to_token_stream() }
+    }
 
     pub fn callback<T: ToTokens, U: ToTokens>(attrs: &[Attribute], lifetimes: &[Lifetime], ffi_type: Type, inputs: CommaPunctuatedArgs, output: ReturnType, args_conversions: T, result_conversion: U) -> Self {
         InterfacePresentation::Callback {
@@ -113,7 +137,10 @@ impl InterfacePresentation {
         }
     }
     pub fn send_sync(attrs: &[Attribute], ffi_type: &Type) -> Self {
-        InterfacePresentation::SendAndSync { attrs: attrs.to_owned(), ffi_type: ffi_type.clone() }
+        InterfacePresentation::markers(attrs, ffi_type, vec![format_ident!("Send"), format_ident!("Sync")])
+    }
+    pub fn markers(attrs: &[Attribute], ffi_type: &Type, markers: Vec<Ident>) -> Self {
+        InterfacePresentation::Markers { attrs: attrs.to_owned(), ffi_type: ffi_type.clone(), markers }
     }
 }
 
@@ -230,17 +257,24 @@ impl ToTokens for InterfacePresentation {
 
                 }
             }
-            Self::SendAndSync { attrs, ffi_type } => {
-                quote! {
+            Self::Markers { attrs, ffi_type, markers } => {
+                let impls = markers.iter().map(|marker| quote! {
                     #(#attrs)*
-                    unsafe impl Send for #ffi_type {}
-                    #(#attrs)*
-                    unsafe impl Sync for #ffi_type {}
-                }
+                    unsafe impl #marker for #ffi_type {}
+                });
+                quote!(#(#impls)*)
             },
             Self::Drop { attrs, ty, body } => quote! {
                 #(#attrs)*
                 impl Drop for #ty { fn drop(&mut self) { unsafe { #body; } } }
+            },
+            Self::From { attrs, types: (from_ty, ty), body } => quote! {
+                #(#attrs)*
+                impl From<#from_ty> for #ty { fn from(value: #from_ty) -> Self { #body } }
+            },
+            Self::TraitImpl { attrs, trait_ty, ty, methods } => quote! {
+                #(#attrs)*
+                impl #trait_ty for #ty { #methods }
             }
         }.to_tokens(tokens)
     }
@@ -62,6 +62,7 @@ pub enum DictionaryExpr {
     CallbackCaller(TokenStream2, TokenStream2),
     CallbackDestructor(TokenStream2, TokenStream2),
     CastedFFIConversion(DictionaryName, TokenStream2, TokenStream2, TokenStream2, TokenStream2),
+    CastedFrom(TokenStream2, TokenStream2, TokenStream2),
     Clone(TokenStream2),
     FromPtrRead(TokenStream2),
     FromArc(TokenStream2),
@@ -111,6 +112,9 @@ impl DictionaryExpr {
     pub fn casted_ffi_conversion_from_opt<T: ToTokens, U: ToTokens, V: ToTokens>(ffi_ty: T, ty: U, field_expr: V) -> Self {
         Self::CastedFFIConversion(DictionaryName::InterfaceFrom, FFIConversionFromMethod::Opt.to_token_stream(), ffi_ty.to_token_stream(), ty.to_token_stream(), field_expr.to_token_stream())
     }
+    pub fn casted_from<T: ToTokens, U: ToTokens, V: ToTokens>(ty: T, from_ty: U, field_expr: V) -> Self {
+        Self::CastedFrom(ty.to_token_stream(), from_ty.to_token_stream(), field_expr.to_token_stream())
+    }
 
     pub fn some<T: ToTokens>(body: T) -> Self {
         Self::Some(body.to_token_stream())
@@ -323,6 +327,8 @@ impl ToTokens for DictionaryExpr {
                 ).to_tokens(tokens),
             Self::CastedFFIConversion(interface, method, ffi_type, target_type, expr) =>
                 quote!(<#ffi_type as ferment::#interface<#target_type>>::#method(#expr)).to_tokens(tokens),
+            Self::CastedFrom(ty, from_ty, expr) =>
+                quote!(<#ty as From<#from_ty>>::from(#expr)).to_tokens(tokens),
             Self::BoxedSelfDestructuring(expr) =>
                 InterfacesMethodExpr::Boxed(DictionaryExpr::self_destruct(expr)).to_tokens(tokens),
             Self::IfElse(condition, true_expr, false_expr) =>
@@ -30,6 +30,8 @@ pub enum DictionaryName {
     FFiResult,
     Caller,
     Destructor,
+    Context,
+    ContextDestructor,
     Tag,
     Index,
 }
@@ -66,6 +68,8 @@ impl ToTokens for DictionaryName {
             DictionaryName::FFiResult => quote!(ffi_result),
             DictionaryName::Caller => quote!(caller),
             DictionaryName::Destructor => quote!(destructor),
+            DictionaryName::Context => quote!(context),
+            DictionaryName::ContextDestructor => quote!(context_destructor),
             DictionaryName::Tag => quote!(tag),
             DictionaryName::Index => quote!(index),
         }
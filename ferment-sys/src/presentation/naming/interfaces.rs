@@ -30,6 +30,9 @@ pub enum InterfacesMethod {
     FromOptOpaque,
     ToOptOpaque,
     DestroyOptPrimitive,
+    FromScalar,
+    FromOptScalar,
+    ToOptScalar,
 
     FromGroup,
     FromPrimitiveGroup,
@@ -108,6 +111,9 @@ impl ToTokens for InterfacesMethod {
             InterfacesMethod::ToOptPrimitive => quote!(to_opt_primitive),
             InterfacesMethod::ToOptOpaque => quote!(to_opt_opaque),
             InterfacesMethod::DestroyOptPrimitive => quote!(destroy_opt_primitive),
+            InterfacesMethod::FromScalar => quote!(from_scalar),
+            InterfacesMethod::FromOptScalar => quote!(from_opt_scalar),
+            InterfacesMethod::ToOptScalar => quote!(to_opt_scalar),
         }.to_tokens(dst)
     }
 }
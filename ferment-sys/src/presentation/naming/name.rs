@@ -38,6 +38,7 @@ pub enum Name<SPEC> where SPEC: Specification {
     TraitFn(Type, Type),
     TraitDestructor(Type, Type),
     Vtable(Ident),
+    TraitProxy(Ident),
     ModFn(Path),
     VTableInnerFn(Ident),
     Getter(Path, TokenStream2),
@@ -119,6 +120,12 @@ impl<SPEC> Name<SPEC>
     pub fn destructor() -> Self {
         Self::Dictionary(DictionaryName::Destructor)
     }
+    pub fn context() -> Self {
+        Self::Dictionary(DictionaryName::Context)
+    }
+    pub fn context_destructor() -> Self {
+        Self::Dictionary(DictionaryName::ContextDestructor)
+    }
     pub fn ctor(ty: &Type) -> Self {
         Self::Constructor(ty.clone())
     }
@@ -162,5 +169,9 @@ impl<SPEC> NameComposable<SPEC> for Name<SPEC>
     fn unnamed_arg(index: usize) -> Self {
         Self::UnnamedArg(index)
     }
+
+    fn trait_proxy(ident: Ident) -> Self {
+        Self::TraitProxy(ident)
+    }
 }
 
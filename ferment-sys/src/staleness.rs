@@ -0,0 +1,143 @@
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+use indexmap::IndexMap;
+use quote::ToTokens;
+use similar::{capture_diff_slices, Algorithm, DiffTag};
+use syn::{Item, ItemImpl, ItemMod};
+
+/// Generated file which differs from the one on disk
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StaleFile {
+    pub path: PathBuf,
+    /// Items which are generated but missing on disk
+    pub added: Vec<String>,
+    /// Items which are on disk but aren't generated anymore
+    pub removed: Vec<String>,
+    /// Items which are generated differently
+    pub changed: Vec<String>,
+}
+
+impl Display for StaleFile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{}", self.path.display()))?;
+        for (sign, items) in [('+', &self.added), ('-', &self.removed), ('~', &self.changed)] {
+            for item in items {
+                f.write_fmt(format_args!("\n\t{sign} {item}"))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl StaleFile {
+    /// Compares generated Rust code with the code on disk (`actual` is `None` if there is no file),
+    /// returns `None` if they're identical
+    #[allow(unused)]
+    pub(crate) fn rust(path: PathBuf, expected: &str, actual: Option<&str>) -> Option<Self> {
+        (actual != Some(expected)).then(|| {
+            let expected_items = rust_items(expected);
+            let actual_items = actual.map(rust_items).unwrap_or_default();
+            Self::new(path, expected_items, actual_items)
+        })
+    }
+    /// Compares generated C header with the header on disk (`actual` is `None` if there is no file),
+    /// returns `None` if they're identical.
+    /// Line sequences are diffed, so the duplicated and reordered lines are reported as well;
+    /// each line is prefixed with its number in the generated (`+`) or the on-disk (`-`) header
+    pub(crate) fn header(path: PathBuf, expected: &str, actual: Option<&str>) -> Option<Self> {
+        (actual != Some(expected)).then(|| {
+            let expected_lines = header_lines(expected);
+            let actual_lines = actual.map(header_lines).unwrap_or_default();
+            let old = actual_lines.iter().map(|(_, line)| *line).collect::<Vec<_>>();
+            let new = expected_lines.iter().map(|(_, line)| *line).collect::<Vec<_>>();
+            let mut stale = Self { path, added: vec![], removed: vec![], changed: vec![] };
+            for op in capture_diff_slices(Algorithm::Myers, &old, &new) {
+                let (tag, old_range, new_range) = op.as_tag_tuple();
+                if tag != DiffTag::Equal {
+                    stale.removed.extend(actual_lines[old_range].iter().map(numbered_line));
+                    stale.added.extend(expected_lines[new_range].iter().map(numbered_line));
+                }
+            }
+            stale
+        })
+    }
+    /// File which is on disk but isn't generated anymore
+    #[allow(unused)]
+    pub(crate) fn redundant(path: PathBuf, actual: &str) -> Self {
+        Self::new(path, IndexMap::new(), rust_items(actual))
+    }
+
+    #[allow(unused)]
+    fn new(path: PathBuf, expected: IndexMap<String, String>, actual: IndexMap<String, String>) -> Self {
+        let added = expected.keys()
+            .filter(|key| !actual.contains_key(*key))
+            .cloned()
+            .collect();
+        let removed = actual.keys()
+            .filter(|key| !expected.contains_key(*key))
+            .cloned()
+            .collect();
+        let changed = expected.iter()
+            .filter_map(|(key, value)| actual.get(key).and_then(|actual_value| actual_value.ne(value).then(|| key.clone())))
+            .collect();
+        Self { path, added, removed, changed }
+    }
+}
+
+/// Items of the Rust file keyed by their module path (e.g. `types::crate_name::module::Item`).
+/// Unparseable content is treated as empty
+#[allow(unused)]
+fn rust_items(content: &str) -> IndexMap<String, String> {
+    let mut items = IndexMap::new();
+    if let Ok(file) = syn::parse_file(content) {
+        collect_rust_items(&file.items, "", &mut items);
+    }
+    items
+}
+
+#[allow(unused)]
+fn collect_rust_items(items: &[Item], prefix: &str, dict: &mut IndexMap<String, String>) {
+    items.iter().for_each(|item| {
+        let key = format!("{prefix}{}", rust_item_key(item));
+        match item {
+            Item::Mod(ItemMod { content: Some((_, items)), .. }) =>
+                collect_rust_items(items, &format!("{key}::"), dict),
+            // Formatted, so the layouts are compared by their content
+            _ =>
+                dict.entry(key).or_default().push_str(&prettyplease::unparse(&syn::File { shebang: None, attrs: vec![], items: vec![item.clone()] })),
+        }
+    });
+}
+
+#[allow(unused)]
+fn rust_item_key(item: &Item) -> String {
+    match item {
+        Item::Const(item) => item.ident.to_string(),
+        Item::Enum(item) => item.ident.to_string(),
+        Item::Fn(item) => item.sig.ident.to_string(),
+        Item::Mod(item) => item.ident.to_string(),
+        Item::Static(item) => item.ident.to_string(),
+        Item::Struct(item) => item.ident.to_string(),
+        Item::Trait(item) => item.ident.to_string(),
+        Item::Type(item) => item.ident.to_string(),
+        Item::Union(item) => item.ident.to_string(),
+        Item::Impl(ItemImpl { generics, trait_, self_ty, .. }) => match trait_ {
+            Some((_, path, _)) => format!("impl{} {} for {}", generics.to_token_stream(), path.to_token_stream(), self_ty.to_token_stream()),
+            None => format!("impl{} {}", generics.to_token_stream(), self_ty.to_token_stream()),
+        },
+        item => item.to_token_stream().to_string(),
+    }
+}
+
+/// Non-empty trimmed lines of the header with their (1-based) numbers
+fn header_lines(content: &str) -> Vec<(usize, &str)> {
+    content.lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty())
+        .collect()
+}
+
+fn numbered_line((number, line): &(usize, &str)) -> String {
+    format!("{number}: {line}")
+}
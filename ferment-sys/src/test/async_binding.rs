@@ -0,0 +1,47 @@
+use quote::{quote, ToTokens};
+use syn::{parse_quote, ReturnType};
+use crate::AsyncMode;
+use crate::ast::CommaPunctuated;
+use crate::presentation::BindingPresentation;
+
+fn async_fn_presentation(mode: AsyncMode) -> String {
+    BindingPresentation::RegularFunction {
+        aspect: (vec![], vec![], None),
+        name: quote!(crate_fetch),
+        asyncness: Some((mode, parse_quote!(my_crate::LocalExecutor))),
+        arguments: CommaPunctuated::new(),
+        input_conversions: quote!(crate::fetch()),
+        return_type: ReturnType::Type(Default::default(), Box::new(parse_quote!(u32))),
+        output_conversions: quote!(obj),
+    }.to_token_stream().to_string()
+}
+
+#[test]
+fn async_blocking_uses_executor() {
+    let presentation = async_fn_presentation(AsyncMode::Blocking);
+    assert!(presentation.contains("runtime as * const my_crate :: LocalExecutor"));
+    assert!(presentation.contains("ferment :: Executor :: block_on"));
+    assert!(!presentation.contains("tokio"));
+}
+
+#[test]
+fn async_callback_uses_executor() {
+    let presentation = async_fn_presentation(AsyncMode::Callback);
+    assert!(presentation.contains("pub struct crate_fetch_Completion"));
+    assert!(presentation.contains("completion : crate_fetch_Completion"));
+    assert!(presentation.contains("runtime as * const my_crate :: LocalExecutor"));
+    assert!(presentation.contains("ferment :: Executor :: spawn"));
+    assert!(presentation.contains("-> * mut ferment :: CancellationHandle"));
+    // the arguments are converted before the task is created, only the spawned future is awaited
+    assert!(presentation.contains("let future = crate :: fetch () ; let (task , handle) = ferment :: Task :: new (async move { let obj = future . await ;"));
+    assert!(presentation.contains("unsafe impl Send for crate_fetch_Completion { }"));
+    assert!(!presentation.contains("AssertSend"));
+    assert!(!presentation.contains("tokio"));
+}
+
+#[test]
+fn invalid_executor_fails_the_build() {
+    let mut config = crate::Config::new("fermented", crate::Crate::current_with_name("my_crate"), cbindgen::Config::default());
+    config.executor = String::from("not a path");
+    assert!(matches!(crate::tree::FileTreeProcessor::build(&config), Err(crate::Error::Configuration(message)) if message.contains("Invalid executor path")));
+}
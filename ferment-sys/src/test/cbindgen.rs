@@ -0,0 +1,35 @@
+use crate::{Config, Crate, Error};
+use crate::config::DEFAULT_CBINDGEN_CONFIG_FILE;
+
+fn config(cbindgen_config_from_file: Option<&str>) -> Config {
+    let cbindgen_config = cbindgen::Config { language: cbindgen::Language::Cython, ..Default::default() };
+    let mut config = Config::new("fermented", Crate::current_with_name("my_crate"), cbindgen_config);
+    config.cbindgen_config_from_file = cbindgen_config_from_file.map(String::from);
+    config
+}
+
+#[test]
+fn programmatic_cbindgen_config_is_used_without_file() {
+    let config = config(None).resolve_cbindgen_config().unwrap();
+    assert_eq!(config.language, cbindgen::Language::Cython);
+}
+
+#[test]
+fn missing_default_cbindgen_config_file_falls_back_to_generated_config() {
+    let config = config(Some(DEFAULT_CBINDGEN_CONFIG_FILE)).resolve_cbindgen_config().unwrap();
+    assert_eq!(config.language, cbindgen::Language::C);
+    assert_eq!(config.include_guard.as_deref(), Some("my_crate_h"));
+}
+
+#[test]
+fn cbindgen_config_file_is_used() {
+    let config = config(Some("../ferment-example/entry-point/cbindgen.toml")).resolve_cbindgen_config().unwrap();
+    assert_eq!(config.language, cbindgen::Language::C);
+    assert_eq!(config.include_guard.as_deref(), Some("example_h"));
+}
+
+#[test]
+fn missing_cbindgen_config_file_is_error() {
+    let result = config(Some("missing/cbindgen.toml")).resolve_cbindgen_config();
+    assert!(matches!(result, Err(Error::Configuration(..))));
+}
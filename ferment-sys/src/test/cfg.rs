@@ -0,0 +1,108 @@
+use indexmap::{IndexMap, IndexSet};
+use syn::{parse_quote, Attribute, File, Item, Meta};
+use crate::CfgMode;
+use crate::test::{fermentate, fixture, fn_item, has_item, has_item_containing, items, struct_item};
+use crate::tree::CfgEvaluator;
+
+const LIB: &str = "#[cfg(feature = \"a\")]
+#[ferment_macro::export]
+pub fn value() -> u32 { 1 }
+
+#[cfg(not(feature = \"a\"))]
+#[ferment_macro::export]
+pub fn value() -> u32 { 0 }
+
+#[cfg_attr(feature = \"b\", ferment_macro::export)]
+pub struct OnlyWithB { pub id: u32 }
+
+#[cfg_attr(feature = \"a\", ferment_macro::export)]
+pub struct OnlyWithA {
+    pub id: u32,
+    #[cfg(feature = \"b\")]
+    pub extra: u32,
+}
+
+#[cfg(test)]
+#[ferment_macro::export]
+pub fn test_only() -> u32 { 0 }
+
+#[cfg(target_os = \"android\")]
+#[ferment_macro::export]
+pub fn android_only() -> u32 { 0 }
+
+#[cfg(test)]
+mod tests {
+    #[ferment_macro::export]
+    pub fn in_tests() -> u32 { 0 }
+}
+";
+
+fn fermentate_cfg(name: &str, mode: CfgMode, target_cfg: Option<IndexMap<String, IndexSet<String>>>) -> File {
+    let mut config = fixture(&format!("cfg_{name}"), "cfg", &[("lib.rs", LIB)]);
+    config.current_crate = config.current_crate.with_features(["a"]);
+    config.cfg_mode = mode;
+    config.target_cfg = target_cfg;
+    fermentate(config).0
+}
+
+fn fn_count(fermentate: &File, ident: &str) -> usize {
+    items(fermentate).into_iter()
+        .filter(|item| matches!(item, Item::Fn(item) if item.sig.ident == ident))
+        .count()
+}
+
+#[test]
+fn cfg_test_items_are_always_skipped() {
+    let fermentate = fermentate_cfg("keep", CfgMode::Keep, None);
+    assert!(!has_item_containing(&fermentate, "test_only"));
+    assert!(!has_item_containing(&fermentate, "in_tests"));
+    // all the other branches are kept along with their cfg attributes,
+    // items with the same name are collapsed into the last one
+    assert_eq!(fn_count(&fermentate, "cfg_value"), 1);
+    let cfg: Attribute = parse_quote!(#[cfg(not(feature = "a"))]);
+    assert!(fn_item(&fermentate, "cfg_value").attrs.contains(&cfg));
+    assert!(has_item(&fermentate, "cfg_OnlyWithB"));
+    assert!(has_item(&fermentate, "cfg_android_only"));
+}
+
+#[test]
+fn disabled_branches_are_skipped() {
+    let evaluated = fermentate_cfg("evaluate", CfgMode::Evaluate, None);
+    assert_eq!(fn_count(&evaluated, "cfg_value"), 1);
+    assert!(!has_item(&evaluated, "cfg_OnlyWithB"));
+    assert!(struct_item(&evaluated, "cfg_OnlyWithA").fields.iter().all(|field| field.ident.as_ref().is_some_and(|ident| ident != "extra")));
+    assert!(!has_item_containing(&evaluated, "extra"));
+    // target options aren't known outside the build script
+    assert!(has_item(&evaluated, "cfg_android_only"));
+    let target_cfg = IndexMap::from_iter([(String::from("target_os"), IndexSet::from_iter([String::from("ios")]))]);
+    let with_target = fermentate_cfg("evaluate_target", CfgMode::Evaluate, Some(target_cfg));
+    assert!(!has_item(&with_target, "cfg_android_only"));
+}
+
+#[test]
+fn predicates() {
+    let evaluator = CfgEvaluator {
+        features: Some(IndexSet::from_iter([String::from("std"), String::from("serde_json")])),
+        target_cfg: Some(IndexMap::from_iter([
+            (String::from("target_os"), IndexSet::from_iter([String::from("ios")])),
+            (String::from("unix"), IndexSet::from_iter([String::new()])),
+        ])),
+    };
+    let eval = |meta: Meta| evaluator.eval(&meta);
+    assert_eq!(eval(parse_quote!(test)), Some(false));
+    assert_eq!(eval(parse_quote!(ferment)), Some(true));
+    assert_eq!(eval(parse_quote!(feature = "std")), Some(true));
+    assert_eq!(eval(parse_quote!(feature = "serde-json")), Some(true));
+    assert_eq!(eval(parse_quote!(feature = "alloc")), Some(false));
+    assert_eq!(eval(parse_quote!(unix)), Some(true));
+    assert_eq!(eval(parse_quote!(windows)), Some(false));
+    assert_eq!(eval(parse_quote!(target_os = "ios")), Some(true));
+    assert_eq!(eval(parse_quote!(all(unix, not(target_os = "macos")))), Some(true));
+    assert_eq!(eval(parse_quote!(any(test, feature = "alloc"))), Some(false));
+    assert_eq!(eval(parse_quote!(any(test, feature = "std"))), Some(true));
+    let unknown = CfgEvaluator::default();
+    assert_eq!(unknown.eval(&parse_quote!(feature = "std")), None);
+    assert_eq!(unknown.eval(&parse_quote!(any(test, feature = "std"))), None);
+    assert_eq!(unknown.eval(&parse_quote!(all(test, feature = "std"))), Some(false));
+    assert_eq!(unknown.eval(&parse_quote!(not(test))), Some(true));
+}
@@ -0,0 +1,65 @@
+use syn::{parse_quote, Expr, File, Stmt, Type};
+use crate::test::{fermentate, fixture, fn_item, has_item_containing, static_item};
+
+const LIB: &str = "pub mod api;
+";
+
+const API: &str = "#[ferment_macro::export]
+#[derive(Clone, Copy)]
+pub struct Coord { pub x: u32, pub y: u32 }
+
+#[ferment_macro::export]
+pub const MAX: u32 = 10;
+#[ferment_macro::export]
+pub const NAME: &str = \"consts\";
+#[ferment_macro::export]
+pub const ORIGIN: Coord = Coord { x: 0, y: 0 };
+#[ferment_macro::export]
+pub static LIMIT: u64 = 42;
+#[ferment_macro::export]
+pub static DEFAULT_COORD: Coord = Coord { x: 1, y: 1 };
+#[ferment_macro::export]
+pub static mut COUNTER: u32 = 0;
+pub const NOT_EXPORTED: u32 = 1;
+
+#[ferment_macro::export]
+impl Coord {
+    pub const UNIT: Coord = Coord { x: 1, y: 1 };
+    pub const DIM: u8 = 2;
+    const PRIVATE: u8 = 3;
+    pub fn sum(&self) -> u32 { self.x + self.y + Self::PRIVATE as u32 }
+}
+";
+
+fn static_value(fermentate: &File, ident: &str) -> (Type, Expr) {
+    let item = static_item(fermentate, ident);
+    (*item.ty.clone(), *item.expr.clone())
+}
+
+fn first_stmt(fermentate: &File, ident: &str) -> Stmt {
+    fn_item(fermentate, ident).block.stmts[0].clone()
+}
+
+#[test]
+fn consts_and_statics() {
+    let (fermentate, diagnostics) = fermentate(fixture("consts", "consts", &[("lib.rs", LIB), ("api.rs", API)]));
+    // primitives are C-visible statics
+    assert_eq!(static_value(&fermentate, "consts_api_MAX"), (parse_quote!(u32), parse_quote!(consts::api::MAX)));
+    assert_eq!(static_value(&fermentate, "consts_api_LIMIT"), (parse_quote!(u64), parse_quote!(consts::api::LIMIT)));
+    assert_eq!(static_value(&fermentate, "consts_api_Coord_DIM"), (parse_quote!(u8), parse_quote!(consts::api::Coord::DIM)));
+    // the other types are converted by the getters
+    assert_eq!(fn_item(&fermentate, "consts_api_NAME").sig, parse_quote!(unsafe extern "C" fn consts_api_NAME() -> *mut std::os::raw::c_char));
+    assert_eq!(first_stmt(&fermentate, "consts_api_NAME"), parse_quote!(let obj = consts::api::NAME;));
+    assert_eq!(fn_item(&fermentate, "consts_api_ORIGIN").sig, parse_quote!(unsafe extern "C" fn consts_api_ORIGIN() -> *mut crate::fermented::types::consts::api::consts_api_Coord));
+    assert_eq!(first_stmt(&fermentate, "consts_api_ORIGIN"), parse_quote!(let obj = consts::api::ORIGIN;));
+    assert_eq!(first_stmt(&fermentate, "consts_api_DEFAULT_COORD"), parse_quote!(let obj = &consts::api::DEFAULT_COORD;));
+    assert!(fn_item(&fermentate, "consts_api_Coord_UNIT").sig.inputs.is_empty());
+    assert!(!has_item_containing(&fermentate, "NOT_EXPORTED"));
+    assert!(!has_item_containing(&fermentate, "PRIVATE"));
+    assert!(!has_item_containing(&fermentate, "consts_api_COUNTER"));
+    assert_eq!(diagnostics.len(), 1, "{diagnostics:?}");
+    assert_eq!(diagnostics[0].item, "consts::api::COUNTER");
+    assert!(diagnostics[0].file.as_ref().is_some_and(|file| file.ends_with("src/api.rs")), "{diagnostics:?}");
+    assert_eq!(diagnostics[0].line, Some(16));
+    assert!(diagnostics[0].message.contains("mutable static"), "{diagnostics:?}");
+}
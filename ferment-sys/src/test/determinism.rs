@@ -0,0 +1,24 @@
+use std::path::PathBuf;
+use crate::{Config, Crate};
+use crate::tree::FileTreeProcessor;
+use crate::writer::Writer;
+
+fn entry_point_fermentate() -> String {
+    let root_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../ferment-example/entry-point/src");
+    let config = Config::new("fermented", Crate::new("example_entry_point", root_path), cbindgen::Config::default());
+    let crate_tree = FileTreeProcessor::build(&config)
+        .expect("Crate tree");
+    Writer::from(config)
+        .rust_fermentate(&crate_tree)
+        .expect("Fermentate")
+        .to_string()
+}
+
+#[test]
+fn fermentate_is_reproducible() {
+    let fermentate = entry_point_fermentate();
+    assert!(fermentate.contains("pub mod generics"));
+    for _ in 0..3 {
+        assert_eq!(entry_point_fermentate(), fermentate);
+    }
+}
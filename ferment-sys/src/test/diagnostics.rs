@@ -0,0 +1,124 @@
+use std::path::PathBuf;
+use syn::{parse_quote, File, Fields};
+use crate::{Diagnostic, DiagnosticPolicy, Error};
+use crate::test::{fermentate, fixture, fn_item, has_item, struct_item, try_fermentate};
+
+const LIB: &str = "pub mod model;
+
+#[ferment_macro::export]
+pub struct Valid { pub id: u32 }
+";
+
+const MODEL: &str = "#[ferment_macro::export]
+pub struct Model { pub id: u32 }
+
+#[ferment_macro::export]
+pub fn unsupported(value: impl Fn(u32) -> impl Iterator<Item = u32>) -> u32 { 0 }
+";
+
+fn fermentate_with_policy(name: &str, policy: DiagnosticPolicy) -> (Result<File, Error>, Vec<Diagnostic>) {
+    let mut config = fixture(&format!("diagnostics_{name}"), "diag", &[("lib.rs", LIB), ("model.rs", MODEL)]);
+    config.diagnostic_policy = policy;
+    try_fermentate(config)
+}
+
+fn fermentate_lib(name: &str, lib: &str) -> (File, Vec<Diagnostic>) {
+    fermentate(fixture(&format!("diagnostics_{name}"), "diag", &[("lib.rs", lib)]))
+}
+
+#[test]
+fn unsupported_item_is_skipped_with_location() {
+    let (fermentate, diagnostics) = fermentate_with_policy("warn", DiagnosticPolicy::Warn);
+    let fermentate = fermentate.expect("Fermentate");
+    assert!(has_item(&fermentate, "diag_Valid_ctor"));
+    assert!(has_item(&fermentate, "diag_model_Model_ctor"));
+    assert!(!has_item(&fermentate, "diag_model_unsupported"));
+    let diagnostic = diagnostics.iter()
+        .find(|diagnostic| diagnostic.item == "diag::model::unsupported")
+        .expect("Diagnostic of the unsupported fn");
+    assert!(diagnostic.file.as_ref().is_some_and(|file| file.ends_with("src/model.rs")));
+    assert_eq!(diagnostic.line, Some(5));
+    assert!(!diagnostic.message.is_empty());
+}
+
+const UNSUPPORTED_TYPES: &str = "#[ferment_macro::export]
+pub struct Settings { pub id: u32 }
+
+#[ferment_macro::export]
+impl Settings {
+    pub fn id(&self) -> u32 { self.id }
+    pub fn fail(&self)
+        -> ! { panic!() }
+}
+
+#[ferment_macro::export]
+pub fn notify(callback: Option<&dyn Fn(u32)>) {}
+";
+
+#[test]
+fn unsupported_types_are_reported_at_their_location() {
+    let (fermentate, diagnostics) = fermentate_lib("unsupported_types", UNSUPPORTED_TYPES);
+    assert_eq!(diagnostics.len(), 2, "{diagnostics:?}");
+    assert_eq!((diagnostics[0].item.as_str(), diagnostics[0].line), ("diag::Settings", Some(8)));
+    assert_eq!(diagnostics[0].message, "`!` can't be expressed in FFI");
+    assert_eq!((diagnostics[1].item.as_str(), diagnostics[1].line), ("diag::notify", Some(12)));
+    assert!(diagnostics[1].message.contains("optional callbacks should be boxed"));
+    assert!(diagnostics.iter().all(|diagnostic| diagnostic.file.as_ref().is_some_and(|file| file.ends_with("src/lib.rs"))));
+    // the rejected items are skipped, the rest is fermented
+    assert!(has_item(&fermentate, "diag_Settings_ctor"));
+    assert!(!has_item(&fermentate, "diag_Settings_id"));
+    assert!(!has_item(&fermentate, "diag_notify"));
+}
+
+const TRAIT_OBJECT_RESULTS: &str = "#[ferment_macro::export]
+pub struct Settings { pub id: u32 }
+
+#[ferment_macro::export]
+pub type Describe = fn(u32) -> &'static dyn std::fmt::Debug;
+";
+
+#[test]
+fn trait_object_results_of_fn_pointers_are_reported() {
+    let (fermentate, diagnostics) = fermentate_lib("trait_object_results", TRAIT_OBJECT_RESULTS);
+    assert_eq!(diagnostics.len(), 1, "{diagnostics:?}");
+    assert_eq!((diagnostics[0].item.as_str(), diagnostics[0].line), ("diag::Describe", Some(5)));
+    assert_eq!(diagnostics[0].message, "`& 'static dyn std :: fmt :: Debug` isn't supported as a result of the fn pointer");
+    assert!(has_item(&fermentate, "diag_Settings_ctor"));
+    assert!(!has_item(&fermentate, "diag_Describe"));
+}
+
+#[test]
+fn deny_policy_fails_with_all_diagnostics() {
+    let (fermentate, diagnostics) = fermentate_with_policy("deny", DiagnosticPolicy::Deny);
+    match fermentate {
+        Err(Error::Diagnostics(reported)) => assert_eq!(reported, diagnostics),
+        result => panic!("Expected diagnostics, got {result:?}"),
+    }
+}
+
+#[test]
+fn diagnostic_is_presented_with_location() {
+    let diagnostic = Diagnostic {
+        item: String::from("diag::model::unsupported"),
+        file: Some(PathBuf::from("src/model.rs")),
+        line: Some(5),
+        message: String::from("Non-supported generic kind:\nFn"),
+    };
+    assert_eq!(diagnostic.to_string(), "src/model.rs:5: diag::model::unsupported: Non-supported generic kind:\nFn");
+    assert_eq!(diagnostic.cargo_warning(), "cargo:warning=src/model.rs:5: diag::model::unsupported: Non-supported generic kind: Fn");
+}
+
+const PARENTHESIZED_TYPES: &str = "#[ferment_macro::export]
+pub struct Settings { pub id: (u32), pub name: Option<(String)> }
+
+#[ferment_macro::export]
+pub fn scale(value: (u32)) -> (u32) { value * 2 }
+";
+
+#[test]
+fn parenthesized_types_are_unwrapped() {
+    let (fermentate, diagnostics) = fermentate_lib("parenthesized_types", PARENTHESIZED_TYPES);
+    assert!(diagnostics.is_empty(), "{diagnostics:?}");
+    assert_eq!(struct_item(&fermentate, "diag_Settings").fields, Fields::Named(parse_quote!({ pub id: u32, pub name: *mut std::os::raw::c_char })));
+    assert_eq!(fn_item(&fermentate, "diag_scale").sig, parse_quote!(unsafe extern "C" fn diag_scale(value: u32) -> u32));
+}
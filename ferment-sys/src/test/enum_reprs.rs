@@ -0,0 +1,114 @@
+use syn::{parse_quote, Expr, Pat};
+use crate::test::{any_expr, enum_item, fermentate, fixture, has_item_containing, Undocumented};
+
+const LIB: &str = "#[ferment_macro::export]
+pub struct Identifier(pub u32);
+
+#[repr(u8)]
+#[ferment_macro::export]
+pub enum ContractBounds {
+    SingleContract { id: Identifier } = 0,
+    SingleContractDocumentType { id: Identifier, document_type_name: String } = 1,
+}
+
+#[repr(C, i32)]
+#[ferment_macro::export]
+pub enum Mixed {
+    Empty = -1,
+    Pair(u32, String) = 10,
+    Named { flag: bool } = 20,
+}
+
+#[ferment_macro::export]
+pub enum KeyType {
+    Ecdsa = 0,
+    Bls = 4,
+}
+
+#[repr(u64)]
+#[ferment_macro::export]
+pub enum Plain { A, B }
+";
+
+const CONST_DISCRIMINANTS: &str = "pub mod consts;
+
+use crate::consts::BASE;
+
+pub const LOCAL: u8 = 5;
+
+#[repr(u8)]
+#[ferment_macro::export]
+pub enum Level {
+    Low = BASE,
+    Mid = LOCAL,
+    High = consts::TOP,
+    Max = crate::consts::TOP + 1,
+    Limit = u8::MAX,
+}
+
+const fn compute() -> isize { 1 }
+
+#[ferment_macro::export]
+pub enum Computed { A = compute() }
+";
+
+const CONSTS: &str = "pub const BASE: u8 = 1;
+pub const TOP: u8 = 10;
+";
+
+#[test]
+fn enum_repr_and_discriminants_are_kept() {
+    let (fermentate, diagnostics) = fermentate(fixture("enum_reprs", "enum_reprs", &[("lib.rs", LIB)]));
+    assert!(diagnostics.is_empty(), "{diagnostics:?}");
+    assert_eq!(enum_item(&fermentate, "enum_reprs_ContractBounds").undocumented(), parse_quote! {
+        #[repr(u8)]
+        #[derive(Clone)]
+        #[non_exhaustive]
+        pub enum enum_reprs_ContractBounds {
+            SingleContract { id: *mut crate::fermented::types::enum_reprs::enum_reprs_Identifier } = 0,
+            SingleContractDocumentType { id: *mut crate::fermented::types::enum_reprs::enum_reprs_Identifier, document_type_name: *mut std::os::raw::c_char } = 1
+        }
+    });
+    assert_eq!(enum_item(&fermentate, "enum_reprs_Mixed").undocumented(), parse_quote! {
+        #[repr(C, i32)]
+        #[derive(Clone)]
+        #[non_exhaustive]
+        pub enum enum_reprs_Mixed { Empty = -1, Pair(u32, *mut std::os::raw::c_char) = 10, Named { flag: bool } = 20 }
+    });
+    assert_eq!(enum_item(&fermentate, "enum_reprs_KeyType").undocumented(), parse_quote! {
+        #[repr(C)]
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        #[non_exhaustive]
+        pub enum enum_reprs_KeyType { Ecdsa = 0, Bls = 4 }
+    });
+    assert_eq!(enum_item(&fermentate, "enum_reprs_Plain").undocumented(), parse_quote! {
+        #[repr(u64)]
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        #[non_exhaustive]
+        pub enum enum_reprs_Plain { A, B }
+    });
+    // the conversions match the variants with their fields
+    let pattern: Pat = parse_quote!(enum_reprs_ContractBounds::SingleContractDocumentType { id, document_type_name });
+    assert!(any_expr(&fermentate, |expr| matches!(expr, Expr::Match(expr) if expr.arms.iter().any(|arm| arm.pat == pattern))));
+}
+
+// Paths to the consts are resolved into the full ones, so they can be named from the fermented module
+#[test]
+fn const_discriminants_are_resolved() {
+    let (fermentate, diagnostics) = fermentate(fixture("enum_consts", "enum_consts", &[("lib.rs", CONST_DISCRIMINANTS), ("consts.rs", CONSTS)]));
+    let discriminants = enum_item(&fermentate, "enum_consts_Level").variants.iter()
+        .map(|variant| variant.discriminant.clone().map(|(_, expr)| expr))
+        .collect::<Vec<_>>();
+    assert_eq!(discriminants, [
+        Some(parse_quote!(enum_consts::consts::BASE)),
+        Some(parse_quote!(enum_consts::LOCAL)),
+        Some(parse_quote!(enum_consts::consts::TOP)),
+        Some(parse_quote!(enum_consts::consts::TOP + 1)),
+        Some(parse_quote!(u8::MAX)),
+    ]);
+    assert!(!has_item_containing(&fermentate, "enum_consts_Computed"));
+    assert_eq!(diagnostics.len(), 1, "{diagnostics:?}");
+    assert_eq!(diagnostics[0].item, "enum_consts::Computed");
+    assert_eq!(diagnostics[0].line, Some(20));
+    assert!(diagnostics[0].message.contains("`compute ()` isn't supported as a discriminant"), "{diagnostics:?}");
+}
@@ -0,0 +1,80 @@
+use cargo_metadata::MetadataCommand;
+use crate::{Config, Crate};
+use crate::error::Error;
+use crate::lang::rust::resolve_crates;
+use crate::writer::Writer;
+
+fn package(name: &str, version: &str, kind: &str) -> String {
+    format!(r#"{{
+        "name": "{name}", "version": "{version}", "id": "{name} {version}", "source": null,
+        "dependencies": [], "features": {{}}, "manifest_path": "/deps/{name}-{version}/Cargo.toml",
+        "targets": [{{ "name": "{target}", "kind": ["{kind}"], "src_path": "/deps/{name}-{version}/src/{file}" }}]
+    }}"#, target = name.replace('-', "_"), file = if kind == "bin" { "main.rs" } else { "lib.rs" })
+}
+
+fn node(id: &str, deps: &[(&str, &str, &str)], features: &[&str]) -> String {
+    let deps = deps.iter()
+        .map(|(name, pkg, kind)| format!(r#"{{ "name": "{name}", "pkg": "{pkg}", "dep_kinds": [{{ "kind": {kind}, "target": null }}] }}"#))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let features = features.iter().map(|feature| format!("\"{feature}\"")).collect::<Vec<_>>().join(", ");
+    format!(r#"{{ "id": "{id}", "dependencies": [], "deps": [{deps}], "features": [{features}] }}"#)
+}
+
+fn metadata() -> cargo_metadata::Metadata {
+    let packages = [
+        package("app", "0.1.0", "lib"),
+        package("dash-core", "0.2.0", "lib"),
+        package("serde", "1.0.0", "lib"),
+        package("rand", "0.7.0", "lib"),
+        package("rand", "0.8.0", "lib"),
+        package("nested", "0.3.0", "lib"),
+        package("tool", "1.0.0", "bin"),
+        package("test-utils", "0.1.0", "lib"),
+    ];
+    let nodes = [
+        node("app 0.1.0", &[("dashcore", "dash-core 0.2.0", "null"), ("serde", "serde 1.0.0", "null"), ("rand", "rand 0.8.0", "null"), ("test_utils", "test-utils 0.1.0", "\"dev\"")], &[]),
+        node("dash-core 0.2.0", &[("rand", "rand 0.7.0", "null"), ("nested", "nested 0.3.0", "null")], &["default", "std"]),
+        node("serde 1.0.0", &[], &[]),
+        node("rand 0.7.0", &[], &[]),
+        node("rand 0.8.0", &[], &[]),
+        node("nested 0.3.0", &[("tool", "tool 1.0.0", "\"build\"")], &[]),
+        node("tool 1.0.0", &[], &[]),
+        node("test-utils 0.1.0", &[("rand", "rand 0.7.0", "null")], &[]),
+    ];
+    let json = format!(r#"{{
+        "packages": [{}], "workspace_members": ["app 0.1.0"], "workspace_default_members": ["app 0.1.0"],
+        "resolve": {{ "nodes": [{}], "root": "app 0.1.0" }},
+        "workspace_root": "/app", "target_directory": "/app/target", "version": 1
+    }}"#, packages.join(", "), nodes.join(", "));
+    MetadataCommand::parse(json).expect("Metadata")
+}
+
+#[test]
+fn external_crates_are_resolved_with_the_dependency_graph() {
+    let metadata = metadata();
+    let (crates, errors) = resolve_crates(&metadata, "app", &["dashcore", "dash-core", "serde", "rand", "nested"]);
+    assert!(errors.is_empty(), "{errors:?}");
+    let names = crates.iter().map(|crate_config| crate_config.name.as_str()).collect::<Vec<_>>();
+    // renamed dependency is named by its new name either way
+    assert_eq!(names, ["dashcore", "dashcore", "serde", "rand", "nested"]);
+    assert_eq!(crates[0].root_path(), std::path::PathBuf::from("/deps/dash-core-0.2.0/src/lib.rs"));
+    assert_eq!(crates[0].features, Some(vec![String::from("default"), String::from("std")]));
+    // direct dependency takes precedence over the other versions in the graph
+    assert_eq!(crates[3].root_path(), std::path::PathBuf::from("/deps/rand-0.8.0/src/lib.rs"));
+}
+
+#[test]
+fn unresolved_external_crates_are_reported() {
+    let metadata = metadata();
+    let (crates, errors) = resolve_crates(&metadata, "nested", &["rand", "tool", "missing"]);
+    assert!(crates.is_empty(), "{crates:?}");
+    assert_eq!(errors.len(), 3, "{errors:?}");
+    assert!(errors[0].contains("versions 0.7.0, 0.8.0"), "{}", errors[0]);
+    assert!(errors[1].contains("no library target"), "{}", errors[1]);
+    assert!(errors[2].contains("isn't a dependency of `nested`"), "{}", errors[2]);
+    let mut config = Config::new("fermented", Crate::new("nested", std::env::temp_dir().join("ferment_external_crates")), cbindgen::Config::default());
+    config.unresolved_crates = errors;
+    let result = Writer::from(config).write_all();
+    assert!(matches!(result, Err(Error::Configuration(message)) if message.contains("`missing`")));
+}
@@ -0,0 +1,78 @@
+use syn::{parse_quote, Expr, Type};
+use crate::PanicPolicy;
+use crate::test::{any_expr, enum_item, fermentate, field, find_impl, fixture, fn_item, has_expr, has_item, struct_item, variant, Undocumented};
+
+const LIB: &str = "#[ferment_macro::export]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ChainType { MainNet, TestNet, DevNet = 7 }
+
+#[ferment_macro::export]
+pub struct Settings {
+    pub chain: ChainType,
+    pub fallback: Option<ChainType>,
+    pub history: Vec<ChainType>,
+}
+
+#[ferment_macro::export]
+pub enum Network { Known(ChainType), Custom(String) }
+
+#[ferment_macro::export]
+pub fn next_chain(chain: ChainType) -> ChainType { chain }
+";
+
+#[test]
+fn fieldless_enums_are_passed_by_value() {
+    let (fermentate, diagnostics) = fermentate(fixture("fieldless_enums", "fieldless_enums", &[("lib.rs", LIB)]));
+    assert!(diagnostics.is_empty(), "{diagnostics:?}");
+    let ffi_type: Type = parse_quote!(crate::fermented::types::fieldless_enums::fieldless_enums_ChainType);
+    assert_eq!(enum_item(&fermentate, "fieldless_enums_ChainType").undocumented(), parse_quote! {
+        #[repr(C)]
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        #[non_exhaustive]
+        pub enum fieldless_enums_ChainType { MainNet, TestNet, DevNet = 7 }
+    });
+    assert!(find_impl(&fermentate, Some(&parse_quote!(From<fieldless_enums_ChainType>)), &parse_quote!(fieldless_enums::ChainType)).is_some());
+    assert!(find_impl(&fermentate, Some(&parse_quote!(From<fieldless_enums::ChainType>)), &parse_quote!(fieldless_enums_ChainType)).is_some());
+    assert_eq!(fn_item(&fermentate, "fieldless_enums_next_chain").sig, parse_quote!(unsafe extern "C" fn fieldless_enums_next_chain(chain: #ffi_type) -> #ffi_type));
+    let settings = struct_item(&fermentate, "fieldless_enums_Settings");
+    assert_eq!(field(settings, "chain").ty, ffi_type);
+    assert_eq!(field(settings, "fallback").ty, parse_quote!(*mut #ffi_type));
+    assert_eq!(*variant(enum_item(&fermentate, "fieldless_enums_Network"), "Known"), parse_quote!(Known(#ffi_type)));
+    // no per-variant constructors: the values are created directly on the other side
+    assert!(!has_item(&fermentate, "fieldless_enums_ChainType_MainNet_ctor"));
+    assert!(has_item(&fermentate, "fieldless_enums_ChainType_destroy"));
+    assert!(has_item(&fermentate, "fieldless_enums_Network_Known_ctor"));
+}
+
+const NON_ZERO_BASED: &str = "pub mod low {
+    #[ferment_macro::export]
+    #[derive(Clone, Copy)]
+    pub enum Level { Low = 1, High = 2 }
+
+    #[ferment_macro::export]
+    pub fn raise(level: Level) -> Level { level }
+}
+
+pub mod high {
+    #[ferment_macro::export]
+    #[derive(Clone, Copy)]
+    pub enum Level { Top = 3, Bottom = 4 }
+
+    #[ferment_macro::export]
+    pub fn lower(level: Level) -> Level { level }
+}
+
+#[ferment_macro::export]
+pub fn count() -> u32 { 0 }
+";
+
+#[test]
+fn caught_panic_returns_first_variant_of_fieldless_enum() {
+    let mut config = fixture("fieldless_enums_unwind", "fieldless_enums_unwind", &[("lib.rs", NON_ZERO_BASED)]);
+    config.panic_policy = PanicPolicy::CatchUnwind;
+    let (fermentate, _) = fermentate(config);
+    assert!(has_expr(&fermentate, &parse_quote!(|| crate::fermented::types::fieldless_enums_unwind::low::fieldless_enums_unwind_low_Level::Low)));
+    assert!(has_expr(&fermentate, &parse_quote!(|| crate::fermented::types::fieldless_enums_unwind::high::fieldless_enums_unwind_high_Level::Top)));
+    assert!(has_expr(&fermentate, &parse_quote!(|| <u32 as Default>::default())));
+    assert!(!any_expr(&fermentate, |expr| matches!(expr, Expr::Path(expr) if expr.path == parse_quote!(std::mem::zeroed))));
+}
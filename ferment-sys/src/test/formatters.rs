@@ -0,0 +1,63 @@
+use syn::parse_quote;
+use crate::test::{fermentate, fixture, fn_item, has_expr, has_item, has_item_containing};
+
+const LIB: &str = "pub mod model;
+pub mod fmt;
+";
+
+const MODEL: &str = "#[ferment_macro::export]
+#[derive(Debug, Clone)]
+pub enum Status { Ok, Failed(String) }
+
+#[ferment_macro::export(Display)]
+pub struct Version { pub major: u32 }
+
+#[ferment_macro::export]
+pub struct Point { pub x: u32 }
+
+#[ferment_macro::opaque]
+pub struct Engine { pub id: u32 }
+
+#[ferment_macro::export]
+pub struct Plain { pub x: u32 }
+
+pub struct Hidden;
+";
+
+const FMT: &str = "use std::fmt;
+use crate::model::{Engine, Hidden, Point};
+
+impl fmt::Display for crate::model::Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, \"{self:?}\") }
+}
+impl fmt::Debug for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, \"P{}\", self.x) }
+}
+impl ToString for Engine {
+    fn to_string(&self) -> String { format!(\"engine {}\", self.id) }
+}
+impl fmt::Display for Hidden {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, \"hidden\") }
+}
+";
+
+#[test]
+fn formatters_are_exposed() {
+    let (fermentate, diagnostics) = fermentate(fixture("formatters", "formatters", &[("lib.rs", LIB), ("model.rs", MODEL), ("fmt.rs", FMT)]));
+    assert!(diagnostics.is_empty(), "{diagnostics:?}");
+    // derived
+    assert_eq!(fn_item(&fermentate, "formatters_model_Status_debug_string").sig, parse_quote!(unsafe extern "C" fn formatters_model_Status_debug_string(obj: *const formatters_model_Status) -> *mut std::os::raw::c_char));
+    // implemented in the other module with the full path
+    let to_string = fn_item(&fermentate, "formatters_model_Status_to_string");
+    assert_eq!(to_string.sig, parse_quote!(unsafe extern "C" fn formatters_model_Status_to_string(obj: *const formatters_model_Status) -> *mut std::os::raw::c_char));
+    assert!(has_expr(to_string, &parse_quote!(ToString::to_string(&<formatters_model_Status as ferment::FFIConversionFrom<formatters::model::Status>>::ffi_from_const(obj)))));
+    // listed in the export attribute
+    assert!(has_item(&fermentate, "formatters_model_Version_to_string"));
+    // implemented in the other module with the imported path
+    assert!(has_item(&fermentate, "formatters_model_Point_debug_string"));
+    assert!(!has_item(&fermentate, "formatters_model_Point_to_string"));
+    // opaque types are passed as is
+    assert_eq!(fn_item(&fermentate, "formatters_model_Engine_to_string").sig, parse_quote!(unsafe extern "C" fn formatters_model_Engine_to_string(obj: *const formatters::model::Engine) -> *mut std::os::raw::c_char));
+    assert!(!has_item_containing(&fermentate, "Plain_to_string"));
+    assert!(!has_item_containing(&fermentate, "Hidden"));
+}
@@ -0,0 +1,42 @@
+use crate::test::{fermentate, fixture, has_item, has_item_containing, struct_item};
+
+const LIB: &str = "pub mod model;
+pub mod api;
+";
+
+const MODEL: &str = "#[ferment_macro::export]
+#[derive(Clone)]
+pub struct Identity { pub id: u32 }
+
+#[ferment_macro::export]
+#[derive(Clone)]
+pub struct Document { pub body: String }
+
+#[ferment_macro::export(instantiate(Page<Identity>))]
+#[derive(Clone)]
+pub struct Page<T> { pub items: Vec<T>, pub next: Option<u32> }
+
+#[ferment_macro::export]
+#[derive(Clone)]
+pub enum Response<T, E> { Ok(T), Err(E) }
+";
+
+const API: &str = "use crate::model::{Document, Page, Response};
+
+#[ferment_macro::export]
+pub fn documents(page: Page<Document>) -> Response<Page<Document>, String> { Response::Ok(page) }
+";
+
+#[test]
+fn generic_objects_are_fermented_per_instantiation() {
+    let (fermentate, diagnostics) = fermentate(fixture("generic_instances", "generic_instances", &[("lib.rs", LIB), ("model.rs", MODEL), ("api.rs", API)]));
+    assert!(diagnostics.is_empty(), "{diagnostics:?}");
+    // listed in the export attribute
+    assert!(struct_item(&fermentate, "generic_instances_model_Page_generic_instances_model_Identity").generics.params.is_empty());
+    // used in the exported signature
+    assert!(struct_item(&fermentate, "generic_instances_model_Page_generic_instances_model_Document").generics.params.is_empty());
+    assert!(has_item(&fermentate, "generic_instances_model_Response_generic_instances_model_Page_generic_instances_model_Document_String"));
+    // generic declarations themselves aren't fermented
+    assert!(!has_item(&fermentate, "generic_instances_model_Page"));
+    assert!(!has_item_containing(&fermentate, "generic_instances_model_Page_T"));
+}
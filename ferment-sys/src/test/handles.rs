@@ -0,0 +1,37 @@
+use syn::parse_quote;
+use crate::test::{fermentate, fixture, fn_item, has_item, has_item_containing};
+
+const LIB: &str = "pub mod model;
+";
+
+const MODEL: &str = "#[ferment_macro::export(handle)]
+#[derive(Clone)]
+pub struct State { pub count: u32, pub names: Vec<String> }
+
+#[ferment_macro::export]
+impl State {
+    pub fn total(&self) -> u32 { self.count }
+    pub fn increment(&mut self) { self.count += 1; }
+}
+
+#[ferment_macro::export]
+#[derive(Clone)]
+pub struct Plain { pub count: u32 }
+";
+
+#[test]
+fn handles_are_passed_as_boxed_values() {
+    let (fermentate, diagnostics) = fermentate(fixture("handles", "handles", &[("lib.rs", LIB), ("model.rs", MODEL)]));
+    assert!(diagnostics.is_empty(), "{diagnostics:?}");
+    // methods borrow the boxed value
+    assert_eq!(fn_item(&fermentate, "handles_model_State_total").sig, parse_quote!(unsafe extern "C" fn handles_model_State_total(self_: *const handles::model::State) -> u32));
+    assert_eq!(fn_item(&fermentate, "handles_model_State_increment").sig, parse_quote!(unsafe extern "C" fn handles_model_State_increment(self_: *mut handles::model::State)));
+    // the FFI struct is the snapshot
+    assert_eq!(fn_item(&fermentate, "handles_model_State_to_ffi").sig, parse_quote!(unsafe extern "C" fn handles_model_State_to_ffi(obj: *const handles::model::State) -> *mut handles_model_State));
+    assert_eq!(fn_item(&fermentate, "handles_model_State_from_ffi").sig, parse_quote!(unsafe extern "C" fn handles_model_State_from_ffi(ffi: *const handles_model_State) -> *mut handles::model::State));
+    assert_eq!(fn_item(&fermentate, "handles_model_State_handle_destroy").sig, parse_quote!(unsafe extern "C" fn handles_model_State_handle_destroy(obj: *mut handles::model::State)));
+    assert_eq!(fn_item(&fermentate, "handles_model_State_handle_get_count").sig, parse_quote!(unsafe extern "C" fn handles_model_State_handle_get_count(obj: *const handles::model::State) -> u32));
+    assert!(has_item(&fermentate, "handles_model_State_handle_set_names"));
+    assert!(!has_item_containing(&fermentate, "Plain_to_ffi"));
+    assert!(!has_item_containing(&fermentate, "Plain_handle_"));
+}
@@ -1,7 +1,9 @@
+use indexmap::{IndexMap, IndexSet};
 use quote::ToTokens;
-use syn::{parse_quote, UseTree};
+use syn::{parse_quote, Path, UseTree};
 use proc_macro2::Ident;
 use crate::context::{ImportResolver, ScopeChain};
+use crate::test::{fermentate, fixture, fn_item};
 
 #[test]
 fn fold_import_tree_simple_and_group() {
@@ -52,16 +54,116 @@ fn fold_import_tree_rename_and_ignore_underscore() {
 }
 
 #[test]
-fn fold_import_tree_glob_is_ignored() {
+fn fold_import_tree_glob_is_deferred() {
     let scope = ScopeChain::crate_root_with_ident(parse_quote!(my_crate), vec![]);
     let mut resolver = ImportResolver::default();
     // Prime with one import so map exists
     resolver.fold_import_tree(&scope, &parse_quote!(foo::Bar), Vec::<Ident>::new());
     let before_len = resolver.maybe_scope_imports(&scope).map(|m| m.len()).unwrap_or_default();
 
-    // use foo::*; should be ignored by resolver
+    // use foo::*; is recorded but can't be resolved until the tree is visited
     let glob: UseTree = parse_quote!(foo::*);
     resolver.fold_import_tree(&scope, &glob, Vec::<Ident>::new());
     let after_len = resolver.maybe_scope_imports(&scope).map(|m| m.len()).unwrap_or_default();
     assert_eq!(after_len, before_len);
+    let globs = resolver.globs.get(&scope).expect("globs present");
+    assert_eq!(globs.iter().map(|path| path.to_token_stream().to_string()).collect::<Vec<_>>(), vec!["foo"]);
+}
+
+fn modules(modules: &[(Path, &[&str])]) -> IndexMap<Path, IndexSet<Ident>> {
+    IndexMap::from_iter(modules.iter().map(|(path, names)| (path.clone(), IndexSet::from_iter(names.iter().map(|name| Ident::new(name, proc_macro2::Span::call_site()))))))
+}
+
+fn resolved(resolver: &ImportResolver, scope: &ScopeChain, name: &str) -> Option<String> {
+    let key: Path = syn::parse_str(name).unwrap();
+    resolver.maybe_scope_imports(scope)
+        .and_then(|imports| imports.get(&key))
+        .map(|path| path.to_token_stream().to_string())
+}
+
+#[test]
+fn resolve_globs_against_visited_modules() {
+    let root = ScopeChain::crate_root_with_ident(parse_quote!(my_crate), vec![]);
+    let mut resolver = ImportResolver::default();
+    // use crate::model::*; use external::types::*;
+    resolver.fold_import_tree(&root, &parse_quote!(crate::model::*), Vec::<Ident>::new());
+    resolver.fold_import_tree(&root, &parse_quote!(external::types::*), Vec::<Ident>::new());
+    resolver.resolve_globs(&modules(&[
+        (parse_quote!(my_crate), &["model"]),
+        (parse_quote!(my_crate::model), &["Model", "Status"]),
+        (parse_quote!(external::types), &["Hash"]),
+    ]));
+    assert_eq!(resolved(&resolver, &root, "Model").as_deref(), Some("my_crate :: model :: Model"));
+    assert_eq!(resolved(&resolver, &root, "Status").as_deref(), Some("my_crate :: model :: Status"));
+    assert_eq!(resolved(&resolver, &root, "Hash").as_deref(), Some("external :: types :: Hash"));
+}
+
+#[test]
+fn resolve_globs_precedence() {
+    let root = ScopeChain::crate_root_with_ident(parse_quote!(my_crate), vec![]);
+    let mut resolver = ImportResolver::default();
+    // Explicit import declared after the glob still wins
+    resolver.fold_import_tree(&root, &parse_quote!(a::*), Vec::<Ident>::new());
+    resolver.fold_import_tree(&root, &parse_quote!(b::*), Vec::<Ident>::new());
+    resolver.fold_import_tree(&root, &parse_quote!(c::Explicit), Vec::<Ident>::new());
+    resolver.resolve_globs(&modules(&[
+        (parse_quote!(my_crate), &["a", "b", "Local"]),
+        (parse_quote!(my_crate::a), &["Shared", "Explicit", "Local"]),
+        (parse_quote!(my_crate::b), &["Shared", "OnlyB"]),
+    ]));
+    assert_eq!(resolved(&resolver, &root, "Explicit").as_deref(), Some("c :: Explicit"));
+    // The first glob wins
+    assert_eq!(resolved(&resolver, &root, "Shared").as_deref(), Some("my_crate :: a :: Shared"));
+    assert_eq!(resolved(&resolver, &root, "OnlyB").as_deref(), Some("my_crate :: b :: OnlyB"));
+    // Items defined in the module shadow glob imports
+    assert_eq!(resolved(&resolver, &root, "Local"), None);
+}
+
+#[test]
+fn resolve_globs_follows_reexports() {
+    let root = ScopeChain::crate_root_with_ident(parse_quote!(my_crate), vec![]);
+    let model = ScopeChain::child_mod(vec![], parse_quote!(my_crate), &parse_quote!(model), &root);
+    let mut resolver = ImportResolver::default();
+    // lib.rs: use self::model::*;
+    resolver.fold_import_tree(&root, &parse_quote!(self::model::*), Vec::<Ident>::new());
+    // model.rs: pub use inner::*; pub use super::other::Other;
+    resolver.fold_import_tree(&model, &parse_quote!(inner::*), Vec::<Ident>::new());
+    resolver.fold_import_tree(&model, &parse_quote!(super::other::Other), Vec::<Ident>::new());
+    resolver.resolve_globs(&modules(&[
+        (parse_quote!(my_crate), &["model", "other"]),
+        (parse_quote!(my_crate::model), &["inner"]),
+        (parse_quote!(my_crate::model::inner), &["Deep"]),
+        (parse_quote!(my_crate::other), &["Other"]),
+    ]));
+    assert_eq!(resolved(&resolver, &model, "Deep").as_deref(), Some("my_crate :: model :: inner :: Deep"));
+    assert_eq!(resolved(&resolver, &root, "Deep").as_deref(), Some("my_crate :: model :: inner :: Deep"));
+    assert_eq!(resolved(&resolver, &root, "Other").as_deref(), Some("my_crate :: other :: Other"));
+}
+
+const GLOB_LIB: &str = "pub mod model;
+use crate::model::*;
+
+#[ferment_macro::export]
+pub fn model_id(model: Model) -> u32 { model.id }
+
+#[ferment_macro::export]
+pub fn deep_id(deep: Deep) -> u32 { deep.id }
+";
+
+const GLOB_MODEL: &str = "pub mod inner;
+pub use self::inner::*;
+
+#[ferment_macro::export]
+pub struct Model { pub id: u32 }
+";
+
+const GLOB_INNER: &str = "#[ferment_macro::export]
+pub struct Deep { pub id: u32 }
+";
+
+#[test]
+fn glob_imported_types_are_fermented() {
+    let (fermentate, _) = fermentate(fixture("imports_glob", "globs", &[("lib.rs", GLOB_LIB), ("model/mod.rs", GLOB_MODEL), ("model/inner.rs", GLOB_INNER)]));
+    assert_eq!(fn_item(&fermentate, "globs_model_id").sig, parse_quote!(unsafe extern "C" fn globs_model_id(model: *mut crate::fermented::types::globs::model::globs_model_Model) -> u32));
+    assert_eq!(fn_item(&fermentate, "globs_deep_id").sig, parse_quote!(unsafe extern "C" fn globs_deep_id(deep: *mut crate::fermented::types::globs::model::inner::globs_model_inner_Deep) -> u32));
 }
@@ -0,0 +1,95 @@
+use std::path::PathBuf;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use crate::{Config, FermentateLayout};
+use crate::lang::rust::Crate as RustCrate;
+use crate::writer::Writer;
+
+fn fermentate() -> TokenStream2 {
+    quote! {
+        #[allow(dead_code, non_camel_case_types)]
+        pub mod types {
+            pub mod my_crate {
+                use crate as my_crate;
+                pub mod model {
+                    use crate as my_crate;
+                    #[repr(C)]
+                    pub struct my_crate_model_Model { pub id: u32 }
+                    pub mod r#type {
+                        pub struct my_crate_model_type_Kind;
+                    }
+                }
+                pub mod rnt {
+                    #![allow(unused_imports)]
+                    pub fn my_crate_rnt_run() -> u32 { 0 }
+                }
+            }
+        }
+        #[allow(dead_code, non_camel_case_types)]
+        pub mod generics {
+            #[repr(C)]
+            pub struct Vec_u8 { pub count: usize, pub values: *mut u8 }
+        }
+    }
+}
+
+fn rust_files(layout: FermentateLayout) -> Vec<(PathBuf, String)> {
+    let mut config = Config::new("fermented", RustCrate::current_with_name("my_crate"), cbindgen::Config::default());
+    config.layout = layout;
+    Writer::from(config)
+        .rust_files(fermentate())
+        .expect("Fermentate layout")
+}
+
+#[test]
+fn compact_layout_is_single_line() {
+    let files = rust_files(FermentateLayout::Compact);
+    assert_eq!(files.len(), 1);
+    let (path, contents) = &files[0];
+    assert_eq!(path, &PathBuf::from("src/fermented.rs"));
+    assert!(!contents.contains('\n'));
+    assert!(syn::parse_file(contents).is_ok());
+}
+
+#[test]
+fn pretty_layout_is_formatted() {
+    let files = rust_files(FermentateLayout::Pretty);
+    assert_eq!(files.len(), 1);
+    let (path, contents) = &files[0];
+    assert_eq!(path, &PathBuf::from("src/fermented.rs"));
+    assert!(contents.contains("pub mod generics {\n"));
+    assert!(contents.contains("    pub struct Vec_u8 {\n        pub count: usize,\n"));
+    assert_eq!(&prettyplease::unparse(&syn::parse_file(contents).unwrap()), contents);
+}
+
+#[test]
+fn modules_layout_mirrors_module_tree() {
+    let files = rust_files(FermentateLayout::Modules);
+    let paths = files.iter().map(|(path, _)| path.to_str().unwrap()).collect::<Vec<_>>();
+    assert_eq!(paths, vec![
+        "src/fermented/mod.rs",
+        "src/fermented/types/mod.rs",
+        "src/fermented/types/my_crate/mod.rs",
+        "src/fermented/types/my_crate/model/mod.rs",
+        "src/fermented/types/my_crate/model/type.rs",
+        "src/fermented/types/my_crate/rnt.rs",
+        "src/fermented/generics.rs",
+    ]);
+    for (path, contents) in &files {
+        let file = syn::parse_file(contents).unwrap_or_else(|err| panic!("{}: {err}", path.display()));
+        let has_inline_modules = file.items.iter()
+            .any(|item| matches!(item, syn::Item::Mod(syn::ItemMod { content: Some(..), .. })));
+        assert!(!has_inline_modules, "{}", path.display());
+    }
+    let contents = |path: &str| files.iter().find(|(file_path, _)| file_path.to_str() == Some(path)).map(|(_, contents)| contents.as_str()).unwrap();
+    assert_eq!(contents("src/fermented/mod.rs"), "#[allow(dead_code, non_camel_case_types)]\npub mod types;\n#[allow(dead_code, non_camel_case_types)]\npub mod generics;\n");
+    assert!(contents("src/fermented/types/my_crate/model/mod.rs").contains("pub mod r#type;\n"));
+    assert!(contents("src/fermented/types/my_crate/rnt.rs").starts_with("#![allow(unused_imports)]\n"));
+}
+
+#[test]
+fn layouts_are_stable() {
+    for layout in [FermentateLayout::Compact, FermentateLayout::Pretty, FermentateLayout::Modules] {
+        assert_eq!(rust_files(layout), rust_files(layout));
+    }
+}
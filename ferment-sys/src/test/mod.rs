@@ -7,4 +7,238 @@ mod resolver_generic;
 mod resolver_scope;
 mod scope_search_key;
 mod type_chain;
+mod async_binding;
 mod visit_scope;
+mod layout;
+mod determinism;
+mod staleness;
+mod cbindgen;
+mod diagnostics;
+mod visibility;
+mod module_export;
+mod cfg;
+mod modules;
+mod external_crates;
+mod consts;
+mod formatters;
+mod trait_defaults;
+mod enum_reprs;
+mod fieldless_enums;
+mod trait_proxies;
+mod generic_instances;
+mod handles;
+
+use std::fs;
+use std::path::PathBuf;
+use syn::{Attribute, Expr, Field, File, Item, ItemEnum, ItemFn, ItemImpl, ItemStatic, ItemStruct, Path, Type, Variant};
+use syn::visit::Visit;
+use crate::{Config, Crate, Diagnostic, Error};
+use crate::tree::FileTreeProcessor;
+use crate::writer::Writer;
+
+/// Config of the crate `name` with the `files` written into `{temp_dir}/ferment_{dir}/src`
+pub(crate) fn fixture(dir: &str, name: &str, files: &[(&str, &str)]) -> Config {
+    let root = fixture_root(dir);
+    files.iter().for_each(|(file, content)| {
+        let path = root.join(file);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    });
+    Config::new("fermented", Crate::new(name, root), ::cbindgen::Config::default())
+}
+
+pub(crate) fn fixture_root(dir: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("ferment_{dir}")).join("src")
+}
+
+/// Parsed fermentate of the crate along with the diagnostics reported while building the tree
+pub(crate) fn try_fermentate(config: Config) -> (Result<File, Error>, Vec<Diagnostic>) {
+    let crate_tree = FileTreeProcessor::build(&config)
+        .expect("Crate tree");
+    let fermentate = Writer::from(config)
+        .rust_fermentate(&crate_tree)
+        .and_then(|fermentate| syn::parse2(fermentate).map_err(Error::from));
+    (fermentate, crate_tree.diagnostics())
+}
+
+pub(crate) fn fermentate(config: Config) -> (File, Vec<Diagnostic>) {
+    let (fermentate, diagnostics) = try_fermentate(config);
+    (fermentate.expect("Fermentate"), diagnostics)
+}
+
+/// Items of the file including the ones of the nested modules
+pub(crate) fn items(file: &File) -> Vec<&Item> {
+    fn collect<'a>(items: &'a [Item], acc: &mut Vec<&'a Item>) {
+        items.iter().for_each(|item| {
+            acc.push(item);
+            if let Item::Mod(syn::ItemMod { content: Some((_, content)), .. }) = item {
+                collect(content, acc);
+            }
+        });
+    }
+    let mut acc = Vec::new();
+    collect(&file.items, &mut acc);
+    acc
+}
+
+pub(crate) fn item_ident(item: &Item) -> Option<String> {
+    match item {
+        Item::Const(item) => Some(item.ident.to_string()),
+        Item::Enum(item) => Some(item.ident.to_string()),
+        Item::Fn(item) => Some(item.sig.ident.to_string()),
+        Item::Mod(item) => Some(item.ident.to_string()),
+        Item::Static(item) => Some(item.ident.to_string()),
+        Item::Struct(item) => Some(item.ident.to_string()),
+        Item::Trait(item) => Some(item.ident.to_string()),
+        Item::Type(item) => Some(item.ident.to_string()),
+        Item::Union(item) => Some(item.ident.to_string()),
+        _ => None
+    }
+}
+
+pub(crate) fn has_item(file: &File, ident: &str) -> bool {
+    items(file).into_iter().any(|item| item_ident(item).is_some_and(|item_ident| item_ident == ident))
+}
+
+pub(crate) fn has_item_containing(file: &File, part: &str) -> bool {
+    items(file).into_iter().any(|item| item_ident(item).is_some_and(|item_ident| item_ident.contains(part)))
+}
+
+pub(crate) fn fn_item<'a>(file: &'a File, ident: &str) -> &'a ItemFn {
+    items(file).into_iter()
+        .find_map(|item| match item {
+            Item::Fn(item) if item.sig.ident == ident => Some(item),
+            _ => None
+        })
+        .unwrap_or_else(|| panic!("fn `{ident}` isn't fermented"))
+}
+
+pub(crate) fn struct_item<'a>(file: &'a File, ident: &str) -> &'a ItemStruct {
+    items(file).into_iter()
+        .find_map(|item| match item {
+            Item::Struct(item) if item.ident == ident => Some(item),
+            _ => None
+        })
+        .unwrap_or_else(|| panic!("struct `{ident}` isn't fermented"))
+}
+
+pub(crate) fn enum_item<'a>(file: &'a File, ident: &str) -> &'a ItemEnum {
+    items(file).into_iter()
+        .find_map(|item| match item {
+            Item::Enum(item) if item.ident == ident => Some(item),
+            _ => None
+        })
+        .unwrap_or_else(|| panic!("enum `{ident}` isn't fermented"))
+}
+
+pub(crate) fn static_item<'a>(file: &'a File, ident: &str) -> &'a ItemStatic {
+    items(file).into_iter()
+        .find_map(|item| match item {
+            Item::Static(item) if item.ident == ident => Some(item),
+            _ => None
+        })
+        .unwrap_or_else(|| panic!("static `{ident}` isn't fermented"))
+}
+
+/// Implementation of the trait (or the inherent one if `trait_path` is `None`) for the type
+pub(crate) fn find_impl<'a>(file: &'a File, trait_path: Option<&Path>, self_ty: &Type) -> Option<&'a ItemImpl> {
+    items(file).into_iter()
+        .find_map(|item| match item {
+            Item::Impl(item) if *item.self_ty == *self_ty && item.trait_.as_ref().map(|(_, path, _)| path) == trait_path => Some(item),
+            _ => None
+        })
+}
+
+pub(crate) fn impl_fn<'a>(item: &'a ItemImpl, ident: &str) -> &'a syn::ImplItemFn {
+    item.items.iter()
+        .find_map(|item| match item {
+            syn::ImplItem::Fn(item) if item.sig.ident == ident => Some(item),
+            _ => None
+        })
+        .unwrap_or_else(|| panic!("fn `{ident}` isn't implemented"))
+}
+
+/// Syntax nodes whose expressions can be searched
+pub(crate) trait Visited {
+    fn visit<'ast, V: Visit<'ast>>(&'ast self, visitor: &mut V);
+}
+impl Visited for File {
+    fn visit<'ast, V: Visit<'ast>>(&'ast self, visitor: &mut V) { visitor.visit_file(self) }
+}
+impl Visited for ItemFn {
+    fn visit<'ast, V: Visit<'ast>>(&'ast self, visitor: &mut V) { visitor.visit_item_fn(self) }
+}
+impl Visited for ItemImpl {
+    fn visit<'ast, V: Visit<'ast>>(&'ast self, visitor: &mut V) { visitor.visit_item_impl(self) }
+}
+impl Visited for syn::ImplItemFn {
+    fn visit<'ast, V: Visit<'ast>>(&'ast self, visitor: &mut V) { visitor.visit_impl_item_fn(self) }
+}
+
+pub(crate) fn any_expr<N: Visited, P: Fn(&Expr) -> bool>(node: &N, predicate: P) -> bool {
+    struct Search<P> { predicate: P, found: bool }
+    impl<'ast, P: Fn(&Expr) -> bool> Visit<'ast> for Search<P> {
+        fn visit_expr(&mut self, expr: &'ast Expr) {
+            self.found |= (self.predicate)(expr);
+            if !self.found {
+                syn::visit::visit_expr(self, expr);
+            }
+        }
+    }
+    let mut search = Search { predicate, found: false };
+    node.visit(&mut search);
+    search.found
+}
+
+pub(crate) fn any_path<N: Visited, P: Fn(&Path) -> bool>(node: &N, predicate: P) -> bool {
+    struct Search<P> { predicate: P, found: bool }
+    impl<'ast, P: Fn(&Path) -> bool> Visit<'ast> for Search<P> {
+        fn visit_path(&mut self, path: &'ast Path) {
+            self.found |= (self.predicate)(path);
+            if !self.found {
+                syn::visit::visit_path(self, path);
+            }
+        }
+    }
+    let mut search = Search { predicate, found: false };
+    node.visit(&mut search);
+    search.found
+}
+
+pub(crate) fn has_expr<N: Visited>(node: &N, expr: &Expr) -> bool {
+    any_expr(node, |candidate| candidate == expr)
+}
+
+/// Items are compared with the expected ones regardless of their doc comments
+pub(crate) trait Undocumented: Clone {
+    fn attrs_mut(&mut self) -> &mut Vec<Attribute>;
+    fn undocumented(&self) -> Self {
+        let mut item = self.clone();
+        item.attrs_mut().retain(|attr| !attr.path().is_ident("doc"));
+        item
+    }
+}
+impl Undocumented for ItemEnum {
+    fn attrs_mut(&mut self) -> &mut Vec<Attribute> { &mut self.attrs }
+}
+impl Undocumented for ItemFn {
+    fn attrs_mut(&mut self) -> &mut Vec<Attribute> { &mut self.attrs }
+}
+impl Undocumented for ItemStatic {
+    fn attrs_mut(&mut self) -> &mut Vec<Attribute> { &mut self.attrs }
+}
+impl Undocumented for ItemStruct {
+    fn attrs_mut(&mut self) -> &mut Vec<Attribute> { &mut self.attrs }
+}
+
+pub(crate) fn field<'a>(item: &'a ItemStruct, ident: &str) -> &'a Field {
+    item.fields.iter()
+        .find(|field| field.ident.as_ref().is_some_and(|field_ident| field_ident == ident))
+        .unwrap_or_else(|| panic!("field `{ident}` isn't fermented"))
+}
+
+pub(crate) fn variant<'a>(item: &'a ItemEnum, ident: &str) -> &'a Variant {
+    item.variants.iter()
+        .find(|variant| variant.ident == ident)
+        .unwrap_or_else(|| panic!("variant `{ident}` isn't fermented"))
+}
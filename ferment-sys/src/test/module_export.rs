@@ -0,0 +1,73 @@
+use crate::test::{fermentate, fixture, has_item, has_item_containing};
+
+const LIB: &str = "pub mod file;
+
+#[ferment_macro::export]
+pub mod model {
+    pub struct Exported { pub id: u32 }
+
+    impl Exported {
+        pub fn id(&self) -> u32 { self.id }
+    }
+
+    pub fn make(id: u32) -> Exported { Exported { id } }
+
+    #[ferment_macro::opaque]
+    pub struct Handle { pub id: u32 }
+
+    #[ferment_macro::skip]
+    pub struct Internal { pub id: u32 }
+
+    #[rustfmt::skip]
+    pub struct Formatted { pub id: u32 }
+
+    struct Private { pub id: u32 }
+
+    pub mod nested {
+        pub fn nested_fn() -> u32 { 0 }
+    }
+}
+
+pub mod plain {
+    pub fn plain_fn() -> u32 { 0 }
+}
+";
+
+const FILE: &str = "#![cfg_attr(ferment, ferment_macro::export)]
+
+pub mod sub;
+
+pub struct InFile { pub id: u32 }
+
+#[ferment_macro::skip]
+pub mod skipped {
+    pub fn skipped_fn() -> u32 { 0 }
+}
+";
+
+const SUB: &str = "pub fn sub_fn() -> u32 { 0 }
+";
+
+#[test]
+fn module_export() {
+    let (fermentate, diagnostics) = fermentate(fixture("module_export", "module_export", &[("lib.rs", LIB), ("file.rs", FILE), ("file/sub.rs", SUB)]));
+    assert!(diagnostics.is_empty(), "{diagnostics:?}");
+    // inline module
+    assert!(has_item(&fermentate, "module_export_model_Exported"));
+    assert!(has_item(&fermentate, "module_export_model_Exported_id"));
+    assert!(has_item(&fermentate, "module_export_model_make"));
+    assert!(has_item(&fermentate, "module_export_model_nested_nested_fn"));
+    // labeled items are left as is
+    assert!(!has_item(&fermentate, "module_export_model_Handle"));
+    // only ferment's skip label is taken into account
+    assert!(has_item(&fermentate, "module_export_model_Formatted"));
+    // skipped and private items
+    assert!(!has_item_containing(&fermentate, "module_export_model_Internal"));
+    assert!(!has_item_containing(&fermentate, "module_export_model_Private"));
+    // file modules
+    assert!(has_item(&fermentate, "module_export_file_InFile"));
+    assert!(has_item(&fermentate, "module_export_file_sub_sub_fn"));
+    assert!(!has_item_containing(&fermentate, "skipped_fn"));
+    // modules which aren't labeled
+    assert!(!has_item_containing(&fermentate, "plain_fn"));
+}
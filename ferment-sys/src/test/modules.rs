@@ -0,0 +1,61 @@
+use crate::Crate;
+use crate::test::{fermentate, fixture, fixture_root, has_item};
+
+const FFI: &str = "pub mod a;
+#[path = \"impls/custom.rs\"]
+pub mod custom;
+pub mod missing;
+
+pub mod outer {
+    pub mod inner;
+}
+";
+
+const A: &str = "pub mod b;
+pub mod inline {
+    pub mod c;
+    pub mod nested {
+        #[path = \"other.rs\"]
+        pub mod d;
+    }
+}
+#[path = \"sibling.rs\"]
+pub mod e;
+";
+
+fn export_fn(name: &str) -> String {
+    format!("#[ferment_macro::export]\npub fn {name}() -> u32 {{ 0 }}\n")
+}
+
+#[test]
+fn module_files() {
+    let custom = format!("pub mod child;\n{}", export_fn("in_custom"));
+    let mut config = fixture("modules", "modules", &[
+        ("ffi.rs", FFI),
+        ("a.rs", A),
+        ("a/b.rs", &export_fn("in_b")),
+        ("a/inline/c.rs", &export_fn("in_c")),
+        ("a/inline/nested/other.rs", &export_fn("in_d")),
+        ("sibling.rs", &export_fn("in_e")),
+        ("impls/custom.rs", &custom),
+        ("impls/child.rs", &export_fn("in_child")),
+        ("outer/inner.rs", &export_fn("in_inner")),
+    ]);
+    let root_file = fixture_root("modules").join("ffi.rs");
+    config.current_crate = Crate::with_root_file("modules", root_file.clone());
+    let (fermentate, diagnostics) = fermentate(config);
+    // non-mod.rs file: child modules are in the directory named after the file
+    assert!(has_item(&fermentate, "modules_a_b_in_b"));
+    // out-of-line modules inside the inline ones
+    assert!(has_item(&fermentate, "modules_a_inline_c_in_c"));
+    assert!(has_item(&fermentate, "modules_outer_inner_in_inner"));
+    // `#[path]` inside the inline modules is relative to the module directory, otherwise to the directory of the file
+    assert!(has_item(&fermentate, "modules_a_inline_nested_d_in_d"));
+    assert!(has_item(&fermentate, "modules_a_e_in_e"));
+    // `#[path]` file owns its directory
+    assert!(has_item(&fermentate, "modules_custom_in_custom"));
+    assert!(has_item(&fermentate, "modules_custom_child_in_child"));
+    assert_eq!(diagnostics.len(), 1, "{diagnostics:?}");
+    assert_eq!(diagnostics[0].item, "modules::missing");
+    assert_eq!(diagnostics[0].file, Some(root_file));
+}
@@ -1,6 +1,6 @@
 use quote::ToTokens;
-use syn::{parse_quote, Ident};
-use crate::context::ScopeChain;
+use syn::{parse_quote, Ident, Path};
+use crate::context::{Scope, ScopeChain, ScopeInfo};
 
 #[test]
 fn scope_chain_child_paths_and_display() {
@@ -24,3 +24,73 @@ fn scope_chain_child_paths_and_display() {
     let mid = child.fmt_mid();
     assert!(mid.contains("utils"));
 }
+
+fn normalized(scope: &ScopeChain, path: Path) -> String {
+    scope.normalize_path(&path).to_token_stream().to_string()
+}
+
+fn info(path: Path) -> ScopeInfo {
+    ScopeInfo::new(vec![], parse_quote!(my_crate), Scope::empty(path))
+}
+
+/// my_crate::outer::inner
+fn nested_mod() -> ScopeChain {
+    let root = ScopeChain::crate_root_with_ident(parse_quote!(my_crate), vec![]);
+    let outer = ScopeChain::child_mod(vec![], parse_quote!(my_crate), &parse_quote!(outer), &root);
+    ScopeChain::child_mod(vec![], parse_quote!(my_crate), &parse_quote!(inner), &outer)
+}
+
+#[test]
+fn normalize_path_in_crate_root() {
+    let root = ScopeChain::crate_root_with_ident(parse_quote!(my_crate), vec![]);
+    assert_eq!(normalized(&root, parse_quote!(crate::model::Model)), "my_crate :: model :: Model");
+    assert_eq!(normalized(&root, parse_quote!(self::model::Model)), "my_crate :: model :: Model");
+    // Can't go above the crate root
+    assert_eq!(normalized(&root, parse_quote!(super::Model)), "super :: Model");
+    // Crate root created with `crate` path
+    let root = ScopeChain::crate_root(parse_quote!(my_crate), vec![]);
+    assert_eq!(normalized(&root, parse_quote!(self::Model)), "my_crate :: Model");
+}
+
+#[test]
+fn normalize_path_in_nested_mods() {
+    let inner = nested_mod();
+    assert_eq!(normalized(&inner, parse_quote!(crate::Model)), "my_crate :: Model");
+    assert_eq!(normalized(&inner, parse_quote!(self::Model)), "my_crate :: outer :: inner :: Model");
+    assert_eq!(normalized(&inner, parse_quote!(super::Model)), "my_crate :: outer :: Model");
+    assert_eq!(normalized(&inner, parse_quote!(super::super::model::Model)), "my_crate :: model :: Model");
+    assert_eq!(normalized(&inner, parse_quote!(self::super::Model)), "my_crate :: outer :: Model");
+    // Generic arguments are kept
+    assert_eq!(normalized(&inner, parse_quote!(super::Wrapper<u32>)), "my_crate :: outer :: Wrapper < u32 >");
+    // Nothing to normalize
+    assert_eq!(normalized(&inner, parse_quote!(Model)), "Model");
+    assert_eq!(normalized(&inner, parse_quote!(std::collections::HashMap)), "std :: collections :: HashMap");
+    assert_eq!(normalized(&inner, parse_quote!(::external::Model)), ":: external :: Model");
+}
+
+#[test]
+fn normalize_path_in_object_and_impl() {
+    let inner = nested_mod();
+    let object = ScopeChain::object(info(parse_quote!(my_crate::outer::inner::Model)), inner.clone());
+    let r#impl = ScopeChain::r#impl(info(parse_quote!(my_crate::outer::inner::Model)), inner);
+    let method = ScopeChain::r#fn(info(parse_quote!(my_crate::outer::inner::Model::method)), r#impl.clone());
+    // `self` and `super` refer to the module containing the object, not to the object itself
+    for scope in [&object, &r#impl, &method] {
+        assert_eq!(normalized(scope, parse_quote!(self::Other)), "my_crate :: outer :: inner :: Other");
+        assert_eq!(normalized(scope, parse_quote!(super::Other)), "my_crate :: outer :: Other");
+        assert_eq!(normalized(scope, parse_quote!(super::super::Other)), "my_crate :: Other");
+    }
+}
+
+#[test]
+fn normalize_path_in_trait_and_fn() {
+    let inner = nested_mod();
+    let r#trait = ScopeChain::r#trait(info(parse_quote!(my_crate::outer::inner::Trait)), inner.clone());
+    let trait_fn = ScopeChain::r#fn(info(parse_quote!(my_crate::outer::inner::Trait::method)), r#trait.clone());
+    let r#fn = ScopeChain::r#fn(info(parse_quote!(my_crate::outer::inner::function)), inner);
+    for scope in [&r#trait, &trait_fn, &r#fn] {
+        assert_eq!(normalized(scope, parse_quote!(self::Other)), "my_crate :: outer :: inner :: Other");
+        assert_eq!(normalized(scope, parse_quote!(super::Other)), "my_crate :: outer :: Other");
+        assert_eq!(normalized(scope, parse_quote!(crate::Other)), "my_crate :: Other");
+    }
+}
@@ -0,0 +1,71 @@
+use std::path::PathBuf;
+use crate::{Error, StaleFile};
+
+const FERMENTATE: &str = "pub mod types { pub mod my_crate { pub struct my_crate_A { pub a: u32 } impl Drop for my_crate_A { fn drop(&mut self) {} } pub fn my_crate_a_ctor() {} } } pub mod generics { pub struct Vec_u8; }";
+
+#[test]
+fn identical_files_are_fresh() {
+    assert_eq!(StaleFile::rust(PathBuf::from("src/fermented.rs"), FERMENTATE, Some(FERMENTATE)), None);
+    assert_eq!(StaleFile::header(PathBuf::from("target/include/my_crate.h"), "struct A;\n", Some("struct A;\n")), None);
+}
+
+#[test]
+fn missing_file_lists_all_items() {
+    let stale = StaleFile::rust(PathBuf::from("src/fermented.rs"), FERMENTATE, None).unwrap();
+    assert_eq!(stale.added, vec![
+        "types::my_crate::my_crate_A",
+        "types::my_crate::impl Drop for my_crate_A",
+        "types::my_crate::my_crate_a_ctor",
+        "generics::Vec_u8",
+    ]);
+    assert!(stale.removed.is_empty());
+    assert!(stale.changed.is_empty());
+}
+
+#[test]
+fn differing_items_are_listed() {
+    let actual = "pub mod types { pub mod my_crate { pub struct my_crate_A { pub a: u64 } impl Drop for my_crate_A { fn drop(&mut self) {} } pub fn my_crate_b_ctor() {} } } pub mod generics { pub struct Vec_u8; }";
+    let stale = StaleFile::rust(PathBuf::from("src/fermented.rs"), FERMENTATE, Some(actual)).unwrap();
+    assert_eq!(stale.added, vec!["types::my_crate::my_crate_a_ctor"]);
+    assert_eq!(stale.removed, vec!["types::my_crate::my_crate_b_ctor"]);
+    assert_eq!(stale.changed, vec!["types::my_crate::my_crate_A"]);
+}
+
+#[test]
+fn formatting_only_difference_is_stale() {
+    let pretty = prettyplease::unparse(&syn::parse_file(FERMENTATE).unwrap());
+    let stale = StaleFile::rust(PathBuf::from("src/fermented.rs"), FERMENTATE, Some(&pretty)).unwrap();
+    assert!(stale.added.is_empty() && stale.removed.is_empty() && stale.changed.is_empty());
+}
+
+#[test]
+fn header_lines_are_compared() {
+    let stale = StaleFile::header(PathBuf::from("target/include/my_crate.h"), "struct A;\nvoid a_ctor(void);\n", Some("struct A;\nvoid b_ctor(void);\n")).unwrap();
+    assert_eq!(stale.added, vec!["2: void a_ctor(void);"]);
+    assert_eq!(stale.removed, vec!["2: void b_ctor(void);"]);
+}
+
+#[test]
+fn header_duplicates_are_reported() {
+    let stale = StaleFile::header(PathBuf::from("target/include/my_crate.h"), "struct A;\nstruct B;\n", Some("struct A;\nstruct A;\nstruct B;\n")).unwrap();
+    assert!(stale.added.is_empty());
+    assert_eq!(stale.removed, vec!["2: struct A;"]);
+}
+
+#[test]
+fn header_reorders_are_reported() {
+    let stale = StaleFile::header(PathBuf::from("target/include/my_crate.h"), "struct A;\nstruct B;\n", Some("struct B;\nstruct A;\n")).unwrap();
+    assert_eq!(stale.added, vec!["1: struct A;"]);
+    assert_eq!(stale.removed, vec!["2: struct A;"]);
+}
+
+#[test]
+fn stale_error_lists_files() {
+    let redundant = StaleFile::redundant(PathBuf::from("src/fermented/mod.rs"), "pub mod generics { pub struct Vec_u8; }");
+    assert_eq!(redundant.removed, vec!["generics::Vec_u8"]);
+    let error = Error::Stale(vec![
+        StaleFile::rust(PathBuf::from("src/fermented.rs"), "pub struct A;", Some("pub struct B;")).unwrap(),
+        redundant
+    ]);
+    assert_eq!(error.to_string(), "Fermentate is out of date:\nsrc/fermented.rs\n\t+ A\n\t- B\nsrc/fermented/mod.rs\n\t- generics::Vec_u8");
+}
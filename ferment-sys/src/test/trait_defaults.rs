@@ -0,0 +1,38 @@
+use std::path::PathBuf;
+use syn::{parse_quote, Expr, FieldValue, File, Type};
+use crate::{Config, Crate};
+use crate::test::{any_expr, fermentate, field, has_expr, struct_item};
+
+// Entries of the vtable provided by the Rust implementor
+fn has_vtable_entries(fermentate: &File, implementor: &str) -> bool {
+    let entry = |method: &str| format!("example_traits_transport_transport_request_Backoff_example_traits_transport_transport_request_{implementor}_{method}");
+    let expected: [FieldValue; 3] = [
+        syn::parse_str(&format!("base_delay_ms: {}", entry("base_delay_ms"))).unwrap(),
+        syn::parse_str(&format!("max_retries: Some({})", entry("max_retries"))).unwrap(),
+        syn::parse_str(&format!("delay_ms: Some({})", entry("delay_ms"))).unwrap(),
+    ];
+    any_expr(fermentate, |expr| matches!(expr, Expr::Struct(expr) if expected.iter().all(|value| expr.fields.iter().any(|field| field == value))))
+}
+
+#[test]
+fn default_methods_are_in_vtables() {
+    let root_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../ferment-example/traits/src");
+    let (fermentate, _) = fermentate(Config::new("fermented", Crate::new("example_traits", root_path), cbindgen::Config::default()));
+    // required methods can't be null, default ones are optional for the foreign implementors
+    let vtable = struct_item(&fermentate, "example_traits_transport_transport_request_Backoff_VTable");
+    let is_optional = |ident: &str| match &field(vtable, ident).ty {
+        Type::Path(ty) => ty.path.segments.last().is_some_and(|segment| segment.ident == "Option"),
+        Type::BareFn(ty) => {
+            assert!(ty.unsafety.is_some() && ty.abi.is_some(), "`{ident}` isn't an `unsafe extern \"C\" fn`");
+            false
+        },
+        ty => panic!("Unexpected vtable entry `{ident}`: {ty:?}")
+    };
+    assert!(!is_optional("base_delay_ms"));
+    assert!(is_optional("max_retries"));
+    assert!(is_optional("delay_ms"));
+    // Rust implementors provide the entries for the default methods they don't override
+    assert!(has_vtable_entries(&fermentate, "CoreGrpcClient"));
+    assert!(has_vtable_entries(&fermentate, "Identifier"));
+    assert!(has_expr(&fermentate, &parse_quote!(<example_traits::transport::transport_request::CoreGrpcClient as example_traits::transport::transport_request::Backoff>::delay_ms)));
+}
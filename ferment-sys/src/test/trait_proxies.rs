@@ -0,0 +1,86 @@
+use std::path::PathBuf;
+use syn::{parse_quote, Expr, Fields, Type};
+use crate::{Config, Crate};
+use crate::test::{any_expr, fermentate, find_impl, fixture, fn_item, has_expr, has_item, has_item_containing, impl_fn, struct_item};
+
+#[test]
+fn foreign_implementations_go_through_proxies() {
+    let root_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../ferment-example/traits/src");
+    let (fermentate, _) = fermentate(Config::new("fermented", Crate::new("example_traits", root_path), cbindgen::Config::default()));
+    let proxy: Type = parse_quote!(example_traits_transport_transport_request_Backoff_Proxy);
+    let backoff = parse_quote!(example_traits::transport::transport_request::Backoff);
+    // the proxy implements the trait on top of the foreign vtable and owns the foreign context
+    assert_eq!(struct_item(&fermentate, "example_traits_transport_transport_request_Backoff_Proxy").fields, Fields::Named(parse_quote!({
+        object: example_traits_transport_transport_request_Backoff,
+        context_destructor: Option<unsafe extern "C" fn(object: *const ())>
+    })));
+    let proxy_impl = find_impl(&fermentate, Some(&backoff), &proxy)
+        .expect("Backoff for the proxy");
+    let drop_impl = find_impl(&fermentate, Some(&parse_quote!(Drop)), &proxy)
+        .expect("Drop for the proxy");
+    assert_eq!(*impl_fn(drop_impl, "drop"), parse_quote! {
+        fn drop(&mut self) {
+            unsafe {
+                if let Some(context_destructor) = self.context_destructor {
+                    context_destructor(self.object.object)
+                };
+            }
+        }
+    });
+    assert_eq!(fn_item(&fermentate, "example_traits_transport_transport_request_Backoff_Proxy_ctor").sig, parse_quote! {
+        unsafe extern "C" fn example_traits_transport_transport_request_Backoff_Proxy_ctor(
+            object: *const (),
+            vtable: *const example_traits_transport_transport_request_Backoff_VTable,
+            context_destructor: Option<unsafe extern "C" fn(object: *const ())>
+        ) -> *mut example_traits_transport_transport_request_Backoff_Proxy
+    });
+    assert_eq!(fn_item(&fermentate, "example_traits_transport_transport_request_Backoff_Proxy_destroy").sig, parse_quote! {
+        unsafe extern "C" fn example_traits_transport_transport_request_Backoff_Proxy_destroy(ffi: *mut example_traits_transport_transport_request_Backoff_Proxy)
+    });
+    // null default entries fall back to the Rust implementation
+    let max_retries = impl_fn(proxy_impl, "max_retries");
+    let entry: Expr = parse_quote!((*self.object.vtable).max_retries);
+    assert!(any_expr(max_retries, |expr| matches!(expr, Expr::Match(expr) if *expr.expr == entry)));
+    assert!(has_expr(max_retries, &parse_quote! {
+        <example_traits_transport_transport_request_Backoff_Proxy_Defaults_max_retries as example_traits::transport::transport_request::Backoff>::max_retries(
+            &example_traits_transport_transport_request_Backoff_Proxy_Defaults_max_retries::Ref(self),
+        )
+    }));
+    // the default body calls the other methods through the proxy
+    let defaults_impl = find_impl(&fermentate, Some(&backoff), &parse_quote!(example_traits_transport_transport_request_Backoff_Proxy_Defaults_delay_ms<'_>))
+        .expect("Backoff for the defaults of `delay_ms`");
+    assert!(has_expr(impl_fn(defaults_impl, "base_delay_ms"), &parse_quote! {
+        <example_traits_transport_transport_request_Backoff_Proxy as example_traits::transport::transport_request::Backoff>::base_delay_ms(proxy,)
+    }));
+    let mut_self: Type = parse_quote!(*mut Self);
+    assert!(!any_expr(&fermentate, |expr| matches!(expr, Expr::Cast(expr) if *expr.ty == mut_self)));
+    // the trait without default methods doesn't need a fallback
+    assert!(has_item(&fermentate, "example_traits_transport_transport_request_CanRetry_Proxy_ctor"));
+    assert!(!has_item_containing(&fermentate, "example_traits_transport_transport_request_CanRetry_Proxy_Defaults"));
+    // the proxy is accepted wherever the trait is expected
+    assert!(has_expr(&fermentate, &parse_quote!(example_traits::transport::transport_request::total_delay_ms(*Box::from_raw(backoff)))));
+    assert!(has_expr(&fermentate, &parse_quote!(example_traits::transport::transport_request::should_retry(&*policy))));
+    assert!(has_expr(&fermentate, &parse_quote!(example_traits::transport::transport_request::shared_backoff_delay_ms(std::sync::Arc::new(*Box::from_raw(backoff))))));
+    // the proxy is thread-safe only where the accepting signature requires it
+    let can_retry_proxy: Type = parse_quote!(example_traits_transport_transport_request_CanRetry_Proxy);
+    assert!(find_impl(&fermentate, Some(&parse_quote!(Send)), &proxy).is_some_and(|item| item.unsafety.is_some()));
+    assert!(find_impl(&fermentate, Some(&parse_quote!(Sync)), &proxy).is_some_and(|item| item.unsafety.is_some()));
+    assert!(find_impl(&fermentate, Some(&parse_quote!(Send)), &can_retry_proxy).is_none());
+    assert!(find_impl(&fermentate, Some(&parse_quote!(Sync)), &can_retry_proxy).is_none());
+}
+
+const BY_VALUE: &str = "#[ferment_macro::export]
+pub trait Consumer {
+    fn name(&self) -> u32;
+    fn consume(self) -> u32;
+}
+";
+
+#[test]
+fn by_value_receivers_are_reported() {
+    let (fermentate, diagnostics) = fermentate(fixture("trait_proxies_by_value", "trait_proxies_by_value", &[("lib.rs", BY_VALUE)]));
+    assert!(!has_item_containing(&fermentate, "Consumer_Proxy"));
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line, Some(4));
+    assert!(diagnostics[0].message.contains("`Consumer::consume` takes `self` by value"));
+}
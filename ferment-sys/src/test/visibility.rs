@@ -0,0 +1,104 @@
+use syn::{parse_quote, File, Path, Visibility};
+use crate::Diagnostic;
+use crate::context::is_visible_from;
+use crate::test::{any_path, fermentate, find_impl, fixture, fn_item, has_item, has_item_containing, struct_item};
+
+const LIB: &str = "pub mod model;
+
+pub mod api {
+    mod imp {
+        #[ferment_macro::export]
+        pub struct Reexported { pub count: u32 }
+
+        #[ferment_macro::export]
+        pub fn not_reexported() -> u32 { 0 }
+    }
+    pub use self::imp::Reexported;
+}
+
+#[ferment_macro::export]
+pub fn count(reexported: crate::api::Reexported) -> u32 { reexported.count }
+";
+
+const MODEL: &str = "#[ferment_macro::export]
+pub struct Model { pub id: u32, pub(crate) rank: u32 }
+
+#[ferment_macro::export]
+pub struct Sealed { pub id: u32, secret: u32 }
+
+#[ferment_macro::export]
+fn hidden() -> u32 { 0 }
+
+pub mod inner {
+    mod deep {
+        #[ferment_macro::export]
+        pub fn unreachable() -> u32 { 0 }
+    }
+}
+";
+
+fn fermentate_vis(name: &str) -> (File, Vec<Diagnostic>) {
+    fermentate(fixture(&format!("visibility_{name}"), "vis", &[("lib.rs", LIB), ("model.rs", MODEL)]))
+}
+
+fn has_diagnostic(diagnostics: &[Diagnostic], item: &str) -> bool {
+    diagnostics.iter().any(|diagnostic| diagnostic.item == item)
+}
+
+#[test]
+fn unreachable_items_are_skipped() {
+    let (fermentate, diagnostics) = fermentate_vis("unreachable");
+    assert!(has_item(&fermentate, "vis_model_Model_destroy"));
+    assert!(!has_item(&fermentate, "vis_model_hidden"));
+    assert!(!has_item(&fermentate, "vis_model_inner_deep_unreachable"));
+    assert!(!has_item(&fermentate, "vis_api_imp_not_reexported"));
+    assert!(!has_item_containing(&fermentate, "vis_model_Sealed"));
+    assert!(has_diagnostic(&diagnostics, "vis::model::hidden"));
+    assert!(has_diagnostic(&diagnostics, "vis::model::inner::deep::unreachable"));
+    assert!(has_diagnostic(&diagnostics, "vis::api::imp::not_reexported"));
+    assert!(has_diagnostic(&diagnostics, "vis::model::Sealed"));
+}
+
+#[test]
+fn reexported_items_are_named_by_public_path() {
+    let (fermentate, diagnostics) = fermentate_vis("reexported");
+    assert!(!has_diagnostic(&diagnostics, "vis::api::imp::Reexported"));
+    assert!(struct_item(&fermentate, "vis_api_Reexported").generics.params.is_empty());
+    assert!(find_impl(&fermentate, Some(&parse_quote!(ferment::FFIConversionFrom<vis::api::Reexported>)), &parse_quote!(vis_api_Reexported)).is_some());
+    assert_eq!(fn_item(&fermentate, "vis_count").sig, parse_quote!(unsafe extern "C" fn vis_count(reexported: *mut crate::fermented::types::vis::api::vis_api_Reexported) -> u32));
+    // the private module isn't named anywhere
+    assert!(!any_path(&fermentate, |path| path.segments.iter()
+        .map(|segment| segment.ident.to_string())
+        .collect::<Vec<_>>()
+        .windows(3)
+        .any(|segments| segments == ["vis", "api", "imp"])));
+}
+
+// `pub(crate)` fields can be named from the fermented module, so they get the accessors as well
+#[test]
+fn accessors_for_fields_visible_from_fermented_module() {
+    let (fermentate, _) = fermentate_vis("accessors");
+    assert!(has_item(&fermentate, "vis_model_Model_get_id"));
+    assert!(has_item(&fermentate, "vis_model_Model_set_id"));
+    assert!(has_item(&fermentate, "vis_model_Model_get_rank"));
+    assert!(has_item(&fermentate, "vis_model_Model_set_rank"));
+    assert!(has_item(&fermentate, "vis_model_Model_ctor"));
+    assert!(has_item(&fermentate, "vis_model_Model_destroy"));
+    assert!(has_item(&fermentate, "vis_api_Reexported_ctor"));
+}
+
+#[test]
+fn visibility_restrictions() {
+    let crate_name: Path = parse_quote!(vis);
+    let mod_path: Path = parse_quote!(vis::model::inner);
+    let fermented: Path = parse_quote!(vis::fermented);
+    let visible = |vis: Visibility, from: Option<&Path>| is_visible_from(&vis, &mod_path, &crate_name, from);
+    assert!(visible(parse_quote!(pub), None));
+    assert!(visible(parse_quote!(pub(crate)), Some(&fermented)));
+    assert!(!visible(parse_quote!(pub(crate)), None));
+    assert!(!visible(Visibility::Inherited, Some(&fermented)));
+    assert!(!visible(parse_quote!(pub(super)), Some(&fermented)));
+    assert!(visible(parse_quote!(pub(in crate)), Some(&fermented)));
+    assert!(visible(parse_quote!(pub(super)), Some(&parse_quote!(vis::model::other))));
+    assert!(!visible(parse_quote!(pub(self)), Some(&parse_quote!(vis::model))));
+}
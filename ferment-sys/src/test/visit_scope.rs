@@ -12,7 +12,7 @@ use crate::kind::{ObjectKind, TypeModelKind};
 fn test_context() -> Rc<RefCell<GlobalContext>> {
     let krate = RustCrate::current_with_name("my_crate");
     let cfg = Config::new("fermented", krate, cbindgen::Config::default());
-    Rc::new(RefCell::new(GlobalContext::with_config(cfg)))
+    Rc::new(RefCell::new(GlobalContext::try_from(&cfg).unwrap()))
 }
 
 fn module_scope() -> ScopeChain {
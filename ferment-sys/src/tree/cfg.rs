@@ -0,0 +1,208 @@
+use indexmap::{IndexMap, IndexSet};
+use syn::{Attribute, Expr, ExprLit, Field, Fields, ImplItem, Item, ItemEnum, ItemImpl, ItemMod, ItemStruct, ItemTrait, Lit, Meta, MetaList, MetaNameValue, TraitItem, Variant};
+use syn::parse::Parser;
+use syn::punctuated::Punctuated;
+use crate::ast::CommaPunctuated;
+use crate::{CfgMode, Config, Crate};
+
+/// Evaluates the `#[cfg(...)]` predicates while the crate tree is built (see `CfgMode`).
+/// Predicates are tri-state: `None` means the value is unknown (features or target options aren't known
+/// or the predicate isn't supported), such items are kept along with their cfg attributes
+#[derive(Clone, Debug, Default)]
+pub struct CfgEvaluator {
+    /// Enabled features normalized with `normalize_feature`
+    pub features: Option<IndexSet<String>>,
+    pub target_cfg: Option<IndexMap<String, IndexSet<String>>>,
+}
+
+impl CfgEvaluator {
+    pub fn new(config: &Config, crate_config: &Crate) -> Self {
+        match config.cfg_mode {
+            CfgMode::Keep =>
+                Self::default(),
+            CfgMode::Evaluate => Self {
+                features: crate_config.features.as_ref()
+                    .map(|features| features.iter().map(|feature| normalize_feature(feature)).collect()),
+                target_cfg: config.target_cfg.clone()
+            }
+        }
+    }
+
+    pub fn eval(&self, meta: &Meta) -> Option<bool> {
+        match meta {
+            Meta::Path(path) if path.is_ident("test") =>
+                Some(false),
+            // Set while the sources are fermented (e.g. `#![cfg_attr(ferment, ferment_macro::export)]`)
+            Meta::Path(path) if path.is_ident("ferment") =>
+                Some(true),
+            Meta::Path(path) =>
+                path.get_ident().and_then(|ident| self.eval_option(&ident.to_string(), None)),
+            Meta::NameValue(MetaNameValue { path, value: Expr::Lit(ExprLit { lit: Lit::Str(value), .. }), .. }) => if path.is_ident("feature") {
+                self.features.as_ref().map(|features| features.contains(&normalize_feature(&value.value())))
+            } else {
+                path.get_ident().and_then(|ident| self.eval_option(&ident.to_string(), Some(&value.value())))
+            },
+            Meta::List(MetaList { path, tokens, .. }) => {
+                let nested = CommaPunctuated::<Meta>::parse_terminated.parse2(tokens.clone()).ok()?;
+                if path.is_ident("not") {
+                    nested.first().and_then(|meta| self.eval(meta)).map(|value| !value)
+                } else if path.is_ident("all") {
+                    nested.iter().try_fold(Some(true), |acc, meta| match self.eval(meta) {
+                        Some(false) => Err(()),
+                        Some(true) => Ok(acc),
+                        None => Ok(None)
+                    }).unwrap_or(Some(false))
+                } else if path.is_ident("any") {
+                    nested.iter().try_fold(Some(false), |acc, meta| match self.eval(meta) {
+                        Some(true) => Err(()),
+                        Some(false) => Ok(acc),
+                        None => Ok(None)
+                    }).unwrap_or(Some(true))
+                } else {
+                    None
+                }
+            },
+            _ => None
+        }
+    }
+
+    fn eval_option(&self, name: &str, value: Option<&str>) -> Option<bool> {
+        self.target_cfg.as_ref()
+            .map(|target_cfg| target_cfg.get(name)
+                .is_some_and(|values| value.is_none_or(|value| values.contains(value))))
+    }
+
+    /// Expands `cfg_attr` with the known predicates and returns whether none of `cfg` is disabled
+    pub fn is_enabled(&self, attrs: &mut Vec<Attribute>) -> bool {
+        self.expand_cfg_attrs(attrs);
+        attrs.iter()
+            .filter(|attr| attr.path().is_ident("cfg"))
+            .all(|attr| match &attr.meta {
+                Meta::List(MetaList { tokens, .. }) => match syn::parse2::<Meta>(tokens.clone()) {
+                    Ok(meta) => self.eval(&meta) != Some(false),
+                    Err(_) => true
+                },
+                _ => true
+            })
+    }
+
+    fn expand_cfg_attrs(&self, attrs: &mut Vec<Attribute>) {
+        let mut expanded = Vec::with_capacity(attrs.len());
+        let mut changed = false;
+        for attr in attrs.drain(..) {
+            match &attr.meta {
+                Meta::List(MetaList { path, tokens, .. }) if path.is_ident("cfg_attr") => {
+                    let nested = CommaPunctuated::<Meta>::parse_terminated.parse2(tokens.clone()).unwrap_or_default();
+                    let mut nested = nested.into_iter();
+                    match nested.next().and_then(|predicate| self.eval(&predicate)) {
+                        Some(true) => {
+                            changed = true;
+                            expanded.extend(nested.map(|meta| Attribute { meta, ..attr.clone() }));
+                        },
+                        Some(false) =>
+                            changed = true,
+                        None =>
+                            expanded.push(attr)
+                    }
+                },
+                _ => expanded.push(attr)
+            }
+        }
+        *attrs = expanded;
+        if changed {
+            self.expand_cfg_attrs(attrs);
+        }
+    }
+
+    /// Drops the disabled items (recursively for the inline modules, impl and trait items, fields and variants),
+    /// items of the disabled file (`#![cfg(...)]`) are dropped entirely
+    pub fn retain_enabled_file(&self, file: &mut syn::File) {
+        if self.is_enabled(&mut file.attrs) {
+            self.retain_enabled_items(&mut file.items);
+        } else {
+            file.items.clear();
+        }
+    }
+
+    pub fn retain_enabled_items(&self, items: &mut Vec<Item>) {
+        items.retain_mut(|item| {
+            let enabled = item_attrs_mut(item).is_none_or(|attrs| self.is_enabled(attrs));
+            if enabled {
+                match item {
+                    Item::Mod(ItemMod { content: Some((_, items)), .. }) =>
+                        self.retain_enabled_items(items),
+                    Item::Struct(ItemStruct { fields, .. }) =>
+                        self.retain_enabled_fields(fields),
+                    Item::Enum(ItemEnum { variants, .. }) =>
+                        *variants = variants.clone().into_iter().filter_map(|mut variant| self.is_enabled(&mut variant.attrs).then(|| {
+                            self.retain_enabled_fields(&mut variant.fields);
+                            variant
+                        })).collect::<Punctuated<Variant, _>>(),
+                    Item::Impl(ItemImpl { items, .. }) =>
+                        items.retain_mut(|item| impl_item_attrs_mut(item).is_none_or(|attrs| self.is_enabled(attrs))),
+                    Item::Trait(ItemTrait { items, .. }) =>
+                        items.retain_mut(|item| trait_item_attrs_mut(item).is_none_or(|attrs| self.is_enabled(attrs))),
+                    _ => {}
+                }
+            }
+            enabled
+        });
+    }
+
+    fn retain_enabled_fields(&self, fields: &mut Fields) {
+        let retain = |fields: &Punctuated<Field, _>| fields.clone().into_iter()
+            .filter_map(|mut field| self.is_enabled(&mut field.attrs).then_some(field))
+            .collect();
+        match fields {
+            Fields::Named(fields) => fields.named = retain(&fields.named),
+            Fields::Unnamed(fields) => fields.unnamed = retain(&fields.unnamed),
+            Fields::Unit => {}
+        }
+    }
+}
+
+/// `CARGO_FEATURE_*` names are uppercased with `-` replaced by `_`
+fn normalize_feature(feature: &str) -> String {
+    feature.to_lowercase().replace('-', "_")
+}
+
+fn item_attrs_mut(item: &mut Item) -> Option<&mut Vec<Attribute>> {
+    match item {
+        Item::Const(item) => Some(&mut item.attrs),
+        Item::Enum(item) => Some(&mut item.attrs),
+        Item::ExternCrate(item) => Some(&mut item.attrs),
+        Item::Fn(item) => Some(&mut item.attrs),
+        Item::ForeignMod(item) => Some(&mut item.attrs),
+        Item::Impl(item) => Some(&mut item.attrs),
+        Item::Macro(item) => Some(&mut item.attrs),
+        Item::Mod(item) => Some(&mut item.attrs),
+        Item::Static(item) => Some(&mut item.attrs),
+        Item::Struct(item) => Some(&mut item.attrs),
+        Item::Trait(item) => Some(&mut item.attrs),
+        Item::TraitAlias(item) => Some(&mut item.attrs),
+        Item::Type(item) => Some(&mut item.attrs),
+        Item::Union(item) => Some(&mut item.attrs),
+        Item::Use(item) => Some(&mut item.attrs),
+        _ => None,
+    }
+}
+
+fn impl_item_attrs_mut(item: &mut ImplItem) -> Option<&mut Vec<Attribute>> {
+    match item {
+        ImplItem::Const(item) => Some(&mut item.attrs),
+        ImplItem::Fn(item) => Some(&mut item.attrs),
+        ImplItem::Type(item) => Some(&mut item.attrs),
+        ImplItem::Macro(item) => Some(&mut item.attrs),
+        _ => None,
+    }
+}
+
+fn trait_item_attrs_mut(item: &mut TraitItem) -> Option<&mut Vec<Attribute>> {
+    match item {
+        TraitItem::Const(item) => Some(&mut item.attrs),
+        TraitItem::Fn(item) => Some(&mut item.attrs),
+        TraitItem::Type(item) => Some(&mut item.attrs),
+        TraitItem::Macro(item) => Some(&mut item.attrs),
+        _ => None,
+    }
+}
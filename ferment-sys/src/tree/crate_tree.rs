@@ -1,8 +1,8 @@
-use std::collections::HashMap;
+use indexmap::IndexMap;
 use quote::quote;
 use syn::parse_quote;
 use syn::Attribute;
-use crate::{Crate, error, print_phase};
+use crate::{Crate, Diagnostic, error, print_phase};
 use crate::ast::Depunctuated;
 use crate::composer::SourceAccessible;
 use crate::context::ScopeContextLink;
@@ -26,18 +26,18 @@ impl SourceAccessible for CrateTree {
 
 #[allow(unused)]
 impl CrateTree {
-    pub fn new(current_crate: &Crate, current_tree: ScopeTreeExportItem, external_crates: HashMap<Crate, ScopeTreeExportItem>) -> Result<Self, error::Error> {
+    pub fn new(current_crate: &Crate, current_tree: ScopeTreeExportItem, external_crates: IndexMap<Crate, ScopeTreeExportItem>) -> Result<Self, error::Error> {
         match current_tree {
             ScopeTreeExportItem::Item(..) =>
                 Err(error::Error::ExpansionError("Bad tree root")),
-            ScopeTreeExportItem::Tree(scope_context, imported, exported, attrs) => {
+            ScopeTreeExportItem::Tree(scope_context, imported, exported, attrs, _) => {
                 // print_phase!("PHASE 2: CRATE TREE MORPHING", "\n{}", format_tree_exported_dict(&exported));
                 let current_tree = create_crate_root_scope_tree(current_crate.ident(), scope_context, imported, exported, attrs);
                 let mut crates = Depunctuated::from_iter(external_crates.into_iter()
                     .filter_map(|(external_crate, export_item)| match export_item {
                         ScopeTreeExportItem::Item(..) =>
                             None,
-                        ScopeTreeExportItem::Tree(scope_context, imported, exported, attrs) =>
+                        ScopeTreeExportItem::Tree(scope_context, imported, exported, attrs, _) =>
                             Some(create_crate_root_scope_tree(external_crate.ident(), scope_context, imported, exported, attrs))
                     }));
                 // print_phase!("PHASE 2: CURRENT CRATE TREE", "\n{:?}", current_tree);
@@ -59,3 +59,9 @@ impl CrateTree {
     }
 }
 
+impl CrateTree {
+    /// Diagnostics of the items which were skipped so far
+    pub fn diagnostics(&self) -> Vec<Diagnostic> {
+        self.context().borrow().context.borrow().diagnostics.clone()
+    }
+}
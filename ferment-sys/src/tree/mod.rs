@@ -1,12 +1,18 @@
 mod scope_tree_export_item;
 mod scope_tree_item;
 mod scope_tree;
+mod cfg;
 mod crate_tree;
+mod module_export;
+mod reachability;
 mod scope_tree_id;
 mod tree_processor;
 mod visitor;
 
+pub use self::cfg::*;
 pub use self::crate_tree::*;
+pub use self::module_export::*;
+pub use self::reachability::*;
 pub use self::scope_tree::*;
 pub use self::scope_tree_id::*;
 pub use self::scope_tree_export_item::*;
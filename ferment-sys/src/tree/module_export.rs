@@ -0,0 +1,52 @@
+use syn::{Attribute, ImplItem, ImplItemConst, ImplItemFn, Item, ItemConst, ItemEnum, ItemFn, ItemImpl, ItemMod, ItemStatic, ItemStruct, ItemTrait, ItemType, parse_quote, Visibility};
+use crate::composer::MaybeMacroLabeled;
+use crate::kind::MacroKind;
+
+/// Whether the items of the module are exported: the module is labeled with `#[ferment_macro::export]`
+/// (outer attribute or inner one `#![ferment_macro::export]`) or it's inside such a module,
+/// unless it's labeled with `#[ferment_macro::skip]`
+pub fn is_exported_mod(attrs: &[Attribute], parent_exported: bool) -> bool {
+    !is_skipped(attrs) && (parent_exported || matches!(attrs.iter().find_map(MaybeMacroLabeled::maybe_macro_labeled), Some(MacroKind::Export)))
+}
+
+/// Module-level export: labels every non-private item of the exported modules with `#[ferment_macro::export]`
+/// (recursively for the inline modules), so they're processed as if they were labeled one by one.
+/// Items which are already labeled (e.g. `opaque`) or labeled with `#[ferment_macro::skip]` are left as is.
+/// Only inherent impls are labeled, impls of the traits stay opt-in
+pub fn label_exported_items(items: &mut [Item], exported: bool) {
+    items.iter_mut().for_each(|item| match item {
+        Item::Mod(ItemMod { attrs, content: Some((_, items)), .. }) => {
+            let exported = is_exported_mod(attrs, exported);
+            label_exported_items(items, exported);
+        },
+        Item::Const(ItemConst { attrs, vis, .. }) |
+        Item::Enum(ItemEnum { attrs, vis, .. }) |
+        Item::Fn(ItemFn { attrs, vis, .. }) |
+        Item::Static(ItemStatic { attrs, vis, .. }) |
+        Item::Struct(ItemStruct { attrs, vis, .. }) |
+        Item::Trait(ItemTrait { attrs, vis, .. }) |
+        Item::Type(ItemType { attrs, vis, .. }) if exported && !matches!(vis, Visibility::Inherited) =>
+            label_export(attrs),
+        Item::Impl(ItemImpl { attrs, trait_: None, items, .. }) if exported && items.iter().any(|item| matches!(item, ImplItem::Const(ImplItemConst { vis, .. }) | ImplItem::Fn(ImplItemFn { vis, .. }) if !matches!(vis, Visibility::Inherited))) =>
+            label_export(attrs),
+        _ => {}
+    });
+}
+
+fn label_export(attrs: &mut Vec<Attribute>) {
+    if !is_skipped(attrs) && attrs.iter().all(|attr| attr.maybe_macro_labeled().is_none()) {
+        attrs.push(parse_quote!(#[ferment_macro::export]));
+    }
+}
+
+/// Only `#[ferment_macro::skip]` (or `#[skip]` with the macro imported) counts: tool attributes like `#[rustfmt::skip]` don't
+fn is_skipped(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        let mut segments = attr.path().segments.iter().map(|segment| &segment.ident);
+        match (segments.next(), segments.next(), segments.next()) {
+            (Some(ident), None, None) => ident == "skip",
+            (Some(krate), Some(ident), None) => krate == "ferment_macro" && ident == "skip",
+            _ => false
+        }
+    })
+}
@@ -0,0 +1,107 @@
+use indexmap::{IndexMap, IndexSet};
+use syn::{Fields, ImplItem, ImplItemConst, ImplItemFn, Item, ItemConst, ItemEnum, ItemFn, ItemImpl, ItemStatic, ItemStruct, ItemTrait, ItemType, Path, StaticMutability, Type, TypePath, Visibility};
+use crate::context::{is_visible_from, GlobalContext};
+use crate::Diagnostic;
+use crate::ext::{Join, MaybeIdent};
+use crate::kind::MacroKind;
+
+/// Decides which of the exported items can be named from the fermented module:
+/// the module chain, the item itself and the fields (for the items converted by value) must be visible from there.
+/// Items from the private modules stay exported if they're re-exported with the sufficient visibility,
+/// they're named by the re-exported path then (see `public_paths`)
+pub struct Reachability<'a> {
+    pub context: &'a GlobalContext,
+    pub crate_name: Path,
+    /// Path of the fermented module or `None` for the external crates (only `pub` is visible there)
+    pub from: Option<Path>,
+    pub modules: IndexSet<Path>,
+    pub reexports: IndexMap<Path, Path>,
+    /// Full paths of the re-exported items mapped onto the paths they're re-exported with
+    pub public_paths: IndexMap<Path, Path>,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl<'a> Reachability<'a> {
+    pub fn new(context: &'a GlobalContext, crate_name: Path, from: Option<Path>) -> Self {
+        Self { context, crate_name, from, modules: IndexSet::new(), reexports: IndexMap::new(), public_paths: IndexMap::new(), diagnostics: vec![] }
+    }
+
+    pub fn is_visible(&self, vis: &Visibility, mod_path: &Path) -> bool {
+        is_visible_from(vis, mod_path, &self.crate_name, self.from.as_ref())
+    }
+
+    /// Whether the item (defined in the module with `mod_path`) stays exported,
+    /// non-visible methods are dropped from the inherent impls
+    pub fn retain_item(&mut self, item: &mut Item, mod_path: &Path, dropped: &IndexSet<Path>) -> bool {
+        let path = item.maybe_ident()
+            .map(|ident| mod_path.joined(ident))
+            .unwrap_or_else(|| mod_path.clone());
+        if let Item::Impl(item_impl) = item {
+            return self.retain_impl(item_impl, mod_path, &path, dropped);
+        }
+        let is_reachable = self.modules.contains(mod_path) && maybe_vis(item).is_some_and(|vis| self.is_visible(vis, mod_path));
+        if !is_reachable {
+            match self.reexports.get(&path) {
+                Some(public_path) => {
+                    self.public_paths.insert(path.clone(), public_path.clone());
+                },
+                None => {
+                    self.report(item, &path, "isn't reachable from the fermented module: make it and its modules public or re-export it");
+                    return false;
+                }
+            }
+        }
+        match &*item {
+            Item::Struct(ItemStruct { fields, .. }) if matches!(MacroKind::try_from(&*item), Ok(MacroKind::Export)) && !self.are_fields_visible(fields, mod_path) => {
+                self.report(item, &path, "has fields which aren't visible from the fermented module, so it can't be converted by value: make them public or export it as opaque");
+                false
+            },
+            Item::Static(ItemStatic { mutability: StaticMutability::Mut(..), .. }) => {
+                self.report(item, &path, "is a mutable static, which can't be exported: wrap the value into a synchronized type or export the accessor functions");
+                false
+            },
+            _ => true
+        }
+    }
+
+    fn retain_impl(&mut self, item_impl: &mut ItemImpl, mod_path: &Path, path: &Path, dropped: &IndexSet<Path>) -> bool {
+        if let Type::Path(TypePath { qself: None, path: self_path, .. }) = &*item_impl.self_ty {
+            if let Some(ident) = self_path.get_ident() {
+                if dropped.contains(&mod_path.joined(ident)) {
+                    self.report(&Item::Impl(item_impl.clone()), path, "is implemented for the type which isn't exported");
+                    return false;
+                }
+            }
+        }
+        if item_impl.trait_.is_none() {
+            item_impl.items.retain(|impl_item| match impl_item {
+                ImplItem::Const(ImplItemConst { vis, .. }) |
+                ImplItem::Fn(ImplItemFn { vis, .. }) => self.is_visible(vis, mod_path),
+                _ => true
+            });
+        }
+        true
+    }
+
+    fn are_fields_visible(&self, fields: &Fields, mod_path: &Path) -> bool {
+        fields.iter().all(|field| self.is_visible(&field.vis, mod_path))
+    }
+
+    fn report(&mut self, item: &Item, path: &Path, message: &str) {
+        let diagnostic = self.context.item_diagnostic(item, path, message.to_string());
+        self.diagnostics.push(diagnostic);
+    }
+}
+
+fn maybe_vis(item: &Item) -> Option<&Visibility> {
+    match item {
+        Item::Const(ItemConst { vis, .. }) |
+        Item::Enum(ItemEnum { vis, .. }) |
+        Item::Fn(ItemFn { vis, .. }) |
+        Item::Static(ItemStatic { vis, .. }) |
+        Item::Struct(ItemStruct { vis, .. }) |
+        Item::Trait(ItemTrait { vis, .. }) |
+        Item::Type(ItemType { vis, .. }) => Some(vis),
+        _ => None
+    }
+}
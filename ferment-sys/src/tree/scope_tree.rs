@@ -1,7 +1,7 @@
 use std::cell::RefCell;
-use std::collections::HashSet;
 use std::fmt::{Debug, Formatter};
 use std::rc::Rc;
+use indexmap::IndexSet;
 use indexmap::IndexMap;
 use proc_macro2::Ident;
 use quote::format_ident;
@@ -18,7 +18,7 @@ use crate::tree::ScopeTreeExportItem;
 pub struct ScopeTree {
     pub attrs: Vec<Attribute>,
     pub scope: ScopeChain,
-    pub imported: HashSet<ItemUse>,
+    pub imported: IndexSet<ItemUse>,
     pub exported: IndexMap<ScopeTreeID, ScopeTreeItem>,
     pub scope_context: ScopeContextLink,
 }
@@ -52,7 +52,7 @@ pub fn create_generics_scope_tree(root_scope_chain: &ScopeChain, global_context:
     create_scope_tree(
         generics_scope_chain.clone(),
         ScopeContext::cell_with(generics_scope_chain, global_context),
-        HashSet::from_iter([
+        IndexSet::from_iter([
             create_item_use_with_tree(UseTree::Rename(UseRename { ident: format_ident!("crate"), as_token: Default::default(), rename }))
         ]),
         IndexMap::new(),
@@ -76,7 +76,7 @@ pub fn create_item_use_with_tree(tree: UseTree) -> ItemUse {
 pub fn create_crate_root_scope_tree(
     crate_ident: Ident,
     scope_context: ScopeContextLink,
-    imported: HashSet<ItemUse>,
+    imported: IndexSet<ItemUse>,
     exported: IndexMap<ScopeTreeID, ScopeTreeExportItem>,
     attrs: Vec<Attribute>
 ) -> ScopeTree {
@@ -88,7 +88,7 @@ pub fn create_crate_root_scope_tree(
 pub fn create_scope_tree(
     scope: ScopeChain,
     scope_context: ScopeContextLink,
-    imported: HashSet<ItemUse>,
+    imported: IndexSet<ItemUse>,
     exported: IndexMap<ScopeTreeID, ScopeTreeExportItem>,
     attrs: Vec<Attribute>
 ) -> ScopeTree {
@@ -97,7 +97,7 @@ pub fn create_scope_tree(
             let scope_tree_item = match scope_tree_export_item {
                 ScopeTreeExportItem::Item(scope_context, item) =>
                     ScopeTreeItem::item(scope.joined(&item), item, scope_context),
-                ScopeTreeExportItem::Tree(scope_context, imported, exported, attrs) =>
+                ScopeTreeExportItem::Tree(scope_context, imported, exported, attrs, _) =>
                     ScopeTreeItem::tree(create_scope_tree(scope_id.create_child_scope(&scope, attrs.clone()), scope_context, imported, exported, attrs))
             };
             (scope_id, scope_tree_item)
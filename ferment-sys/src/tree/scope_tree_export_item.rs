@@ -1,20 +1,24 @@
 use std::cell::RefCell;
 use std::fmt::Formatter;
-use std::collections::HashSet;
 use std::rc::Rc;
+use indexmap::IndexSet;
 use indexmap::IndexMap;
-use syn::{Attribute, Item, ItemMod, ItemUse};
+use proc_macro2::Ident;
+use quote::ToTokens;
+use syn::{Attribute, Item, ItemMod, ItemUse, Path, Visibility};
 use crate::context::{GlobalContext, ScopeChain, ScopeContext, ScopeContextLink};
-use crate::ext::MaybeIdent;
+use crate::ext::{Join, MaybeIdent};
+use crate::kind::MacroKind;
 use crate::formatter::{format_imported_set, format_tree_exported_dict};
-use crate::tree::{ScopeTreeID, GetScopeTreeID};
+use crate::tree::{Reachability, ScopeTreeID, GetScopeTreeID};
 
 
 #[allow(clippy::large_enum_variant)]
 #[derive(Clone)]
 pub enum ScopeTreeExportItem {
     Item(ScopeContextLink, Item),
-    Tree(ScopeContextLink, HashSet<ItemUse>, IndexMap<ScopeTreeID, ScopeTreeExportItem>, Vec<Attribute>),
+    /// Module with its visibility as declared in the parent module
+    Tree(ScopeContextLink, IndexSet<ItemUse>, IndexMap<ScopeTreeID, ScopeTreeExportItem>, Vec<Attribute>, Visibility),
 }
 
 impl std::fmt::Debug for ScopeTreeExportItem {
@@ -22,12 +26,13 @@ impl std::fmt::Debug for ScopeTreeExportItem {
         match self {
             ScopeTreeExportItem::Item(_, item) =>
                 f.write_str(&format!("ScopeTreeExportItem::Item({:?})", item.maybe_ident())),
-            ScopeTreeExportItem::Tree(context, imported, exported, attrs) =>
+            ScopeTreeExportItem::Tree(context, imported, exported, attrs, vis) =>
                 f.debug_struct("ScopeTreeExportItem::Tree")
                     .field("context", context)
                     .field("imported", &format_imported_set(imported))
                     .field("exported", &format_tree_exported_dict(exported))
                     .field("attrs", attrs)
+                    .field("vis", &vis.to_token_stream().to_string())
                     .finish()
         }
     }
@@ -47,7 +52,7 @@ impl ScopeTreeExportItem {
         }
     }
     pub fn tree_with_context_and_exports(context: ScopeContextLink, attrs: &[Attribute]) -> Self {
-        Self::Tree(context, HashSet::default(), IndexMap::default(), attrs.to_owned())
+        Self::Tree(context, IndexSet::default(), IndexMap::default(), attrs.to_owned(), Visibility::Inherited)
     }
     pub fn tree_with_context(scope: &ScopeChain, context: Rc<RefCell<GlobalContext>>, attrs: &[Attribute]) -> Self {
         Self::tree_with_context_and_exports(ScopeContext::cell_with(scope.clone(), context), attrs)
@@ -55,11 +60,101 @@ impl ScopeTreeExportItem {
     pub fn item_with_context(scope: &ScopeChain, item: &Item, context: Rc<RefCell<GlobalContext>>) -> Self {
         Self::Item(ScopeContext::cell_with(scope.clone(), context), item.clone())
     }
+    fn set_vis(&mut self, visibility: &Visibility) {
+        if let Self::Tree(.., vis) = self {
+            *vis = visibility.clone();
+        }
+    }
+    /// Collects the names defined in each module of the tree (keyed by the full path of the module)
+    pub fn fold_module_names(&self, modules: &mut IndexMap<Path, IndexSet<Ident>>) {
+        if let ScopeTreeExportItem::Tree(context, _, exported, ..) = self {
+            let names = modules.entry(context.borrow().scope.self_path_ref().clone()).or_default();
+            names.extend(exported.keys().filter_map(|id| match id {
+                ScopeTreeID::Ident(ident) => Some(ident.clone()),
+                ScopeTreeID::Impl(..) => None
+            }));
+            exported.values().for_each(|item| item.fold_module_names(modules));
+        }
+    }
+    /// Collects the paths of the modules which can be named from the fermented module
+    pub fn fold_reachable_modules(&self, path: &Path, reachability: &mut Reachability) {
+        if let ScopeTreeExportItem::Tree(_, _, exported, ..) = self {
+            reachability.modules.insert(path.clone());
+            exported.iter().for_each(|(id, item)| match (id, item) {
+                (ScopeTreeID::Ident(ident), ScopeTreeExportItem::Tree(.., vis)) if reachability.is_visible(vis, path) =>
+                    item.fold_reachable_modules(&path.joined(ident), reachability),
+                _ => {}
+            });
+        }
+    }
+    /// Drops the items which can't be named from the fermented module (see `Reachability`)
+    /// and returns whether there is something left to export
+    pub fn retain_reachable(&mut self, path: &Path, reachability: &mut Reachability) -> bool {
+        if let ScopeTreeExportItem::Tree(_, _, exported, ..) = self {
+            let mut dropped = IndexSet::new();
+            exported.retain(|id, item| match (id, item) {
+                (ScopeTreeID::Ident(ident), ScopeTreeExportItem::Item(_, item)) => {
+                    let retain = reachability.retain_item(item, path, &dropped);
+                    if !retain {
+                        dropped.insert(path.joined(ident));
+                    }
+                    retain
+                },
+                _ => true
+            });
+            // Impls go after the items they may be implemented for
+            exported.retain(|id, item| match (id, item) {
+                (ScopeTreeID::Impl(..), ScopeTreeExportItem::Item(_, item)) =>
+                    reachability.retain_item(item, path, &dropped),
+                _ => true
+            });
+            exported.retain(|id, item| match (id, &item) {
+                (ScopeTreeID::Ident(ident), ScopeTreeExportItem::Tree(..)) => {
+                    let mod_path = path.joined(ident);
+                    item.retain_reachable(&mod_path, reachability) || reachability.modules.contains(&mod_path)
+                },
+                _ => true
+            });
+            !exported.is_empty()
+        } else {
+            true
+        }
+    }
+    /// Moves the re-exported items (see `Reachability`) into the modules they're re-exported from,
+    /// items of the other crates are left as is
+    pub fn relocate_items(&mut self, crate_ident: &Ident, public_paths: &IndexMap<Path, Path>) {
+        let module_idents = |path: &Path| path.segments.first()
+            .is_some_and(|segment| segment.ident.eq(crate_ident))
+            .then(|| path.segments.iter().skip(1).map(|segment| segment.ident.clone()).collect::<Vec<_>>());
+        public_paths.iter().for_each(|(defined, public)| {
+            if let (Some(mut defined), Some(mut public)) = (module_idents(defined), module_idents(public)) {
+                let (Some(ident), Some(_)) = (defined.pop(), public.pop()) else { return };
+                let id = ScopeTreeID::from_ident(&ident);
+                if let Some(item) = self.exported_mut(&defined).and_then(|exported| exported.shift_remove(&id)) {
+                    if let Some(exported) = self.exported_mut(&public) {
+                        exported.insert(id, item);
+                    }
+                }
+            }
+        });
+    }
+    fn exported_mut(&mut self, module: &[Ident]) -> Option<&mut IndexMap<ScopeTreeID, ScopeTreeExportItem>> {
+        match self {
+            ScopeTreeExportItem::Tree(_, _, exported, ..) => match module.split_first() {
+                Some((ident, rest)) => exported.get_mut(&ScopeTreeID::from_ident(ident))
+                    .and_then(|tree| tree.exported_mut(rest)),
+                None => Some(exported)
+            },
+            ScopeTreeExportItem::Item(..) => None
+        }
+    }
     pub fn add_item(&mut self, item: Item, scope: ScopeChain) {
         if let ScopeTreeExportItem::Tree(..) = self {
             match &item {
                 Item::Use(..) => {},
                 Item::Mod(item_mod) => self.add_mod_item(item_mod, &scope),
+                Item::Const(..) |
+                Item::Static(..) |
                 Item::Trait(..) |
                 Item::Fn(..) |
                 Item::Struct(..) |
@@ -75,40 +170,46 @@ impl ScopeTreeExportItem {
         items.iter().for_each(|item|
             match item {
                 Item::Mod(item_mod) =>
-                    self.add_mod_item(item_mod, scope),
+                    self.add_mod_item(item_mod, &scope.joined(item)),
                 Item::Const(_) |
                 Item::Enum(_) |
                 Item::Fn(_) |
                 Item::Impl(_) |
+                Item::Static(_) |
                 Item::Struct(_) |
                 Item::Trait(_) |
-                Item::Type(_) => self.add_non_mod_item(item, scope),
+                Item::Type(_) if MacroKind::try_from(item).is_ok() =>
+                    self.add_non_mod_item(item, scope),
                 _ => {}
             }
         );
     }
     fn add_non_mod_item(&mut self, item: &Item, scope: &ScopeChain) {
-        if let Self::Tree(scope_context, _, exported, _attrs) = self {
+        if let Self::Tree(scope_context, _, exported, ..) = self {
             exported.insert(item.scope_tree_id(), Self::item_with_context(scope, item, scope_context.borrow().context.clone()));
         }
     }
 
     fn add_mod_item(&mut self, item_mod: &ItemMod, scope: &ScopeChain) {
-        let ItemMod { attrs, ident, content, .. } = item_mod;
-        let new_export_item = |context: &mut ScopeContextLink| Self::tree_with_context(scope, context.borrow().context.clone(), attrs);
+        let ItemMod { attrs, ident, content, vis, .. } = item_mod;
+        let new_export_item = |context: &mut ScopeContextLink| {
+            let mut tree = Self::tree_with_context(scope, context.borrow().context.clone(), attrs);
+            tree.set_vis(vis);
+            tree
+        };
         match content {
             Some((_, items)) => match self {
                 Self::Item(context, _) => {
                     let mut inner_tree = new_export_item(context);
                     inner_tree.add_items(items, scope);
                 },
-                Self::Tree(context, _, exported, _) => {
+                Self::Tree(context, _, exported, ..) => {
                     let mut inner_tree = new_export_item(context);
                     inner_tree.add_items(items, scope);
                     exported.insert(ScopeTreeID::from_ident(ident), inner_tree);
                 }
             },
-            None => if let Self::Tree(context, _, exported, _) = self {
+            None => if let Self::Tree(context, _, exported, ..) = self {
                 exported.insert(ScopeTreeID::from_ident(ident), new_export_item(context));
             }
         }
@@ -1,41 +1,63 @@
 use std::cell::RefCell;
-use std::collections::HashMap;
 use std::path::PathBuf;
 use std::rc::Rc;
+use indexmap::{IndexMap, IndexSet};
 use proc_macro2::Ident;
-use syn::{Attribute, Item, ItemMod};
+use quote::format_ident;
+use syn::{Attribute, Expr, ExprLit, Item, ItemMod, Lit, Meta, MetaNameValue, Path};
 use syn::visit::Visit;
 use crate::Crate;
 use crate::context::{GlobalContext, ScopeChain};
 use crate::{Config, error, print_phase};
-use crate::tree::{CrateTree, ScopeTreeExportItem, Visitor};
+use crate::ext::{Join, ToPath};
+use crate::tree::{is_exported_mod, CfgEvaluator, label_exported_items, CrateTree, Reachability, ScopeTreeExportItem, Visitor};
 pub struct FileTreeProcessor {
     pub path: PathBuf,
+    /// Directory of the child module files: the directory of the file itself for the crate root,
+    /// `mod.rs` and `#[path]` files, `{dir}/{file_stem}` for the others
+    pub module_dir: PathBuf,
     pub scope: ScopeChain,
     pub context: Rc<RefCell<GlobalContext>>,
-    pub attrs: Vec<Attribute>
+    pub attrs: Vec<Attribute>,
+    /// The module is inside the module labeled with `#[ferment_macro::export]` (see `label_exported_items`)
+    pub exported: bool,
+    pub cfg: CfgEvaluator,
 }
 
 impl FileTreeProcessor {
     #[allow(unused)]
     pub fn build(config: &Config) -> Result<CrateTree, error::Error> {
-        let Config { current_crate, external_crates, .. } = config;
-        let context = Rc::new(RefCell::new(GlobalContext::from(config)));
+        let Config { current_crate, external_crates, mod_name, .. } = config;
+        let context = Rc::new(RefCell::new(GlobalContext::try_from(config)?));
         print_phase!("PHASE 0: PROCESS CRATES", "{}", config);
         process_crates(external_crates, &context)
-            .and_then(|external_crates|
+            .and_then(|mut external_crates|
                 current_crate.process(vec![], &context)
-                    .and_then(|current_tree| CrateTree::new(current_crate, current_tree, external_crates)))
+                    .and_then(|mut current_tree| {
+                        let modules = resolve_glob_imports(external_crates.values().chain([&current_tree]), &context);
+                        let fermented_mod_path = current_crate.ident().to_path().joined(&format_ident!("{mod_name}"));
+                        retain_reachable_items(&mut current_tree, current_crate, Some(fermented_mod_path), &modules, &context);
+                        external_crates.iter_mut()
+                            .for_each(|(external_crate, tree)| retain_reachable_items(tree, external_crate, None, &modules, &context));
+                        CrateTree::new(current_crate, current_tree, external_crates)
+                    }))
     }
     pub fn process_crate_tree(crate_config: &Crate, attrs: Vec<Attribute>, context: &Rc<RefCell<GlobalContext>>) -> Result<ScopeTreeExportItem, error::Error> {
         let path = crate_config.root_path();
         let scope = ScopeChain::crate_root_with_ident(crate_config.ident(), attrs.clone());
-        Self::new(path, scope, attrs, context)
+        let cfg = CfgEvaluator::new(&context.borrow().config, crate_config);
+        Self::new(path, true, scope, attrs, false, cfg, context)
             .process()
             .map(Visitor::into_code_tree)
     }
-    fn new(path: PathBuf, scope: ScopeChain, attrs: Vec<Attribute>, context: &Rc<RefCell<GlobalContext>>) -> Self {
-        Self { path, scope, context: context.clone(), attrs }
+    fn new(path: PathBuf, owns_dir: bool, scope: ScopeChain, attrs: Vec<Attribute>, exported: bool, cfg: CfgEvaluator, context: &Rc<RefCell<GlobalContext>>) -> Self {
+        context.borrow_mut().add_module_file(&scope, path.clone());
+        let dir = path.parent().map(std::path::Path::to_path_buf).unwrap_or_default();
+        let module_dir = match path.file_stem() {
+            Some(stem) if !owns_dir => dir.join(stem),
+            _ => dir
+        };
+        Self { path, module_dir, scope, context: context.clone(), attrs, exported, cfg }
     }
     fn process(self) -> Result<Visitor, error::Error> {
         //print_phase!("PHASE 1: PROCESS FILE", "{:?}", self.path);
@@ -48,44 +70,65 @@ impl FileTreeProcessor {
             .and_then(|content| syn::parse_file(&content)
                 .map_err(error::Error::from))
     }
-    fn to_inner_visitors(&self, items: Vec<Item>) -> Vec<Visitor> {
-        let mut visitors = vec![];
+    fn to_inner_visitors(&self, items: &[Item], scope: &ScopeChain, module_dir: &std::path::Path, inline: bool, exported: bool, visitors: &mut Vec<Visitor>) {
         for item in items {
-            if let Item::Mod(ItemMod { ident, attrs, content: None, .. }) = item {
-                if !self.is_fermented_mod(&ident) {
-                    if let Ok(visitor) = self.process_module(&ident, attrs) {
-                        visitors.push(visitor);
+            if let Item::Mod(item_mod) = item {
+                let ItemMod { ident, attrs, content, .. } = item_mod;
+                let exported = is_exported_mod(attrs, exported);
+                let scope = ScopeChain::child_mod(attrs.clone(), self.scope.crate_ident_ref().clone(), ident, scope);
+                match content {
+                    Some((_, items)) =>
+                        self.to_inner_visitors(items, &scope, &module_dir.join(ident.to_string()), true, exported, visitors),
+                    None if self.is_fermented_mod(ident) => {},
+                    None => match self.process_module(item_mod, &scope, module_dir, inline, exported) {
+                        Ok(visitor) =>
+                            visitors.push(visitor),
+                        Err(message) => {
+                            let mut lock = self.context.borrow_mut();
+                            let diagnostic = lock.item_diagnostic(item, scope.self_path_ref(), message);
+                            lock.add_diagnostic(diagnostic);
+                        }
                     }
                 }
             }
         }
-        visitors
     }
-    fn setup_visitor(&self, syntax_tree: syn::File) -> Visitor {
+    fn setup_visitor(&self, mut syntax_tree: syn::File) -> Visitor {
+        self.cfg.retain_enabled_file(&mut syntax_tree);
+        let exported = is_exported_mod(&syntax_tree.attrs, self.exported);
+        label_exported_items(&mut syntax_tree.items, exported);
         let mut visitor = Visitor::new(&self.scope, &self.attrs, &self.context);
         visitor.visit_file(&syntax_tree);
-        visitor.inner_visitors = self.to_inner_visitors(syntax_tree.items);
+        self.to_inner_visitors(&syntax_tree.items, &self.scope, &self.module_dir, false, exported, &mut visitor.inner_visitors);
         visitor
     }
-    fn process_module(&self, mod_name: &Ident, attrs: Vec<Attribute>) -> Result<Visitor, error::Error> {
-        let scope = ScopeChain::child_mod(attrs.clone(), self.scope.crate_ident_ref().clone(), mod_name, &self.scope);
-        if let Some(parent_path) = self.path.parent() {
-            let file_path = parent_path.join(mod_name.to_string());
-            if file_path.is_file() {
-                return FileTreeProcessor::new(file_path, scope, attrs, &self.context).process();
-            } else {
-                let path = file_path.join("mod.rs");
-                if path.is_file() {
-                    return FileTreeProcessor::new(path, scope, attrs, &self.context).process()
-                } else if let Some(file_parent) = file_path.parent() {
-                    let path = file_parent.join(format!("{mod_name}.rs"));
-                    if path.is_file() {
-                        return FileTreeProcessor::new(path, scope, attrs, &self.context).process()
-                    }
+    /// Module files are resolved like rustc does: `{module_dir}/{name}.rs` or `{module_dir}/{name}/mod.rs`,
+    /// `#[path]` is relative to the directory of the current file (or to the module directory inside the inline modules)
+    fn process_module(&self, item_mod: &ItemMod, scope: &ScopeChain, module_dir: &std::path::Path, inline: bool, exported: bool) -> Result<Visitor, String> {
+        let ItemMod { ident, attrs, .. } = item_mod;
+        let (path, owns_dir) = match path_attr(attrs) {
+            Some(path) if inline =>
+                (module_dir.join(path), true),
+            Some(path) =>
+                (self.path.parent().unwrap_or(module_dir).join(path), true),
+            None => {
+                let file_path = module_dir.join(format!("{ident}.rs"));
+                let mod_path = module_dir.join(ident.to_string()).join("mod.rs");
+                if file_path.is_file() {
+                    (file_path, false)
+                } else if mod_path.is_file() {
+                    (mod_path, true)
+                } else {
+                    return Err(format!("can't locate the module file: neither `{}` nor `{}` exists", file_path.display(), mod_path.display()));
                 }
             }
+        };
+        if !path.is_file() {
+            return Err(format!("can't locate the module file `{}`", path.display()));
         }
-        Err(error::Error::ExpansionError("Can't locate module file"))
+        FileTreeProcessor::new(path.clone(), owns_dir, scope.clone(), attrs.clone(), exported, self.cfg.clone(), &self.context)
+            .process()
+            .map_err(|err| format!("can't process the module file `{}`: {err}", path.display()))
     }
     fn is_fermented_mod(&self, ident: &Ident) -> bool {
         let lock = self.context.borrow();
@@ -94,10 +137,50 @@ impl FileTreeProcessor {
 }
 
 #[allow(unused)]
-fn process_crates(crates: &[Crate], context: &Rc<RefCell<GlobalContext>>) -> Result<HashMap<Crate, ScopeTreeExportItem>, error::Error> {
+fn process_crates(crates: &[Crate], context: &Rc<RefCell<GlobalContext>>) -> Result<IndexMap<Crate, ScopeTreeExportItem>, error::Error> {
     crates.iter()
-        .try_fold(HashMap::new(), |mut acc, crate_config| {
+        .try_fold(IndexMap::new(), |mut acc, crate_config| {
             acc.insert(crate_config.clone(), crate_config.process(vec![], context)?);
             Ok(acc)
         })
 }
+
+/// Glob imports (and then the self types of the formatting trait impls) can only be resolved once all the crates are visited,
+/// returns the names defined in each module of the crates
+fn resolve_glob_imports<'a>(trees: impl Iterator<Item = &'a ScopeTreeExportItem>, context: &Rc<RefCell<GlobalContext>>) -> IndexMap<Path, IndexSet<Ident>> {
+    let mut modules = IndexMap::new();
+    trees.for_each(|tree| tree.fold_module_names(&mut modules));
+    let mut lock = context.borrow_mut();
+    lock.imports.resolve_globs(&modules);
+    lock.resolve_formatter_impls(&modules);
+    lock.modules = modules.clone();
+    modules
+}
+
+/// Only the items which can be named from the fermented module are exported (see `Reachability`),
+/// `from` is `None` for the external crates
+fn retain_reachable_items(tree: &mut ScopeTreeExportItem, crate_config: &Crate, from: Option<Path>, modules: &IndexMap<Path, IndexSet<Ident>>, context: &Rc<RefCell<GlobalContext>>) {
+    let crate_name = crate_config.ident().to_path();
+    let (public_paths, diagnostics) = {
+        let lock = context.borrow();
+        let mut reachability = Reachability::new(&lock, crate_name.clone(), from);
+        tree.fold_reachable_modules(&crate_name, &mut reachability);
+        reachability.reexports = lock.imports.public_reexports(&reachability.modules, reachability.from.as_ref(), modules);
+        tree.retain_reachable(&crate_name, &mut reachability);
+        (reachability.public_paths, reachability.diagnostics)
+    };
+    tree.relocate_items(&crate_config.ident(), &public_paths);
+    let mut lock = context.borrow_mut();
+    lock.public_paths.extend(public_paths);
+    diagnostics.into_iter()
+        .for_each(|diagnostic| lock.add_diagnostic(diagnostic));
+}
+
+/// Value of `#[path = "..."]`
+fn path_attr(attrs: &[Attribute]) -> Option<String> {
+    attrs.iter().find_map(|attr| match &attr.meta {
+        Meta::NameValue(MetaNameValue { path, value: Expr::Lit(ExprLit { lit: Lit::Str(value), .. }), .. }) if path.is_ident("path") =>
+            Some(value.value()),
+        _ => None
+    })
+}
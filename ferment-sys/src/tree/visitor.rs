@@ -2,11 +2,11 @@ use std::cell::RefCell;
 use std::fmt::Formatter;
 use std::rc::Rc;
 use quote::{format_ident, ToTokens};
-use syn::{Attribute, Ident, Item, ItemEnum, ItemFn, ItemImpl, ItemMod, ItemStruct, ItemTrait, ItemType, ItemUse, parse_quote, Type, UseTree, Path};
+use syn::{Attribute, Item, ItemConst, ItemEnum, ItemFn, ItemImpl, ItemMod, ItemStatic, ItemStruct, ItemTrait, ItemType, ItemUse, parse_quote, Type, TypePath, Path, Visibility};
 use syn::visit::Visit;
 use crate::context::{GenericChain, GlobalContext, ScopeChain, TypeChain};
-use crate::kind::{MacroKind, ObjectKind};
-use crate::ext::{CrateExtension, extract_trait_names, MaybeIdent, ItemHelper, Join, MergeInto, UniqueNestedItems, Pop, VisitScope, VisitScopeType, ToPath, ToType};
+use crate::kind::{FormatterKind, MacroKind, ObjectKind};
+use crate::ext::{CrateBased, CrateExtension, extract_formatters, extract_trait_names, MaybeIdent, MaybeUnsupported, ItemHelper, Unparenthesized, Join, MergeInto, UniqueNestedItems, Pop, VisitScope, VisitScopeType, ToPath, ToType};
 use crate::tree::{ScopeTreeID, ScopeTreeExportItem};
 
 pub struct Visitor {
@@ -36,6 +36,10 @@ impl std::fmt::Display for Visitor {
 
 impl<'ast> Visit<'ast> for Visitor {
 
+    fn visit_item_const(&mut self, node: &'ast ItemConst) {
+        self.add_conversion(Item::Const(node.clone()));
+    }
+
     fn visit_item_enum(&mut self, node: &'ast ItemEnum) {
         self.add_conversion(Item::Enum(node.clone()));
     }
@@ -58,6 +62,10 @@ impl<'ast> Visit<'ast> for Visitor {
         self.current_module_scope = self.current_module_scope.parent_scope().cloned().unwrap_or(module);
     }
 
+    fn visit_item_static(&mut self, node: &'ast ItemStatic) {
+        self.add_conversion(Item::Static(node.clone()));
+    }
+
     fn visit_item_struct(&mut self, node: &'ast ItemStruct) {
         self.add_conversion(Item::Struct(node.clone()));
     }
@@ -73,7 +81,7 @@ impl<'ast> Visit<'ast> for Visitor {
     fn visit_item_use(&mut self, node: &'ast ItemUse) {
         // TODO: what to do with fn-level use statement?
         let scope = self.current_module_scope.clone();
-        self.fold_import_tree(&scope, &node.tree, vec![]);
+        self.fold_item_use(&scope, node);
     }
 }
 
@@ -110,10 +118,13 @@ impl Visitor {
 impl Visitor {
 
     /// Recursively processes Rust use paths to create a mapping
-    /// between idents and their fully qualified paths.
-    pub(crate) fn fold_import_tree(&mut self, scope: &ScopeChain, use_tree: &UseTree, current_path: Vec<Ident>) {
+    /// between idents and their fully qualified paths (and keeps track of the re-exports).
+    pub(crate) fn fold_item_use(&mut self, scope: &ScopeChain, item_use: &ItemUse) {
         let mut lock = self.context.borrow_mut();
-        lock.imports.fold_import_tree(scope, use_tree, current_path);
+        lock.imports.fold_import_tree(scope, &item_use.tree, vec![]);
+        if !matches!(item_use.vis, Visibility::Inherited) {
+            lock.imports.fold_reexport_tree(scope, &item_use.vis, &item_use.tree, vec![]);
+        }
     }
 
     pub(crate) fn add_full_qualified_trait_match(&mut self, scope: &ScopeChain, item_trait: &ItemTrait, itself: &ObjectKind) {
@@ -149,7 +160,17 @@ impl Visitor {
         let trait_names = extract_trait_names(item_trait_attrs);
         trait_names.iter().for_each(|trait_name| self.add_full_qualified_type_match(scope, &trait_name.to_type(), true));
         let mut lock = self.context.borrow_mut();
-        lock.traits.add_used_traits(scope, trait_names)
+        lock.traits.add_used_traits(scope, trait_names);
+        lock.traits.add_formatters(scope.self_path_ref().crate_named(&scope.crate_ident_as_path()), extract_formatters(item_trait_attrs));
+    }
+    /// `impl Display for Type` doesn't have to be exported to get the `to_string` binding for the type
+    fn add_formatter_impl(&mut self, scope: &ScopeChain, item: &Item) {
+        if let Item::Impl(ItemImpl { trait_: Some((None, trait_path, _)), self_ty, .. }) = item {
+            if let (Some(kind), Type::Path(TypePath { qself: None, path })) = (FormatterKind::from_trait_path(trait_path), &**self_ty) {
+                let mut lock = self.context.borrow_mut();
+                lock.traits.add_formatter_impl(scope, path.clone(), kind);
+            }
+        }
     }
 
     pub(crate) fn create_type_chain<N>(&self, ty: &N, scope: &ScopeChain) -> TypeChain
@@ -231,7 +252,7 @@ impl Visitor {
     fn find_scope_tree(&mut self, scope: &Path) -> &mut ScopeTreeExportItem {
         let mut current_tree = &mut self.tree;
         for ident in scope.segments.crate_less().iter().map(ScopeTreeID::from) {
-            if let ScopeTreeExportItem::Tree(scope_context, _, exported, attrs) = current_tree {
+            if let ScopeTreeExportItem::Tree(scope_context, _, exported, attrs, _) = current_tree {
                 if !exported.contains_key(&ident) {
                     exported.insert(ident.clone(), ScopeTreeExportItem::tree_with_context_and_exports(scope_context.clone(), attrs));
                 }
@@ -242,20 +263,30 @@ impl Visitor {
     }
 
     pub fn add_conversion(&mut self, item: Item) {
-        // TODO: filter out #[cfg(test)]
+        let item = item.unparenthesized();
         let ident = item.maybe_ident();
         let current_scope = self.current_module_scope.clone();
         let self_scope = current_scope.to_path();
+        self.add_formatter_impl(&current_scope, &item);
         match (MacroKind::try_from(&item), ObjectKind::try_from((&item, &self_scope))) {
-            (Ok(MacroKind::Export | MacroKind::Opaque), Ok(_)) => if let Some(scope) = item.join_scope(&current_scope, self) {
-                self.find_scope_tree(&self_scope)
-                    .add_item(item, scope);
-            },
             (_, Ok(_)) if item.is_mod() => {
                 item.add_to_scope(&current_scope, self);
                 self.find_scope_tree(&self_scope.popped())
                     .add_item(item, current_scope);
             },
+            (Ok(macro_kind @ (MacroKind::Export | MacroKind::Opaque)), Ok(_)) => {
+                let item_path = ident.map(|ident| current_scope.joined_path(ident))
+                    .unwrap_or_else(|| self_scope.crate_named(&current_scope.crate_ident_as_path()));
+                // Opaque items are passed as is, so only the exported ones are checked for the unsupported types
+                if let Some((span, message)) = matches!(macro_kind, MacroKind::Export).then(|| item.maybe_unsupported()).flatten() {
+                    let mut context = self.context.borrow_mut();
+                    let diagnostic = context.span_diagnostic(&item_path, span, message);
+                    context.add_diagnostic(diagnostic);
+                } else if let Some(scope) = item.join_scope(&current_scope, self) {
+                    self.find_scope_tree(&self_scope)
+                        .add_item(item, scope);
+                }
+            },
             (Ok(MacroKind::Register(custom_type)), Ok(_)) => if let ScopeTreeExportItem::Tree(scope_context, ..) = self.find_scope_tree(&self_scope) {
                 let scope_context_borrowed = scope_context.borrow();
                 scope_context_borrowed.add_custom_conversion(current_scope, custom_type, parse_quote!(#self_scope::#ident));
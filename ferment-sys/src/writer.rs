@@ -1,4 +1,7 @@
-use crate::{Config, Error};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+use crate::{Config, Diagnostic, DiagnosticPolicy, Error};
 use crate::lang::Specification;
 use crate::tree::CrateTree;
 
@@ -20,17 +23,54 @@ impl From<Config> for Writer {
 
 impl Writer {
 
-    pub(crate) fn write_all(&self) -> Result<(), Error> {
+    /// Returns the diagnostics of the skipped items
+    pub(crate) fn write_all(&self) -> Result<Vec<Diagnostic>, Error> {
+        #[allow(unused_mut)]
+        let mut diagnostics: Vec<Diagnostic> = vec![];
         #[cfg(not(feature = "cbindgen_only"))]
         {
+            self.config.check_external_crates()?;
             let crate_tree = crate::tree::FileTreeProcessor::build(&self.config)?;
             CrateTreeWrite::<crate::lang::RustSpecification>::write(self, &crate_tree)?;
             #[cfg(feature = "objc")]
             {
                 CrateTreeWrite::<crate::lang::objc::ObjCSpecification>::write(self, &crate_tree)?;
             }
+            diagnostics = crate_tree.diagnostics();
         }
         #[cfg(feature = "cbindgen")]
-        self.write_headers()
+        self.write_headers()?;
+        if self.config.diagnostic_policy == DiagnosticPolicy::CargoWarning {
+            diagnostics.iter().for_each(|diagnostic| println!("{}", diagnostic.cargo_warning()));
+        }
+        Ok(diagnostics)
+    }
+
+    /// Runs the same pipeline as `write_all` but only compares the result with the files on disk.
+    /// Covers the Rust fermentate and the C header
+    pub(crate) fn check_all(&self) -> Result<(), Error> {
+        let mut stale = vec![];
+        #[cfg(not(feature = "cbindgen_only"))]
+        {
+            self.config.check_external_crates()?;
+            let crate_tree = crate::tree::FileTreeProcessor::build(&self.config)?;
+            stale.extend(self.check_rust(&crate_tree)?);
+        }
+        #[cfg(feature = "cbindgen")]
+        stale.extend(self.check_headers()?);
+        if stale.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Stale(stale))
+        }
+    }
+}
+
+/// Reads the file if it exists
+pub(crate) fn read_existing(path: &Path) -> Result<Option<String>, Error> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(Error::from(err)),
     }
 }
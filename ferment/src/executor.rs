@@ -0,0 +1,117 @@
+use std::future::Future;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::task::{Context, Poll, Waker};
+use crate::unbox_any_opt;
+
+/// Drives the futures of fermented async bindings.
+/// Generated code receives the executor as `runtime: *const c_void` and casts it
+/// to the type set with `ferment_sys::Builder::with_executor` (`tokio::runtime::Runtime` by default)
+pub trait Executor {
+    /// Blocks the current thread until the future is resolved (`AsyncMode::Blocking`)
+    fn block_on<F: Future>(&self, future: F) -> F::Output;
+    /// Runs the future in the background (`AsyncMode::Callback`)
+    fn spawn<F>(&self, future: F)
+        where F: Future + Send + 'static,
+              F::Output: Send + 'static;
+}
+
+#[cfg(feature = "tokio")]
+impl Executor for tokio::runtime::Runtime {
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        tokio::runtime::Runtime::block_on(self, future)
+    }
+    fn spawn<F>(&self, future: F)
+        where F: Future + Send + 'static,
+              F::Output: Send + 'static {
+        tokio::runtime::Runtime::spawn(self, future);
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl Executor for tokio::runtime::Handle {
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        tokio::runtime::Handle::block_on(self, future)
+    }
+    fn spawn<F>(&self, future: F)
+        where F: Future + Send + 'static,
+              F::Output: Send + 'static {
+        tokio::runtime::Handle::spawn(self, future);
+    }
+}
+
+#[derive(Default)]
+struct TaskState {
+    cancelled: AtomicBool,
+    waker: Mutex<Option<Waker>>,
+}
+
+/// Future of a fermented async binding built with `AsyncMode::Callback`.
+/// Resolves with an error if it's cancelled through its `CancellationHandle` or if it panics,
+/// so it doesn't rely on the executor to abort tasks or to catch panics
+pub struct Task<F> {
+    future: F,
+    state: Arc<TaskState>,
+}
+impl<F: Future> Task<F> {
+    pub fn new(future: F) -> (Self, CancellationHandle) {
+        let state = Arc::new(TaskState::default());
+        let handle_state = Arc::clone(&state);
+        let handle = CancellationHandle::new(move || {
+            handle_state.cancelled.store(true, Ordering::SeqCst);
+            if let Some(waker) = handle_state.waker.lock().unwrap().take() {
+                waker.wake();
+            }
+        });
+        (Self { future, state }, handle)
+    }
+}
+impl<F: Future> Future for Task<F> {
+    type Output = Result<F::Output, String>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = unsafe { self.get_unchecked_mut() };
+        *this.state.waker.lock().unwrap() = Some(cx.waker().clone());
+        if this.state.cancelled.load(Ordering::SeqCst) {
+            return Poll::Ready(Err(String::from("Task was cancelled")));
+        }
+        let future = unsafe { Pin::new_unchecked(&mut this.future) };
+        match catch_unwind(AssertUnwindSafe(|| future.poll(cx))) {
+            Ok(poll) => poll.map(Ok),
+            Err(payload) => Poll::Ready(Err(crate::panic_message(payload))),
+        }
+    }
+}
+
+/// Handle of a task spawned by a fermented async binding built with `AsyncMode::Callback`
+pub struct CancellationHandle {
+    cancel: Box<dyn Fn() + Send + Sync>,
+}
+impl CancellationHandle {
+    pub fn new<F: Fn() + Send + Sync + 'static>(cancel: F) -> Self {
+        Self { cancel: Box::new(cancel) }
+    }
+    pub fn cancel(&self) {
+        (self.cancel)()
+    }
+}
+/// Cancels the task: its completion receives `on_error` unless the result is already delivered
+/// # Safety
+/// `handle` should be either null or the pointer returned by the fermented async binding
+/// which is not yet released with `ferment_cancellation_handle_destroy`
+#[no_mangle]
+pub unsafe extern "C" fn ferment_cancellation_handle_cancel(handle: *const CancellationHandle) {
+    if let Some(handle) = handle.as_ref() {
+        handle.cancel();
+    }
+}
+/// Releases the handle, the task itself keeps running
+/// # Safety
+/// `handle` should be either null or the pointer returned by the fermented async binding.
+/// It should be released only once and can't be used afterwards
+#[no_mangle]
+pub unsafe extern "C" fn ferment_cancellation_handle_destroy(handle: *mut CancellationHandle) {
+    unbox_any_opt(handle);
+}
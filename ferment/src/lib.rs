@@ -1,10 +1,16 @@
+pub mod executor;
 pub mod fermented;
 
+pub use executor::{CancellationHandle, Executor, Task, ferment_cancellation_handle_cancel, ferment_cancellation_handle_destroy};
+
+use std::any::Any;
+use std::cell::RefCell;
 use std::collections::{BTreeMap, HashMap};
 use std::ffi::CString;
 use std::hash::Hash;
 use std::mem;
 use std::os::raw::c_char;
+use std::panic::{AssertUnwindSafe, catch_unwind};
 
 /// We pass here main context of parent program
 ///
@@ -39,9 +45,88 @@ pub trait FFIConversionTo<T> {
         }
     }
 }
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Stores an error message for the current thread, replacing any previous one
+pub fn set_last_error<S: Into<String>>(message: S) {
+    LAST_ERROR.with(|cell| *cell.borrow_mut() = Some(message.into()));
+}
+/// Takes the last error message recorded on the current thread
+pub fn take_last_error() -> Option<String> {
+    LAST_ERROR.with(|cell| cell.borrow_mut().take())
+}
+
+/// Runs `f` and converts a panic into the thread-local "last error".
+/// Used by fermented bindings built with `PanicPolicy::CatchUnwind`,
+/// so the panic never unwinds across the `extern "C"` boundary.
+/// On panic the value produced by `fallback` is returned instead
+/// (null for pointers, zeroed memory otherwise) and must not be used by the caller.
+pub fn catch_panic<T, F, D>(f: F, fallback: D) -> T
+    where F: FnOnce() -> T,
+          D: FnOnce() -> T {
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(result) => result,
+        Err(payload) => {
+            set_last_error(panic_message(payload));
+            fallback()
+        }
+    }
+}
+
+pub(crate) fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    payload.downcast_ref::<&str>()
+        .map(|msg| msg.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| String::from("Unknown panic"))
+}
+
+/// Returns the last error recorded on the current thread (if any) and clears it.
+/// Result must be freed with `str_destroy`
+#[no_mangle]
+pub extern "C" fn ferment_last_error() -> *mut c_char {
+    take_last_error()
+        .map_or(std::ptr::null_mut(), |message| CString::new(message.replace('\0', " ")).unwrap().into_raw())
+}
+/// Returns true if an error was recorded on the current thread and not taken yet
+#[no_mangle]
+pub extern "C" fn ferment_has_last_error() -> bool {
+    LAST_ERROR.with(|cell| cell.borrow().is_some())
+}
+/// Clears the last error recorded on the current thread
+#[no_mangle]
+pub extern "C" fn ferment_clear_last_error() {
+    LAST_ERROR.with(|cell| cell.borrow_mut().take());
+}
+
 pub fn black_hole<T>(_obj: T) {}
 pub fn bypass<T>(obj: T) -> T { obj }
 
+/// `char` is passed by value as its Unicode scalar value.
+/// The values which aren't Unicode scalars (e.g. surrogates) have no `char` to convert into:
+/// the error is stored as the thread-local last error and the conversion panics,
+/// so no substituted value reaches the Rust code (`PanicPolicy::CatchUnwind` returns the fallback value instead)
+pub fn from_scalar(scalar: u32) -> char {
+    char::from_u32(scalar).unwrap_or_else(|| {
+        let message = format!("Invalid Unicode scalar value: {scalar:#x}");
+        set_last_error(message.as_str());
+        panic!("{message}")
+    })
+}
+
+/// `Option<char>` is passed as a nullable pointer to its Unicode scalar value
+/// # Safety
+pub unsafe fn from_opt_scalar(ptr: *mut u32) -> Option<char> {
+    (!ptr.is_null()).then(|| from_scalar(*ptr))
+}
+
+/// # Safety
+pub unsafe fn to_opt_scalar(obj: Option<char>) -> *mut u32 {
+    to_opt_primitive(obj.map(u32::from))
+}
+
 pub fn boxed<T>(obj: T) -> *mut T {
     Box::into_raw(Box::new(obj))
 }